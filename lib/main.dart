@@ -152,11 +152,11 @@ class _MemoryInfoWidgetState extends State<MemoryInfoWidget> {
         crossAxisAlignment: CrossAxisAlignment.start,
         children: [
           Text(
-            'Memory: ${storageToFloat(storage: memory.usedMemory).toStringAsFixed(2)} ${unitToString(unit: memory.usedMemory.unit)} / ${storageToFloat(storage: memory.totalMemory)} ${unitToString(unit: memory.totalMemory.unit)}',
+            'Memory: ${storageToString(storage: memory.usedMemory)} / ${storageToString(storage: memory.totalMemory)}',
           ),
           SizedBox(height: 16),
           Text(
-            'Swap: ${storageToFloat(storage: memory.usedSwap).toStringAsFixed(2)} ${unitToString(unit: memory.usedSwap.unit)} / ${storageToFloat(storage: memory.totalSwap)} ${unitToString(unit: memory.totalSwap.unit)}',
+            'Swap: ${storageToString(storage: memory.usedSwap)} / ${storageToString(storage: memory.totalSwap)}',
           ),
           SizedBox(height: 16),
           Expanded(
@@ -230,9 +230,7 @@ class ProcessMemoryInfoWidget extends StatelessWidget {
                     ),
                     Padding(
                       padding: const EdgeInsets.all(8.0),
-                      child: Text(
-                        '${storageToFloat(storage: process.$2.memory).toStringAsFixed(2)} ${unitToString(unit: process.$2.memory.unit)}',
-                      ),
+                      child: Text(storageToString(storage: process.$2.memory)),
                     ),
                   ],
                 )),
@@ -0,0 +1,100 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'alerts.freezed.dart';
+
+            
+
+            
+
+            @freezed
+                sealed class AlertCondition with _$AlertCondition  {
+                    const AlertCondition._();
+
+                     /// `used_memory` above `percent` of `total_memory`.
+const factory AlertCondition.usedMemoryPercentAbove({   required double percent , }) = AlertCondition_UsedMemoryPercentAbove;
+ const factory AlertCondition.usedSwapAbove({   required BigInt bytes , }) = AlertCondition_UsedSwapAbove;
+ /// `total_memory` of any process called `name` above `bytes`.
+const factory AlertCondition.processTotalMemoryAbove({   required String name ,  required BigInt bytes , }) = AlertCondition_ProcessTotalMemoryAbove;
+ /// Resident memory of any process growing faster than
+/// `bytes_per_minute`, measured over the last minute.
+const factory AlertCondition.processGrowthAbove({   required BigInt bytesPerMinute , }) = AlertCondition_ProcessGrowthAbove;
+
+                    
+
+                    
+                }
+
+class AlertEvent  {
+                final int ruleId;
+final String ruleName;
+final AlertEventKind kind;
+/// Set for rules evaluated per process.
+final int? pid;
+final String? processName;
+/// Observed value when the event was raised, in the unit of the
+/// condition (percent, bytes or bytes per minute).
+final double value;
+final String message;
+/// Milliseconds since the Unix epoch.
+final BigInt timestamp;
+
+                const AlertEvent({required this.ruleId ,required this.ruleName ,required this.kind ,this.pid ,this.processName ,required this.value ,required this.message ,required this.timestamp ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ruleId.hashCode^ruleName.hashCode^kind.hashCode^pid.hashCode^processName.hashCode^value.hashCode^message.hashCode^timestamp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AlertEvent &&
+                runtimeType == other.runtimeType
+                && ruleId == other.ruleId&& ruleName == other.ruleName&& kind == other.kind&& pid == other.pid&& processName == other.processName&& value == other.value&& message == other.message&& timestamp == other.timestamp;
+        
+            }
+
+enum AlertEventKind {
+                    fired,
+resolved,
+                    ;
+                    
+                }
+
+class AlertRule  {
+                final int id;
+final String name;
+final AlertCondition condition;
+/// How long the condition has to hold before the alert fires.
+final BigInt forMs;
+
+                const AlertRule({required this.id ,required this.name ,required this.condition ,required this.forMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^condition.hashCode^forMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AlertRule &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& condition == other.condition&& forMs == other.forMs;
+        
+            }
+            
\ No newline at end of file
@@ -3,20 +3,176 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../alerts.dart';
+import '../cgroup.dart';
+import '../control.dart';
+import '../diff.dart';
+import '../error.dart';
+import '../format.dart';
 import '../frb_generated.dart';
+import '../group.dart';
+import '../history.dart';
+import '../leak.dart';
+import '../meminfo.dart';
 import '../memory.dart';
+import '../query.dart';
+import '../smaps.dart';
+import '../store.dart';
 import '../utils.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-Future<Memory> getMemoryInfo() =>
-    RustLib.instance.api.crateApiMemoryGetMemoryInfo();
 
-Future<List<(int, ProcessMemoryInfo)>> getFirstProcessMemoryUsage(
-        {required BigInt n}) =>
-    RustLib.instance.api.crateApiMemoryGetFirstProcessMemoryUsage(n: n);
+            // These functions are ignored because they are not marked as `pub`: `alert_sinks`, `alerts`, `compact_in_background`, `history`, `latest_timestamp`, `no_alert_rule`, `no_store`, `observe`, `recorder`, `refresh`, `sampler`, `send_alert_events`, `snapshot_with_metric`, `snapshot`, `store`
 
-double storageToFloat({required Storage storage}) =>
-    RustLib.instance.api.crateApiMemoryStorageToFloat(storage: storage);
 
-String unitToString({required Unit unit}) =>
-    RustLib.instance.api.crateApiMemoryUnitToString(unit: unit);
+            Future<Memory>  getMemoryInfo() => RustLib.instance.api.crateApiMemoryGetMemoryInfo();
+
+Future<List<(int,ProcessMemoryInfo)>>  getFirstProcessMemoryUsage({required BigInt n }) => RustLib.instance.api.crateApiMemoryGetFirstProcessMemoryUsage(n: n);
+
+/// Lists the processes of the current snapshot matching `query`, one page at
+/// a time.
+Future<ProcessPage>  queryProcesses({required ProcessQuery query }) => RustLib.instance.api.crateApiMemoryQueryProcesses(query: query);
+
+/// Rolls up the processes of the current snapshot by `by`, summing the
+/// metric the sampler aggregates with.
+Future<List<ProcessGroup>>  getProcessGroups({required GroupBy by }) => RustLib.instance.api.crateApiMemoryGetProcessGroups(by: by);
+
+/// Returns the detailed system memory breakdown. Only supported on Linux.
+Future<MemoryBreakdown>  getMemoryBreakdown() => RustLib.instance.api.crateApiMemoryGetMemoryBreakdown();
+
+/// Selects what `total_memory` sums over each subtree. PSS and USS are only
+/// available on Linux; elsewhere they fall back to RSS.
+Future<void>  setMemoryMetric({required MemoryMetric metric }) => RustLib.instance.api.crateApiMemorySetMemoryMetric(metric: metric);
+
+Future<MemoryMetric>  getMemoryMetric() => RustLib.instance.api.crateApiMemoryGetMemoryMetric();
+
+Future<List<ProcessTreeNode>>  getProcessTree() => RustLib.instance.api.crateApiMemoryGetProcessTree();
+
+/// Returns the memory mappings of `pid`, individually and grouped by backing
+/// file. Only supported on Linux.
+Future<ProcessMemoryMaps>  getProcessMemoryMaps({required int pid }) => RustLib.instance.api.crateApiMemoryGetProcessMemoryMaps(pid: pid);
+
+/// Sends `signal` to `pid` if it is still the process started at
+/// `start_time`, as reported in its [`ProcessMemoryInfo`]. Init is never
+/// signalled.
+Future<SignalResult>  sendSignal({required int pid , required BigInt startTime , required Signal signal }) => RustLib.instance.api.crateApiMemorySendSignal(pid: pid, startTime: startTime, signal: signal);
+
+/// Terminates `pid` and all its descendants, escalating from TERM to KILL
+/// for the processes still alive after `grace_ms`.
+Future<List<SignalResult>>  killProcessTree({required int pid , required BigInt graceMs }) => RustLib.instance.api.crateApiMemoryKillProcessTree(pid: pid, graceMs: graceMs);
+
+/// Returns the cgroup v2 hierarchy with the memory usage, limits and OOM
+/// events of every cgroup. Only supported on Linux.
+Future<CgroupNode>  getCgroupTree() => RustLib.instance.api.crateApiMemoryGetCgroupTree();
+
+/// Returns `series` over `[start_ms, end_ms)` (milliseconds since the Unix
+/// epoch), downsampled into buckets `resolution_ms` wide. A resolution of 0
+/// returns every recorded sample.
+Future<List<HistoryBucket>>  queryMemoryHistory({required HistorySeries series , required BigInt startMs , required BigInt endMs , required BigInt resolutionMs }) => RustLib.instance.api.crateApiMemoryQueryMemoryHistory(series: series, startMs: startMs, endMs: endMs, resolutionMs: resolutionMs);
+
+/// Sets how many samples the in-memory history keeps before dropping the
+/// oldest ones.
+Future<void>  setMemoryHistoryCapacity({required BigInt capacity }) => RustLib.instance.api.crateApiMemorySetMemoryHistoryCapacity(capacity: capacity);
+
+Future<void>  clearMemoryHistory() => RustLib.instance.api.crateApiMemoryClearMemoryHistory();
+
+/// Compares two snapshots taken with [`get_memory_info`].
+Future<MemoryDiff>  diffMemorySnapshots({required Memory before , required Memory after }) => RustLib.instance.api.crateApiMemoryDiffMemorySnapshots(before: before, after: after);
+
+/// Compares the recorded samples nearest to `before_ms` and `after_ms`
+/// (milliseconds since the Unix epoch).
+Future<MemoryDiff>  diffMemoryHistory({required BigInt beforeMs , required BigInt afterMs }) => RustLib.instance.api.crateApiMemoryDiffMemoryHistory(beforeMs: beforeMs, afterMs: afterMs);
+
+HistoryStoreOptions  defaultHistoryStoreOptions() => RustLib.instance.api.crateApiMemoryDefaultHistoryStoreOptions();
+
+/// Starts persisting samples to `path`, replacing any store opened before.
+/// Existing data at `path` is kept and compacted.
+Future<void>  openHistoryStore({required String path , required HistoryStoreOptions options }) => RustLib.instance.api.crateApiMemoryOpenHistoryStore(path: path, options: options);
+
+Future<void>  closeHistoryStore() => RustLib.instance.api.crateApiMemoryCloseHistoryStore();
+
+/// Same as [`query_memory_history`], over the samples persisted on disk.
+Future<List<HistoryBucket>>  queryStoredHistory({required HistorySeries series , required BigInt startMs , required BigInt endMs , required BigInt resolutionMs }) => RustLib.instance.api.crateApiMemoryQueryStoredHistory(series: series, startMs: startMs, endMs: endMs, resolutionMs: resolutionMs);
+
+Future<CompactionStats>  compactHistoryStore() => RustLib.instance.api.crateApiMemoryCompactHistoryStore();
+
+LeakDetectorOptions  defaultLeakDetectorOptions() => RustLib.instance.api.crateApiMemoryDefaultLeakDetectorOptions();
+
+/// Looks for processes whose memory grew steadily over the recent history.
+Future<List<LeakReport>>  detectMemoryLeaks({required LeakDetectorOptions options }) => RustLib.instance.api.crateApiMemoryDetectMemoryLeaks(options: options);
+
+/// Adds an alert rule, evaluated against every new sample, and returns its
+/// id.
+Future<int>  addAlertRule({required String name , required AlertCondition condition , required BigInt forMs }) => RustLib.instance.api.crateApiMemoryAddAlertRule(name: name, condition: condition, forMs: forMs);
+
+/// Replaces the rule with the same id. Alerts it was firing are resolved.
+Future<void>  updateAlertRule({required AlertRule rule }) => RustLib.instance.api.crateApiMemoryUpdateAlertRule(rule: rule);
+
+/// Removes a rule. Alerts it was firing are resolved.
+Future<void>  removeAlertRule({required int id }) => RustLib.instance.api.crateApiMemoryRemoveAlertRule(id: id);
+
+Future<List<AlertRule>>  listAlertRules() => RustLib.instance.api.crateApiMemoryListAlertRules();
+
+/// Delivers fired and resolved alerts to `sink` until Dart cancels the
+/// stream. Rules are only evaluated while samples are being taken, e.g.
+/// through [`memory_stream`].
+Stream<AlertEvent>  alertStream() => RustLib.instance.api.crateApiMemoryAlertStream();
+
+/// Starts writing every new sample to a recording at `path`, replacing the
+/// file if it exists.
+Future<void>  startRecording({required String path }) => RustLib.instance.api.crateApiMemoryStartRecording(path: path);
+
+Future<void>  stopRecording() => RustLib.instance.api.crateApiMemoryStopRecording();
+
+/// Replaces the live system with the recording at `path`, played back
+/// `speed` times as fast as it was recorded. Everything fed by samples,
+/// including [`memory_stream`], then sees the recorded data, stamped with
+/// the time it was recorded. The memory history starts over.
+Future<void>  startReplay({required String path , required double speed }) => RustLib.instance.api.crateApiMemoryStartReplay(path: path, speed: speed);
+
+/// Switches back from a replay to the live system. The memory history
+/// starts over.
+Future<void>  stopReplay() => RustLib.instance.api.crateApiMemoryStopReplay();
+
+/// Pushes a fresh [`Memory`] snapshot to `sink` every `interval_ms`
+/// milliseconds, sampled on a dedicated thread, until Dart cancels the
+/// stream. Subscribing again replaces the previous sink.
+Stream<Memory>  memoryStream({required BigInt intervalMs }) => RustLib.instance.api.crateApiMemoryMemoryStream(intervalMs: intervalMs);
+
+void  setMemoryStreamInterval({required BigInt intervalMs }) => RustLib.instance.api.crateApiMemorySetMemoryStreamInterval(intervalMs: intervalMs);
+
+void  pauseMemoryStream() => RustLib.instance.api.crateApiMemoryPauseMemoryStream();
+
+void  resumeMemoryStream() => RustLib.instance.api.crateApiMemoryResumeMemoryStream();
+
+double  storageToFloat({required Storage storage }) => RustLib.instance.api.crateApiMemoryStorageToFloat(storage: storage);
+
+String  unitToString({required StorageUnit unit }) => RustLib.instance.api.crateApiMemoryUnitToString(unit: unit);
+
+/// Formats `storage` in `system`, or in the global unit system if `None`.
+String  storageToString({required Storage storage , UnitSystem? system }) => RustLib.instance.api.crateApiMemoryStorageToString(storage: storage, system: system);
+
+/// Parses a size such as `512M`, `2 GB` or `1.5 GiB`.
+Storage  parseStorage({required String input }) => RustLib.instance.api.crateApiMemoryParseStorage(input: input);
+
+StorageFormatOptions  defaultStorageFormatOptions() => RustLib.instance.api.crateApiMemoryDefaultStorageFormatOptions();
+
+/// Default format options with the separators of `locale`, e.g. `de-DE`.
+StorageFormatOptions  storageFormatOptionsForLocale({required String locale }) => RustLib.instance.api.crateApiMemoryStorageFormatOptionsForLocale(locale: locale);
+
+String  formatStorage({required Storage storage , required StorageFormatOptions options }) => RustLib.instance.api.crateApiMemoryFormatStorage(storage: storage, options: options);
+
+Storage  convertStorage({required Storage storage , required StorageUnit unit }) => RustLib.instance.api.crateApiMemoryConvertStorage(storage: storage, unit: unit);
+
+UnitSystem  getUnitSystem() => RustLib.instance.api.crateApiMemoryGetUnitSystem();
+
+/// Sets the unit system used by [`storage_to_string`] when the call does not
+/// pick one.
+///
+/// Amounts keep the unit they were created in, so [`storage_to_float`] and
+/// [`unit_to_string`] are not affected; display amounts through
+/// [`storage_to_string`] to follow this setting.
+void  setUnitSystem({required UnitSystem system }) => RustLib.instance.api.crateApiMemorySetUnitSystem(system: system);
+
+            
+            
\ No newline at end of file
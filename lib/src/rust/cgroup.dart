@@ -0,0 +1,107 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'utils.dart';
+
+
+            
+
+            
+
+            /// Counters from `memory.events`.
+class CgroupEvents  {
+                final BigInt low;
+final BigInt high;
+final BigInt max;
+final BigInt oom;
+final BigInt oomKill;
+
+                const CgroupEvents({required this.low ,required this.high ,required this.max ,required this.oom ,required this.oomKill ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => low.hashCode^high.hashCode^max.hashCode^oom.hashCode^oomKill.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CgroupEvents &&
+                runtimeType == other.runtimeType
+                && low == other.low&& high == other.high&& max == other.max&& oom == other.oom&& oomKill == other.oomKill;
+        
+            }
+
+/// Memory controller files of a single cgroup. Files missing from the
+/// cgroup, such as `memory.current` of the root cgroup, are left unset.
+class CgroupMemory  {
+                final Storage? current;
+/// `None` when unset or unlimited.
+final Storage? max;
+/// `None` when unset or unlimited.
+final Storage? high;
+/// Entries of `memory.stat`, in bytes for the size entries, in file
+/// order.
+final List<(String,BigInt)> stat;
+final CgroupEvents events;
+
+                const CgroupMemory({this.current ,this.max ,this.high ,required this.stat ,required this.events ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => current.hashCode^max.hashCode^high.hashCode^stat.hashCode^events.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CgroupMemory &&
+                runtimeType == other.runtimeType
+                && current == other.current&& max == other.max&& high == other.high&& stat == other.stat&& events == other.events;
+        
+            }
+
+/// A cgroup and its descendants.
+class CgroupNode  {
+                /// Path relative to the cgroup root, `/` for the root itself, matching
+/// the paths in `/proc/<pid>/cgroup`.
+final String path;
+final String? containerId;
+final CgroupMemory memory;
+/// PIDs listed in `cgroup.procs`.
+final Uint32List processes;
+/// Sorted by path.
+final List<CgroupNode> children;
+
+                const CgroupNode({required this.path ,this.containerId ,required this.memory ,required this.processes ,required this.children ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^containerId.hashCode^memory.hashCode^processes.hashCode^children.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CgroupNode &&
+                runtimeType == other.runtimeType
+                && path == other.path&& containerId == other.containerId&& memory == other.memory&& processes == other.processes&& children == other.children;
+        
+            }
+            
\ No newline at end of file
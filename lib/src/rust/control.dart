@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'control.freezed.dart';
+
+            
+
+            
+
+            enum Signal {
+                    /// Asks the process to terminate; it can clean up or ignore it.
+term,
+/// Terminates the process immediately.
+kill,
+/// Suspends the process until it receives [`Signal::Cont`].
+stop,
+cont,
+                    ;
+                    
+                }
+
+class SignalResult  {
+                final int pid;
+final Signal signal;
+final SignalStatus status;
+
+                const SignalResult({required this.pid ,required this.signal ,required this.status ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^signal.hashCode^status.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SignalResult &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& signal == other.signal&& status == other.status;
+        
+            }
+
+@freezed
+                sealed class SignalStatus with _$SignalStatus  {
+                    const SignalStatus._();
+
+                     const factory SignalStatus.sent() = SignalStatus_Sent;
+ /// The process belongs to another user.
+const factory SignalStatus.permissionDenied() = SignalStatus_PermissionDenied;
+ /// The process does not exist (anymore).
+const factory SignalStatus.notFound() = SignalStatus_NotFound;
+ /// PIDs that would address more than one process, such as 0, are
+/// rejected.
+const factory SignalStatus.invalidPid() = SignalStatus_InvalidPid;
+ /// Signals are not supported on this platform.
+const factory SignalStatus.unsupported() = SignalStatus_Unsupported;
+ const factory SignalStatus.failed({   required String message , }) = SignalStatus_Failed;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
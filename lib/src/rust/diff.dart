@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Differences between two captures, in bytes.
+class MemoryDiff  {
+                /// Milliseconds since the Unix epoch, 0 when diffing two [`Memory`]
+/// snapshots.
+final BigInt beforeTimestamp;
+final BigInt afterTimestamp;
+final PlatformInt64 totalMemoryDelta;
+final PlatformInt64 usedMemoryDelta;
+final PlatformInt64 totalSwapDelta;
+final PlatformInt64 usedSwapDelta;
+/// Started, exited and changed processes, largest absolute change
+/// first. Processes whose memory did not change are left out.
+final List<ProcessDiff> processes;
+
+                const MemoryDiff({required this.beforeTimestamp ,required this.afterTimestamp ,required this.totalMemoryDelta ,required this.usedMemoryDelta ,required this.totalSwapDelta ,required this.usedSwapDelta ,required this.processes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => beforeTimestamp.hashCode^afterTimestamp.hashCode^totalMemoryDelta.hashCode^usedMemoryDelta.hashCode^totalSwapDelta.hashCode^usedSwapDelta.hashCode^processes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MemoryDiff &&
+                runtimeType == other.runtimeType
+                && beforeTimestamp == other.beforeTimestamp&& afterTimestamp == other.afterTimestamp&& totalMemoryDelta == other.totalMemoryDelta&& usedMemoryDelta == other.usedMemoryDelta&& totalSwapDelta == other.totalSwapDelta&& usedSwapDelta == other.usedSwapDelta&& processes == other.processes;
+        
+            }
+
+enum ProcessChange {
+                    started,
+exited,
+changed,
+                    ;
+                    
+                }
+
+/// How a single process changed between two captures, in bytes.
+///
+/// Values missing on one side, such as `before` of a started process, are 0.
+class ProcessDiff  {
+                final int pid;
+final String name;
+final ProcessChange change;
+final BigInt memoryBefore;
+final BigInt memoryAfter;
+final PlatformInt64 memoryDelta;
+final BigInt totalMemoryBefore;
+final BigInt totalMemoryAfter;
+/// Change of the memory of the process and all its descendants.
+final PlatformInt64 totalMemoryDelta;
+
+                const ProcessDiff({required this.pid ,required this.name ,required this.change ,required this.memoryBefore ,required this.memoryAfter ,required this.memoryDelta ,required this.totalMemoryBefore ,required this.totalMemoryAfter ,required this.totalMemoryDelta ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^name.hashCode^change.hashCode^memoryBefore.hashCode^memoryAfter.hashCode^memoryDelta.hashCode^totalMemoryBefore.hashCode^totalMemoryAfter.hashCode^totalMemoryDelta.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessDiff &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& name == other.name&& change == other.change&& memoryBefore == other.memoryBefore&& memoryAfter == other.memoryAfter&& memoryDelta == other.memoryDelta&& totalMemoryBefore == other.totalMemoryBefore&& totalMemoryAfter == other.totalMemoryAfter&& totalMemoryDelta == other.totalMemoryDelta;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,35 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+            
+
+            
+
+            @freezed
+                sealed class MemoryError with _$MemoryError implements FrbException {
+                    const MemoryError._();
+
+                     /// The OS refused access, typically to another user's process.
+const factory MemoryError.permissionDenied({   required String message , }) = MemoryError_PermissionDenied;
+ /// The process exited while it was being inspected.
+const factory MemoryError.processGone({   required int pid , }) = MemoryError_ProcessGone;
+ const factory MemoryError.notFound({   required String message , }) = MemoryError_NotFound;
+ /// The process hierarchy could not be built.
+const factory MemoryError.treeInconsistent({   required String message , }) = MemoryError_TreeInconsistent;
+ /// Not available on this platform.
+const factory MemoryError.unsupported({   required String message , }) = MemoryError_Unsupported;
+ const factory MemoryError.invalidInput({   required String message , }) = MemoryError_InvalidInput;
+ const factory MemoryError.io({   required String message , }) = MemoryError_Io;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
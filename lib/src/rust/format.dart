@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'utils.dart';
+
+
+            
+
+            
+
+            class StorageFormatOptions  {
+                /// Digits after the decimal separator, at most 20.
+final int precision;
+/// Always print in this unit, however small or large the number gets.
+final StorageUnit? unit;
+/// Print in the best fitting unit of this system. Falls back to
+/// [`utils::default_unit_system`]. Ignored if `unit` is set.
+final UnitSystem? system;
+/// Drops trailing zeros after the decimal separator, and the separator
+/// itself if nothing is left.
+final bool trimZeros;
+final StorageStyle style;
+final String decimalSeparator;
+/// Groups the integer part by thousands if set.
+final String? thousandsSeparator;
+
+                const StorageFormatOptions({required this.precision ,this.unit ,this.system ,required this.trimZeros ,required this.style ,required this.decimalSeparator ,this.thousandsSeparator ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => precision.hashCode^unit.hashCode^system.hashCode^trimZeros.hashCode^style.hashCode^decimalSeparator.hashCode^thousandsSeparator.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StorageFormatOptions &&
+                runtimeType == other.runtimeType
+                && precision == other.precision&& unit == other.unit&& system == other.system&& trimZeros == other.trimZeros&& style == other.style&& decimalSeparator == other.decimalSeparator&& thousandsSeparator == other.thousandsSeparator;
+        
+            }
+
+/// How the unit is written after the number.
+enum StorageStyle {
+                    /// `1.50 GiB`
+short,
+/// `1.50G`, as printed by `ls -h`.
+compact,
+/// `1.50 gibibytes`
+long,
+                    ;
+                    
+                }
+            
\ No newline at end of file
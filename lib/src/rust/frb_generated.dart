@@ -3,730 +3,3112 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'alerts.dart';
 import 'api/memory.dart';
+import 'cgroup.dart';
+import 'control.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'diff.dart';
+import 'error.dart';
+import 'format.dart';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'group.dart';
+import 'history.dart';
+import 'leak.dart';
+import 'meminfo.dart';
 import 'memory.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'query.dart';
+import 'smaps.dart';
+import 'store.dart';
 import 'utils.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiMemoryInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -646841934;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_flutter_demo',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<List<(int, ProcessMemoryInfo)>>
-      crateApiMemoryGetFirstProcessMemoryUsage({required BigInt n});
-
-  Future<Memory> crateApiMemoryGetMemoryInfo();
-
-  Future<bool> crateApiMemoryInitApp();
-
-  double crateApiMemoryStorageToFloat({required Storage storage});
-
-  String crateApiMemoryUnitToString({required Unit unit});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<List<(int, ProcessMemoryInfo)>>
-      crateApiMemoryGetFirstProcessMemoryUsage({required BigInt n}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_usize(n, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_record_u_32_process_memory_info,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiMemoryGetFirstProcessMemoryUsageConstMeta,
-      argValues: [n],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiMemoryGetFirstProcessMemoryUsageConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_first_process_memory_usage",
-        argNames: ["n"],
-      );
-
-  @override
-  Future<Memory> crateApiMemoryGetMemoryInfo() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_memory,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiMemoryGetMemoryInfoConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiMemoryGetMemoryInfoConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_memory_info",
-        argNames: [],
-      );
-
-  @override
-  Future<bool> crateApiMemoryInitApp() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiMemoryInitAppConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiMemoryInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
-      );
-
-  @override
-  double crateApiMemoryStorageToFloat({required Storage storage}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_storage(storage, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_f_64,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiMemoryStorageToFloatConstMeta,
-      argValues: [storage],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiMemoryStorageToFloatConstMeta =>
-      const TaskConstMeta(
-        debugName: "storage_to_float",
-        argNames: ["storage"],
-      );
-
-  @override
-  String crateApiMemoryUnitToString({required Unit unit}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_unit(unit, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiMemoryUnitToStringConstMeta,
-      argValues: [unit],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiMemoryUnitToStringConstMeta => const TaskConstMeta(
-        debugName: "unit_to_string",
-        argNames: ["unit"],
-      );
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  Map<int, ProcessMemoryInfo> dco_decode_Map_u_32_process_memory_info_None(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(dco_decode_list_record_u_32_process_memory_info(raw)
-        .map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  Storage dco_decode_box_autoadd_storage(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_storage(raw);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(int, ProcessMemoryInfo)>
-      dco_decode_list_record_u_32_process_memory_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_u_32_process_memory_info)
-        .toList();
-  }
-
-  @protected
-  Memory dco_decode_memory(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return Memory(
-      totalMemory: dco_decode_storage(arr[0]),
-      usedMemory: dco_decode_storage(arr[1]),
-      totalSwap: dco_decode_storage(arr[2]),
-      usedSwap: dco_decode_storage(arr[3]),
-      processes: dco_decode_Map_u_32_process_memory_info_None(arr[4]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
-  }
-
-  @protected
-  ProcessMemoryInfo dco_decode_process_memory_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return ProcessMemoryInfo(
-      memory: dco_decode_storage(arr[0]),
-      rawMemory: dco_decode_u_64(arr[1]),
-      name: dco_decode_String(arr[2]),
-      exe: dco_decode_opt_String(arr[3]),
-      parent: dco_decode_opt_box_autoadd_u_32(arr[4]),
-      root: dco_decode_opt_String(arr[5]),
-      totalMemory: dco_decode_storage(arr[6]),
-    );
-  }
-
-  @protected
-  (int, ProcessMemoryInfo) dco_decode_record_u_32_process_memory_info(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_u_32(arr[0]),
-      dco_decode_process_memory_info(arr[1]),
-    );
-  }
-
-  @protected
-  Storage dco_decode_storage(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return Storage(
-      quotient: dco_decode_u_64(arr[0]),
-      remainder: dco_decode_u_64(arr[1]),
-      unit: dco_decode_unit(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  Unit dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Unit.values[raw as int];
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  Map<int, ProcessMemoryInfo> sse_decode_Map_u_32_process_memory_info_None(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_u_32_process_memory_info(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  Storage sse_decode_box_autoadd_storage(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_storage(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(int, ProcessMemoryInfo)>
-      sse_decode_list_record_u_32_process_memory_info(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, ProcessMemoryInfo)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_32_process_memory_info(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Memory sse_decode_memory(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalMemory = sse_decode_storage(deserializer);
-    var var_usedMemory = sse_decode_storage(deserializer);
-    var var_totalSwap = sse_decode_storage(deserializer);
-    var var_usedSwap = sse_decode_storage(deserializer);
-    var var_processes =
-        sse_decode_Map_u_32_process_memory_info_None(deserializer);
-    return Memory(
-        totalMemory: var_totalMemory,
-        usedMemory: var_usedMemory,
-        totalSwap: var_totalSwap,
-        usedSwap: var_usedSwap,
-        processes: var_processes);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  ProcessMemoryInfo sse_decode_process_memory_info(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_memory = sse_decode_storage(deserializer);
-    var var_rawMemory = sse_decode_u_64(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_exe = sse_decode_opt_String(deserializer);
-    var var_parent = sse_decode_opt_box_autoadd_u_32(deserializer);
-    var var_root = sse_decode_opt_String(deserializer);
-    var var_totalMemory = sse_decode_storage(deserializer);
-    return ProcessMemoryInfo(
-        memory: var_memory,
-        rawMemory: var_rawMemory,
-        name: var_name,
-        exe: var_exe,
-        parent: var_parent,
-        root: var_root,
-        totalMemory: var_totalMemory);
-  }
-
-  @protected
-  (int, ProcessMemoryInfo) sse_decode_record_u_32_process_memory_info(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_process_memory_info(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  Storage sse_decode_storage(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_quotient = sse_decode_u_64(deserializer);
-    var var_remainder = sse_decode_u_64(deserializer);
-    var var_unit = sse_decode_unit(deserializer);
-    return Storage(
-        quotient: var_quotient, remainder: var_remainder, unit: var_unit);
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  Unit sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return Unit.values[inner];
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_Map_u_32_process_memory_info_None(
-      Map<int, ProcessMemoryInfo> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_u_32_process_memory_info(
-        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_storage(Storage self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_storage(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_u_32_process_memory_info(
-      List<(int, ProcessMemoryInfo)> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_32_process_memory_info(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_memory(Memory self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_storage(self.totalMemory, serializer);
-    sse_encode_storage(self.usedMemory, serializer);
-    sse_encode_storage(self.totalSwap, serializer);
-    sse_encode_storage(self.usedSwap, serializer);
-    sse_encode_Map_u_32_process_memory_info_None(self.processes, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_process_memory_info(
-      ProcessMemoryInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_storage(self.memory, serializer);
-    sse_encode_u_64(self.rawMemory, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.exe, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.parent, serializer);
-    sse_encode_opt_String(self.root, serializer);
-    sse_encode_storage(self.totalMemory, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_process_memory_info(
-      (int, ProcessMemoryInfo) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_process_memory_info(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_storage(Storage self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.quotient, serializer);
-    sse_encode_u_64(self.remainder, serializer);
-    sse_encode_unit(self.unit, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(Unit self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiMemoryInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -719145359;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_flutter_demo',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<int> crateApiMemoryAddAlertRule({required String name , required AlertCondition condition , required BigInt forMs });
+
+Stream<AlertEvent> crateApiMemoryAlertStream();
+
+Future<void> crateApiMemoryClearMemoryHistory();
+
+Future<void> crateApiMemoryCloseHistoryStore();
+
+Future<CompactionStats> crateApiMemoryCompactHistoryStore();
+
+Storage crateApiMemoryConvertStorage({required Storage storage , required StorageUnit unit });
+
+HistoryStoreOptions crateApiMemoryDefaultHistoryStoreOptions();
+
+LeakDetectorOptions crateApiMemoryDefaultLeakDetectorOptions();
+
+StorageFormatOptions crateApiMemoryDefaultStorageFormatOptions();
+
+Future<List<LeakReport>> crateApiMemoryDetectMemoryLeaks({required LeakDetectorOptions options });
+
+Future<MemoryDiff> crateApiMemoryDiffMemoryHistory({required BigInt beforeMs , required BigInt afterMs });
+
+Future<MemoryDiff> crateApiMemoryDiffMemorySnapshots({required Memory before , required Memory after });
+
+String crateApiMemoryFormatStorage({required Storage storage , required StorageFormatOptions options });
+
+Future<CgroupNode> crateApiMemoryGetCgroupTree();
+
+Future<List<(int,ProcessMemoryInfo)>> crateApiMemoryGetFirstProcessMemoryUsage({required BigInt n });
+
+Future<MemoryBreakdown> crateApiMemoryGetMemoryBreakdown();
+
+Future<Memory> crateApiMemoryGetMemoryInfo();
+
+Future<MemoryMetric> crateApiMemoryGetMemoryMetric();
+
+Future<List<ProcessGroup>> crateApiMemoryGetProcessGroups({required GroupBy by });
+
+Future<ProcessMemoryMaps> crateApiMemoryGetProcessMemoryMaps({required int pid });
+
+Future<List<ProcessTreeNode>> crateApiMemoryGetProcessTree();
+
+UnitSystem crateApiMemoryGetUnitSystem();
+
+Future<bool> crateApiMemoryInitApp();
+
+Future<List<SignalResult>> crateApiMemoryKillProcessTree({required int pid , required BigInt graceMs });
+
+Future<List<AlertRule>> crateApiMemoryListAlertRules();
+
+Stream<Memory> crateApiMemoryMemoryStream({required BigInt intervalMs });
+
+Future<void> crateApiMemoryOpenHistoryStore({required String path , required HistoryStoreOptions options });
+
+Storage crateApiMemoryParseStorage({required String input });
+
+void crateApiMemoryPauseMemoryStream();
+
+Future<List<HistoryBucket>> crateApiMemoryQueryMemoryHistory({required HistorySeries series , required BigInt startMs , required BigInt endMs , required BigInt resolutionMs });
+
+Future<ProcessPage> crateApiMemoryQueryProcesses({required ProcessQuery query });
+
+Future<List<HistoryBucket>> crateApiMemoryQueryStoredHistory({required HistorySeries series , required BigInt startMs , required BigInt endMs , required BigInt resolutionMs });
+
+Future<void> crateApiMemoryRemoveAlertRule({required int id });
+
+void crateApiMemoryResumeMemoryStream();
+
+Future<SignalResult> crateApiMemorySendSignal({required int pid , required BigInt startTime , required Signal signal });
+
+Future<void> crateApiMemorySetMemoryHistoryCapacity({required BigInt capacity });
+
+Future<void> crateApiMemorySetMemoryMetric({required MemoryMetric metric });
+
+void crateApiMemorySetMemoryStreamInterval({required BigInt intervalMs });
+
+void crateApiMemorySetUnitSystem({required UnitSystem system });
+
+Future<void> crateApiMemoryStartRecording({required String path });
+
+Future<void> crateApiMemoryStartReplay({required String path , required double speed });
+
+Future<void> crateApiMemoryStopRecording();
+
+Future<void> crateApiMemoryStopReplay();
+
+StorageFormatOptions crateApiMemoryStorageFormatOptionsForLocale({required String locale });
+
+double crateApiMemoryStorageToFloat({required Storage storage });
+
+String crateApiMemoryStorageToString({required Storage storage , UnitSystem? system });
+
+String crateApiMemoryUnitToString({required StorageUnit unit });
+
+Future<void> crateApiMemoryUpdateAlertRule({required AlertRule rule });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<int> crateApiMemoryAddAlertRule({required String name , required AlertCondition condition , required BigInt forMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_box_autoadd_alert_condition(condition, serializer);
+sse_encode_u_64(forMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryAddAlertRuleConstMeta,
+            argValues: [name, condition, forMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryAddAlertRuleConstMeta => const TaskConstMeta(
+            debugName: "add_alert_rule",
+            argNames: ["name", "condition", "forMs"],
+        );
+        
+
+@override Stream<AlertEvent> crateApiMemoryAlertStream()  { 
+            final sink = RustStreamSink<AlertEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_alert_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryAlertStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiMemoryAlertStreamConstMeta => const TaskConstMeta(
+            debugName: "alert_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<void> crateApiMemoryClearMemoryHistory()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryClearMemoryHistoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryClearMemoryHistoryConstMeta => const TaskConstMeta(
+            debugName: "clear_memory_history",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiMemoryCloseHistoryStore()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryCloseHistoryStoreConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryCloseHistoryStoreConstMeta => const TaskConstMeta(
+            debugName: "close_history_store",
+            argNames: [],
+        );
+        
+
+@override Future<CompactionStats> crateApiMemoryCompactHistoryStore()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_compaction_stats,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryCompactHistoryStoreConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryCompactHistoryStoreConstMeta => const TaskConstMeta(
+            debugName: "compact_history_store",
+            argNames: [],
+        );
+        
+
+@override Storage crateApiMemoryConvertStorage({required Storage storage , required StorageUnit unit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_storage(storage, serializer);
+sse_encode_storage_unit(unit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_storage,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryConvertStorageConstMeta,
+            argValues: [storage, unit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryConvertStorageConstMeta => const TaskConstMeta(
+            debugName: "convert_storage",
+            argNames: ["storage", "unit"],
+        );
+        
+
+@override HistoryStoreOptions crateApiMemoryDefaultHistoryStoreOptions()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_history_store_options,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryDefaultHistoryStoreOptionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryDefaultHistoryStoreOptionsConstMeta => const TaskConstMeta(
+            debugName: "default_history_store_options",
+            argNames: [],
+        );
+        
+
+@override LeakDetectorOptions crateApiMemoryDefaultLeakDetectorOptions()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_leak_detector_options,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryDefaultLeakDetectorOptionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryDefaultLeakDetectorOptionsConstMeta => const TaskConstMeta(
+            debugName: "default_leak_detector_options",
+            argNames: [],
+        );
+        
+
+@override StorageFormatOptions crateApiMemoryDefaultStorageFormatOptions()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_storage_format_options,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryDefaultStorageFormatOptionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryDefaultStorageFormatOptionsConstMeta => const TaskConstMeta(
+            debugName: "default_storage_format_options",
+            argNames: [],
+        );
+        
+
+@override Future<List<LeakReport>> crateApiMemoryDetectMemoryLeaks({required LeakDetectorOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_leak_detector_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_leak_report,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryDetectMemoryLeaksConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryDetectMemoryLeaksConstMeta => const TaskConstMeta(
+            debugName: "detect_memory_leaks",
+            argNames: ["options"],
+        );
+        
+
+@override Future<MemoryDiff> crateApiMemoryDiffMemoryHistory({required BigInt beforeMs , required BigInt afterMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(beforeMs, serializer);
+sse_encode_u_64(afterMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory_diff,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryDiffMemoryHistoryConstMeta,
+            argValues: [beforeMs, afterMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryDiffMemoryHistoryConstMeta => const TaskConstMeta(
+            debugName: "diff_memory_history",
+            argNames: ["beforeMs", "afterMs"],
+        );
+        
+
+@override Future<MemoryDiff> crateApiMemoryDiffMemorySnapshots({required Memory before , required Memory after })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_memory(before, serializer);
+sse_encode_box_autoadd_memory(after, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory_diff,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryDiffMemorySnapshotsConstMeta,
+            argValues: [before, after],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryDiffMemorySnapshotsConstMeta => const TaskConstMeta(
+            debugName: "diff_memory_snapshots",
+            argNames: ["before", "after"],
+        );
+        
+
+@override String crateApiMemoryFormatStorage({required Storage storage , required StorageFormatOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_storage(storage, serializer);
+sse_encode_box_autoadd_storage_format_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryFormatStorageConstMeta,
+            argValues: [storage, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryFormatStorageConstMeta => const TaskConstMeta(
+            debugName: "format_storage",
+            argNames: ["storage", "options"],
+        );
+        
+
+@override Future<CgroupNode> crateApiMemoryGetCgroupTree()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cgroup_node,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetCgroupTreeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetCgroupTreeConstMeta => const TaskConstMeta(
+            debugName: "get_cgroup_tree",
+            argNames: [],
+        );
+        
+
+@override Future<List<(int,ProcessMemoryInfo)>> crateApiMemoryGetFirstProcessMemoryUsage({required BigInt n })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(n, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_record_u_32_process_memory_info,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetFirstProcessMemoryUsageConstMeta,
+            argValues: [n],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetFirstProcessMemoryUsageConstMeta => const TaskConstMeta(
+            debugName: "get_first_process_memory_usage",
+            argNames: ["n"],
+        );
+        
+
+@override Future<MemoryBreakdown> crateApiMemoryGetMemoryBreakdown()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory_breakdown,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetMemoryBreakdownConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetMemoryBreakdownConstMeta => const TaskConstMeta(
+            debugName: "get_memory_breakdown",
+            argNames: [],
+        );
+        
+
+@override Future<Memory> crateApiMemoryGetMemoryInfo()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetMemoryInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetMemoryInfoConstMeta => const TaskConstMeta(
+            debugName: "get_memory_info",
+            argNames: [],
+        );
+        
+
+@override Future<MemoryMetric> crateApiMemoryGetMemoryMetric()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory_metric,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetMemoryMetricConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetMemoryMetricConstMeta => const TaskConstMeta(
+            debugName: "get_memory_metric",
+            argNames: [],
+        );
+        
+
+@override Future<List<ProcessGroup>> crateApiMemoryGetProcessGroups({required GroupBy by })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_group_by(by, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_process_group,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetProcessGroupsConstMeta,
+            argValues: [by],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetProcessGroupsConstMeta => const TaskConstMeta(
+            debugName: "get_process_groups",
+            argNames: ["by"],
+        );
+        
+
+@override Future<ProcessMemoryMaps> crateApiMemoryGetProcessMemoryMaps({required int pid })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(pid, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_process_memory_maps,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetProcessMemoryMapsConstMeta,
+            argValues: [pid],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetProcessMemoryMapsConstMeta => const TaskConstMeta(
+            debugName: "get_process_memory_maps",
+            argNames: ["pid"],
+        );
+        
+
+@override Future<List<ProcessTreeNode>> crateApiMemoryGetProcessTree()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_process_tree_node,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetProcessTreeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetProcessTreeConstMeta => const TaskConstMeta(
+            debugName: "get_process_tree",
+            argNames: [],
+        );
+        
+
+@override UnitSystem crateApiMemoryGetUnitSystem()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit_system,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryGetUnitSystemConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryGetUnitSystemConstMeta => const TaskConstMeta(
+            debugName: "get_unit_system",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiMemoryInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<List<SignalResult>> crateApiMemoryKillProcessTree({required int pid , required BigInt graceMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(pid, serializer);
+sse_encode_u_64(graceMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_signal_result,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryKillProcessTreeConstMeta,
+            argValues: [pid, graceMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryKillProcessTreeConstMeta => const TaskConstMeta(
+            debugName: "kill_process_tree",
+            argNames: ["pid", "graceMs"],
+        );
+        
+
+@override Future<List<AlertRule>> crateApiMemoryListAlertRules()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_alert_rule,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryListAlertRulesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryListAlertRulesConstMeta => const TaskConstMeta(
+            debugName: "list_alert_rules",
+            argNames: [],
+        );
+        
+
+@override Stream<Memory> crateApiMemoryMemoryStream({required BigInt intervalMs })  { 
+            final sink = RustStreamSink<Memory>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_memory_Sse(sink, serializer);
+sse_encode_u_64(intervalMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryMemoryStreamConstMeta,
+            argValues: [sink, intervalMs],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiMemoryMemoryStreamConstMeta => const TaskConstMeta(
+            debugName: "memory_stream",
+            argNames: ["sink", "intervalMs"],
+        );
+        
+
+@override Future<void> crateApiMemoryOpenHistoryStore({required String path , required HistoryStoreOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_box_autoadd_history_store_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryOpenHistoryStoreConstMeta,
+            argValues: [path, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryOpenHistoryStoreConstMeta => const TaskConstMeta(
+            debugName: "open_history_store",
+            argNames: ["path", "options"],
+        );
+        
+
+@override Storage crateApiMemoryParseStorage({required String input })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(input, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_storage,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryParseStorageConstMeta,
+            argValues: [input],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryParseStorageConstMeta => const TaskConstMeta(
+            debugName: "parse_storage",
+            argNames: ["input"],
+        );
+        
+
+@override void crateApiMemoryPauseMemoryStream()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryPauseMemoryStreamConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryPauseMemoryStreamConstMeta => const TaskConstMeta(
+            debugName: "pause_memory_stream",
+            argNames: [],
+        );
+        
+
+@override Future<List<HistoryBucket>> crateApiMemoryQueryMemoryHistory({required HistorySeries series , required BigInt startMs , required BigInt endMs , required BigInt resolutionMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_history_series(series, serializer);
+sse_encode_u_64(startMs, serializer);
+sse_encode_u_64(endMs, serializer);
+sse_encode_u_64(resolutionMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_history_bucket,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryQueryMemoryHistoryConstMeta,
+            argValues: [series, startMs, endMs, resolutionMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryQueryMemoryHistoryConstMeta => const TaskConstMeta(
+            debugName: "query_memory_history",
+            argNames: ["series", "startMs", "endMs", "resolutionMs"],
+        );
+        
+
+@override Future<ProcessPage> crateApiMemoryQueryProcesses({required ProcessQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_process_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_process_page,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryQueryProcessesConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryQueryProcessesConstMeta => const TaskConstMeta(
+            debugName: "query_processes",
+            argNames: ["query"],
+        );
+        
+
+@override Future<List<HistoryBucket>> crateApiMemoryQueryStoredHistory({required HistorySeries series , required BigInt startMs , required BigInt endMs , required BigInt resolutionMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_history_series(series, serializer);
+sse_encode_u_64(startMs, serializer);
+sse_encode_u_64(endMs, serializer);
+sse_encode_u_64(resolutionMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_history_bucket,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryQueryStoredHistoryConstMeta,
+            argValues: [series, startMs, endMs, resolutionMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryQueryStoredHistoryConstMeta => const TaskConstMeta(
+            debugName: "query_stored_history",
+            argNames: ["series", "startMs", "endMs", "resolutionMs"],
+        );
+        
+
+@override Future<void> crateApiMemoryRemoveAlertRule({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryRemoveAlertRuleConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryRemoveAlertRuleConstMeta => const TaskConstMeta(
+            debugName: "remove_alert_rule",
+            argNames: ["id"],
+        );
+        
+
+@override void crateApiMemoryResumeMemoryStream()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryResumeMemoryStreamConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryResumeMemoryStreamConstMeta => const TaskConstMeta(
+            debugName: "resume_memory_stream",
+            argNames: [],
+        );
+        
+
+@override Future<SignalResult> crateApiMemorySendSignal({required int pid , required BigInt startTime , required Signal signal })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(pid, serializer);
+sse_encode_u_64(startTime, serializer);
+sse_encode_signal(signal, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_signal_result,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemorySendSignalConstMeta,
+            argValues: [pid, startTime, signal],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemorySendSignalConstMeta => const TaskConstMeta(
+            debugName: "send_signal",
+            argNames: ["pid", "startTime", "signal"],
+        );
+        
+
+@override Future<void> crateApiMemorySetMemoryHistoryCapacity({required BigInt capacity })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(capacity, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemorySetMemoryHistoryCapacityConstMeta,
+            argValues: [capacity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemorySetMemoryHistoryCapacityConstMeta => const TaskConstMeta(
+            debugName: "set_memory_history_capacity",
+            argNames: ["capacity"],
+        );
+        
+
+@override Future<void> crateApiMemorySetMemoryMetric({required MemoryMetric metric })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_memory_metric(metric, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemorySetMemoryMetricConstMeta,
+            argValues: [metric],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemorySetMemoryMetricConstMeta => const TaskConstMeta(
+            debugName: "set_memory_metric",
+            argNames: ["metric"],
+        );
+        
+
+@override void crateApiMemorySetMemoryStreamInterval({required BigInt intervalMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(intervalMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemorySetMemoryStreamIntervalConstMeta,
+            argValues: [intervalMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemorySetMemoryStreamIntervalConstMeta => const TaskConstMeta(
+            debugName: "set_memory_stream_interval",
+            argNames: ["intervalMs"],
+        );
+        
+
+@override void crateApiMemorySetUnitSystem({required UnitSystem system })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_unit_system(system, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemorySetUnitSystemConstMeta,
+            argValues: [system],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemorySetUnitSystemConstMeta => const TaskConstMeta(
+            debugName: "set_unit_system",
+            argNames: ["system"],
+        );
+        
+
+@override Future<void> crateApiMemoryStartRecording({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryStartRecordingConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryStartRecordingConstMeta => const TaskConstMeta(
+            debugName: "start_recording",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> crateApiMemoryStartReplay({required String path , required double speed })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_f_64(speed, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryStartReplayConstMeta,
+            argValues: [path, speed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryStartReplayConstMeta => const TaskConstMeta(
+            debugName: "start_replay",
+            argNames: ["path", "speed"],
+        );
+        
+
+@override Future<void> crateApiMemoryStopRecording()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryStopRecordingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryStopRecordingConstMeta => const TaskConstMeta(
+            debugName: "stop_recording",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiMemoryStopReplay()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryStopReplayConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryStopReplayConstMeta => const TaskConstMeta(
+            debugName: "stop_replay",
+            argNames: [],
+        );
+        
+
+@override StorageFormatOptions crateApiMemoryStorageFormatOptionsForLocale({required String locale })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(locale, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_storage_format_options,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryStorageFormatOptionsForLocaleConstMeta,
+            argValues: [locale],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryStorageFormatOptionsForLocaleConstMeta => const TaskConstMeta(
+            debugName: "storage_format_options_for_locale",
+            argNames: ["locale"],
+        );
+        
+
+@override double crateApiMemoryStorageToFloat({required Storage storage })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_storage(storage, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryStorageToFloatConstMeta,
+            argValues: [storage],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryStorageToFloatConstMeta => const TaskConstMeta(
+            debugName: "storage_to_float",
+            argNames: ["storage"],
+        );
+        
+
+@override String crateApiMemoryStorageToString({required Storage storage , UnitSystem? system })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_storage(storage, serializer);
+sse_encode_opt_box_autoadd_unit_system(system, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryStorageToStringConstMeta,
+            argValues: [storage, system],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryStorageToStringConstMeta => const TaskConstMeta(
+            debugName: "storage_to_string",
+            argNames: ["storage", "system"],
+        );
+        
+
+@override String crateApiMemoryUnitToString({required StorageUnit unit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_storage_unit(unit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryUnitToStringConstMeta,
+            argValues: [unit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryUnitToStringConstMeta => const TaskConstMeta(
+            debugName: "unit_to_string",
+            argNames: ["unit"],
+        );
+        
+
+@override Future<void> crateApiMemoryUpdateAlertRule({required AlertRule rule })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_alert_rule(rule, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_memory_error,
+        )
+        ,
+            constMeta: kCrateApiMemoryUpdateAlertRuleConstMeta,
+            argValues: [rule],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMemoryUpdateAlertRuleConstMeta => const TaskConstMeta(
+            debugName: "update_alert_rule",
+            argNames: ["rule"],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected Map<int, ProcessMemoryInfo> dco_decode_Map_u_32_process_memory_info_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_u_32_process_memory_info(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected RustStreamSink<AlertEvent> dco_decode_StreamSink_alert_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<Memory> dco_decode_StreamSink_memory_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AlertCondition dco_decode_alert_condition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return AlertCondition_UsedMemoryPercentAbove(percent: dco_decode_f_64(raw[1]),);
+case 1: return AlertCondition_UsedSwapAbove(bytes: dco_decode_u_64(raw[1]),);
+case 2: return AlertCondition_ProcessTotalMemoryAbove(name: dco_decode_String(raw[1]),bytes: dco_decode_u_64(raw[2]),);
+case 3: return AlertCondition_ProcessGrowthAbove(bytesPerMinute: dco_decode_u_64(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected AlertEvent dco_decode_alert_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return AlertEvent(ruleId: dco_decode_u_32(arr[0]),
+ruleName: dco_decode_String(arr[1]),
+kind: dco_decode_alert_event_kind(arr[2]),
+pid: dco_decode_opt_box_autoadd_u_32(arr[3]),
+processName: dco_decode_opt_String(arr[4]),
+value: dco_decode_f_64(arr[5]),
+message: dco_decode_String(arr[6]),
+timestamp: dco_decode_u_64(arr[7]),); }
+
+@protected AlertEventKind dco_decode_alert_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AlertEventKind.values[raw as int]; }
+
+@protected AlertRule dco_decode_alert_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return AlertRule(id: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+condition: dco_decode_alert_condition(arr[2]),
+forMs: dco_decode_u_64(arr[3]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AlertCondition dco_decode_box_autoadd_alert_condition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_alert_condition(raw); }
+
+@protected AlertRule dco_decode_box_autoadd_alert_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_alert_rule(raw); }
+
+@protected GroupBy dco_decode_box_autoadd_group_by(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_group_by(raw); }
+
+@protected HistorySeries dco_decode_box_autoadd_history_series(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_history_series(raw); }
+
+@protected HistoryStoreOptions dco_decode_box_autoadd_history_store_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_history_store_options(raw); }
+
+@protected LeakDetectorOptions dco_decode_box_autoadd_leak_detector_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_leak_detector_options(raw); }
+
+@protected Memory dco_decode_box_autoadd_memory(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_memory(raw); }
+
+@protected ProcessQuery dco_decode_box_autoadd_process_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_process_query(raw); }
+
+@protected Storage dco_decode_box_autoadd_storage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_storage(raw); }
+
+@protected StorageFormatOptions dco_decode_box_autoadd_storage_format_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_storage_format_options(raw); }
+
+@protected StorageRate dco_decode_box_autoadd_storage_rate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_storage_rate(raw); }
+
+@protected StorageUnit dco_decode_box_autoadd_storage_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_storage_unit(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected UnitSystem dco_decode_box_autoadd_unit_system(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_unit_system(raw); }
+
+@protected CgroupEvents dco_decode_cgroup_events(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CgroupEvents(low: dco_decode_u_64(arr[0]),
+high: dco_decode_u_64(arr[1]),
+max: dco_decode_u_64(arr[2]),
+oom: dco_decode_u_64(arr[3]),
+oomKill: dco_decode_u_64(arr[4]),); }
+
+@protected CgroupMemory dco_decode_cgroup_memory(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CgroupMemory(current: dco_decode_opt_box_autoadd_storage(arr[0]),
+max: dco_decode_opt_box_autoadd_storage(arr[1]),
+high: dco_decode_opt_box_autoadd_storage(arr[2]),
+stat: dco_decode_list_record_string_u_64(arr[3]),
+events: dco_decode_cgroup_events(arr[4]),); }
+
+@protected CgroupNode dco_decode_cgroup_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CgroupNode(path: dco_decode_String(arr[0]),
+containerId: dco_decode_opt_String(arr[1]),
+memory: dco_decode_cgroup_memory(arr[2]),
+processes: dco_decode_list_prim_u_32_strict(arr[3]),
+children: dco_decode_list_cgroup_node(arr[4]),); }
+
+@protected CompactionStats dco_decode_compaction_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CompactionStats(kept: dco_decode_u_64(arr[0]),
+removed: dco_decode_u_64(arr[1]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected GroupBy dco_decode_group_by(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return GroupBy_Name();
+case 1: return GroupBy_Exe();
+case 2: return GroupBy_User();
+case 3: return GroupBy_Pattern(pattern: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected HistoryBucket dco_decode_history_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return HistoryBucket(start: dco_decode_u_64(arr[0]),
+end: dco_decode_u_64(arr[1]),
+min: dco_decode_u_64(arr[2]),
+max: dco_decode_u_64(arr[3]),
+avg: dco_decode_u_64(arr[4]),
+count: dco_decode_u_32(arr[5]),); }
+
+@protected HistorySeries dco_decode_history_series(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return HistorySeries_UsedMemory();
+case 1: return HistorySeries_UsedSwap();
+case 2: return HistorySeries_ProcessMemory(dco_decode_u_32(raw[1]),);
+case 3: return HistorySeries_ProcessTotalMemory(dco_decode_u_32(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected HistoryStoreOptions dco_decode_history_store_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return HistoryStoreOptions(recordIntervalMs: dco_decode_u_64(arr[0]),
+topProcesses: dco_decode_u_32(arr[1]),
+compactionIntervalMs: dco_decode_u_64(arr[2]),
+retention: dco_decode_retention_policy(arr[3]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected LeakDetectorOptions dco_decode_leak_detector_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return LeakDetectorOptions(windowMs: dco_decode_u_64(arr[0]),
+minSamples: dco_decode_u_32(arr[1]),
+minSlopeBytesPerMinute: dco_decode_u_64(arr[2]),
+minRSquared: dco_decode_f_64(arr[3]),
+minMonotonicRatio: dco_decode_f_64(arr[4]),); }
+
+@protected LeakReport dco_decode_leak_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return LeakReport(pid: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+samples: dco_decode_u_32(arr[2]),
+slopeBytesPerMinute: dco_decode_f_64(arr[3]),
+rSquared: dco_decode_f_64(arr[4]),
+monotonicRatio: dco_decode_f_64(arr[5]),
+confidence: dco_decode_f_64(arr[6]),
+current: dco_decode_storage(arr[7]),
+growth: dco_decode_storage(arr[8]),
+timeToOomMs: dco_decode_opt_box_autoadd_u_64(arr[9]),); }
+
+@protected List<AlertRule> dco_decode_list_alert_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_alert_rule).toList(); }
+
+@protected List<CgroupNode> dco_decode_list_cgroup_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cgroup_node).toList(); }
+
+@protected List<HistoryBucket> dco_decode_list_history_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_history_bucket).toList(); }
+
+@protected List<LeakReport> dco_decode_list_leak_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_leak_report).toList(); }
+
+@protected List<MappingGroup> dco_decode_list_mapping_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mapping_group).toList(); }
+
+@protected List<MemoryMapping> dco_decode_list_memory_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_memory_mapping).toList(); }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<ProcessDiff> dco_decode_list_process_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_process_diff).toList(); }
+
+@protected List<ProcessGroup> dco_decode_list_process_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_process_group).toList(); }
+
+@protected List<ProcessTreeNode> dco_decode_list_process_tree_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_process_tree_node).toList(); }
+
+@protected List<(String,BigInt)> dco_decode_list_record_string_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_u_64).toList(); }
+
+@protected List<(int,ProcessMemoryInfo)> dco_decode_list_record_u_32_process_memory_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_32_process_memory_info).toList(); }
+
+@protected List<SignalResult> dco_decode_list_signal_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_signal_result).toList(); }
+
+@protected MappingGroup dco_decode_mapping_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return MappingGroup(name: dco_decode_String(arr[0]),
+kind: dco_decode_mapping_kind(arr[1]),
+count: dco_decode_u_32(arr[2]),
+size: dco_decode_storage(arr[3]),
+rss: dco_decode_storage(arr[4]),
+pss: dco_decode_storage(arr[5]),
+dirty: dco_decode_storage(arr[6]),
+swap: dco_decode_storage(arr[7]),); }
+
+@protected MappingKind dco_decode_mapping_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MappingKind.values[raw as int]; }
+
+@protected Memory dco_decode_memory(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return Memory(totalMemory: dco_decode_storage(arr[0]),
+usedMemory: dco_decode_storage(arr[1]),
+totalSwap: dco_decode_storage(arr[2]),
+usedSwap: dco_decode_storage(arr[3]),
+processes: dco_decode_Map_u_32_process_memory_info_None(arr[4]),); }
+
+@protected MemoryBreakdown dco_decode_memory_breakdown(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 18) throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+                return MemoryBreakdown(total: dco_decode_storage(arr[0]),
+free: dco_decode_storage(arr[1]),
+available: dco_decode_storage(arr[2]),
+buffers: dco_decode_storage(arr[3]),
+cached: dco_decode_storage(arr[4]),
+swapCached: dco_decode_storage(arr[5]),
+shmem: dco_decode_storage(arr[6]),
+slabReclaimable: dco_decode_storage(arr[7]),
+slabUnreclaimable: dco_decode_storage(arr[8]),
+active: dco_decode_storage(arr[9]),
+inactive: dco_decode_storage(arr[10]),
+dirty: dco_decode_storage(arr[11]),
+writeback: dco_decode_storage(arr[12]),
+hugepagesTotal: dco_decode_u_64(arr[13]),
+hugepagesFree: dco_decode_u_64(arr[14]),
+hugepageSize: dco_decode_storage(arr[15]),
+committedAs: dco_decode_storage(arr[16]),
+commitLimit: dco_decode_storage(arr[17]),); }
+
+@protected MemoryDiff dco_decode_memory_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return MemoryDiff(beforeTimestamp: dco_decode_u_64(arr[0]),
+afterTimestamp: dco_decode_u_64(arr[1]),
+totalMemoryDelta: dco_decode_i_64(arr[2]),
+usedMemoryDelta: dco_decode_i_64(arr[3]),
+totalSwapDelta: dco_decode_i_64(arr[4]),
+usedSwapDelta: dco_decode_i_64(arr[5]),
+processes: dco_decode_list_process_diff(arr[6]),); }
+
+@protected MemoryError dco_decode_memory_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return MemoryError_PermissionDenied(message: dco_decode_String(raw[1]),);
+case 1: return MemoryError_ProcessGone(pid: dco_decode_u_32(raw[1]),);
+case 2: return MemoryError_NotFound(message: dco_decode_String(raw[1]),);
+case 3: return MemoryError_TreeInconsistent(message: dco_decode_String(raw[1]),);
+case 4: return MemoryError_Unsupported(message: dco_decode_String(raw[1]),);
+case 5: return MemoryError_InvalidInput(message: dco_decode_String(raw[1]),);
+case 6: return MemoryError_Io(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected MemoryMapping dco_decode_memory_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return MemoryMapping(start: dco_decode_u_64(arr[0]),
+end: dco_decode_u_64(arr[1]),
+permissions: dco_decode_String(arr[2]),
+offset: dco_decode_u_64(arr[3]),
+kind: dco_decode_mapping_kind(arr[4]),
+path: dco_decode_opt_String(arr[5]),
+size: dco_decode_storage(arr[6]),
+rss: dco_decode_storage(arr[7]),
+pss: dco_decode_storage(arr[8]),
+dirty: dco_decode_storage(arr[9]),
+swap: dco_decode_storage(arr[10]),); }
+
+@protected MemoryMetric dco_decode_memory_metric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MemoryMetric.values[raw as int]; }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected Storage? dco_decode_opt_box_autoadd_storage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_storage(raw); }
+
+@protected StorageRate? dco_decode_opt_box_autoadd_storage_rate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_storage_rate(raw); }
+
+@protected StorageUnit? dco_decode_opt_box_autoadd_storage_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_storage_unit(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected UnitSystem? dco_decode_opt_box_autoadd_unit_system(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_unit_system(raw); }
+
+@protected Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_32_strict(raw); }
+
+@protected ProcessChange dco_decode_process_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProcessChange.values[raw as int]; }
+
+@protected ProcessDiff dco_decode_process_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return ProcessDiff(pid: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+change: dco_decode_process_change(arr[2]),
+memoryBefore: dco_decode_u_64(arr[3]),
+memoryAfter: dco_decode_u_64(arr[4]),
+memoryDelta: dco_decode_i_64(arr[5]),
+totalMemoryBefore: dco_decode_u_64(arr[6]),
+totalMemoryAfter: dco_decode_u_64(arr[7]),
+totalMemoryDelta: dco_decode_i_64(arr[8]),); }
+
+@protected ProcessFilter dco_decode_process_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ProcessFilter(text: dco_decode_opt_String(arr[0]),
+pattern: dco_decode_opt_String(arr[1]),
+minMemory: dco_decode_opt_box_autoadd_u_64(arr[2]),
+rootsOnly: dco_decode_bool(arr[3]),
+pids: dco_decode_opt_list_prim_u_32_strict(arr[4]),
+user: dco_decode_opt_String(arr[5]),); }
+
+@protected ProcessGroup dco_decode_process_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ProcessGroup(key: dco_decode_opt_String(arr[0]),
+count: dco_decode_u_32(arr[1]),
+pids: dco_decode_list_prim_u_32_strict(arr[2]),
+memory: dco_decode_storage(arr[3]),); }
+
+@protected ProcessMemoryInfo dco_decode_process_memory_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return ProcessMemoryInfo(memory: dco_decode_storage(arr[0]),
+rawMemory: dco_decode_u_64(arr[1]),
+name: dco_decode_String(arr[2]),
+exe: dco_decode_opt_String(arr[3]),
+parent: dco_decode_opt_box_autoadd_u_32(arr[4]),
+root: dco_decode_opt_String(arr[5]),
+totalMemory: dco_decode_storage(arr[6]),
+pss: dco_decode_opt_box_autoadd_storage(arr[7]),
+uss: dco_decode_opt_box_autoadd_storage(arr[8]),
+swap: dco_decode_opt_box_autoadd_storage(arr[9]),
+user: dco_decode_opt_String(arr[10]),
+startTime: dco_decode_u_64(arr[11]),
+cgroup: dco_decode_opt_String(arr[12]),
+growthRate: dco_decode_opt_box_autoadd_storage_rate(arr[13]),); }
+
+@protected ProcessMemoryMaps dco_decode_process_memory_maps(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ProcessMemoryMaps(pid: dco_decode_u_32(arr[0]),
+mappings: dco_decode_list_memory_mapping(arr[1]),
+groups: dco_decode_list_mapping_group(arr[2]),); }
+
+@protected ProcessPage dco_decode_process_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ProcessPage(items: dco_decode_list_record_u_32_process_memory_info(arr[0]),
+total: dco_decode_u_32(arr[1]),); }
+
+@protected ProcessQuery dco_decode_process_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ProcessQuery(filter: dco_decode_process_filter(arr[0]),
+sortKey: dco_decode_process_sort_key(arr[1]),
+direction: dco_decode_sort_direction(arr[2]),
+offset: dco_decode_u_32(arr[3]),
+limit: dco_decode_u_32(arr[4]),); }
+
+@protected ProcessSortKey dco_decode_process_sort_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProcessSortKey.values[raw as int]; }
+
+@protected ProcessTreeNode dco_decode_process_tree_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ProcessTreeNode(pid: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+depth: dco_decode_u_32(arr[2]),
+memory: dco_decode_storage(arr[3]),
+totalMemory: dco_decode_storage(arr[4]),
+children: dco_decode_list_process_tree_node(arr[5]),); }
+
+@protected (String,BigInt) dco_decode_record_string_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_u_64(arr[1]),); }
+
+@protected (int,ProcessMemoryInfo) dco_decode_record_u_32_process_memory_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_process_memory_info(arr[1]),); }
+
+@protected RetentionPolicy dco_decode_retention_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RetentionPolicy(maxAgeMs: dco_decode_u_64(arr[0]),
+fullResolutionMs: dco_decode_u_64(arr[1]),
+compactedIntervalMs: dco_decode_u_64(arr[2]),); }
+
+@protected Signal dco_decode_signal(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Signal.values[raw as int]; }
+
+@protected SignalResult dco_decode_signal_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SignalResult(pid: dco_decode_u_32(arr[0]),
+signal: dco_decode_signal(arr[1]),
+status: dco_decode_signal_status(arr[2]),); }
+
+@protected SignalStatus dco_decode_signal_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SignalStatus_Sent();
+case 1: return SignalStatus_PermissionDenied();
+case 2: return SignalStatus_NotFound();
+case 3: return SignalStatus_InvalidPid();
+case 4: return SignalStatus_Unsupported();
+case 5: return SignalStatus_Failed(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SortDirection dco_decode_sort_direction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SortDirection.values[raw as int]; }
+
+@protected Storage dco_decode_storage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Storage(quotient: dco_decode_u_64(arr[0]),
+remainder: dco_decode_u_64(arr[1]),
+unit: dco_decode_storage_unit(arr[2]),); }
+
+@protected StorageFormatOptions dco_decode_storage_format_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return StorageFormatOptions(precision: dco_decode_u_32(arr[0]),
+unit: dco_decode_opt_box_autoadd_storage_unit(arr[1]),
+system: dco_decode_opt_box_autoadd_unit_system(arr[2]),
+trimZeros: dco_decode_bool(arr[3]),
+style: dco_decode_storage_style(arr[4]),
+decimalSeparator: dco_decode_String(arr[5]),
+thousandsSeparator: dco_decode_opt_String(arr[6]),); }
+
+@protected StorageRate dco_decode_storage_rate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return StorageRate(bytesPerSecond: dco_decode_f_64(arr[0]),); }
+
+@protected StorageStyle dco_decode_storage_style(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StorageStyle.values[raw as int]; }
+
+@protected StorageUnit dco_decode_storage_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StorageUnit.values[raw as int]; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UnitSystem dco_decode_unit_system(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UnitSystem.values[raw as int]; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected Map<int, ProcessMemoryInfo> sse_decode_Map_u_32_process_memory_info_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_u_32_process_memory_info(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected RustStreamSink<AlertEvent> sse_decode_StreamSink_alert_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<Memory> sse_decode_StreamSink_memory_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AlertCondition sse_decode_alert_condition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_percent = sse_decode_f_64(deserializer);
+return AlertCondition_UsedMemoryPercentAbove(percent: var_percent);case 1: var var_bytes = sse_decode_u_64(deserializer);
+return AlertCondition_UsedSwapAbove(bytes: var_bytes);case 2: var var_name = sse_decode_String(deserializer);
+var var_bytes = sse_decode_u_64(deserializer);
+return AlertCondition_ProcessTotalMemoryAbove(name: var_name, bytes: var_bytes);case 3: var var_bytesPerMinute = sse_decode_u_64(deserializer);
+return AlertCondition_ProcessGrowthAbove(bytesPerMinute: var_bytesPerMinute); default: throw UnimplementedError(''); }
+             }
+
+@protected AlertEvent sse_decode_alert_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ruleId = sse_decode_u_32(deserializer);
+var var_ruleName = sse_decode_String(deserializer);
+var var_kind = sse_decode_alert_event_kind(deserializer);
+var var_pid = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_processName = sse_decode_opt_String(deserializer);
+var var_value = sse_decode_f_64(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return AlertEvent(ruleId: var_ruleId, ruleName: var_ruleName, kind: var_kind, pid: var_pid, processName: var_processName, value: var_value, message: var_message, timestamp: var_timestamp); }
+
+@protected AlertEventKind sse_decode_alert_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AlertEventKind.values[inner]; }
+
+@protected AlertRule sse_decode_alert_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_condition = sse_decode_alert_condition(deserializer);
+var var_forMs = sse_decode_u_64(deserializer);
+return AlertRule(id: var_id, name: var_name, condition: var_condition, forMs: var_forMs); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AlertCondition sse_decode_box_autoadd_alert_condition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_alert_condition(deserializer)); }
+
+@protected AlertRule sse_decode_box_autoadd_alert_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_alert_rule(deserializer)); }
+
+@protected GroupBy sse_decode_box_autoadd_group_by(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_group_by(deserializer)); }
+
+@protected HistorySeries sse_decode_box_autoadd_history_series(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_history_series(deserializer)); }
+
+@protected HistoryStoreOptions sse_decode_box_autoadd_history_store_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_history_store_options(deserializer)); }
+
+@protected LeakDetectorOptions sse_decode_box_autoadd_leak_detector_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_leak_detector_options(deserializer)); }
+
+@protected Memory sse_decode_box_autoadd_memory(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_memory(deserializer)); }
+
+@protected ProcessQuery sse_decode_box_autoadd_process_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_process_query(deserializer)); }
+
+@protected Storage sse_decode_box_autoadd_storage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_storage(deserializer)); }
+
+@protected StorageFormatOptions sse_decode_box_autoadd_storage_format_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_storage_format_options(deserializer)); }
+
+@protected StorageRate sse_decode_box_autoadd_storage_rate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_storage_rate(deserializer)); }
+
+@protected StorageUnit sse_decode_box_autoadd_storage_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_storage_unit(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected UnitSystem sse_decode_box_autoadd_unit_system(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_unit_system(deserializer)); }
+
+@protected CgroupEvents sse_decode_cgroup_events(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_low = sse_decode_u_64(deserializer);
+var var_high = sse_decode_u_64(deserializer);
+var var_max = sse_decode_u_64(deserializer);
+var var_oom = sse_decode_u_64(deserializer);
+var var_oomKill = sse_decode_u_64(deserializer);
+return CgroupEvents(low: var_low, high: var_high, max: var_max, oom: var_oom, oomKill: var_oomKill); }
+
+@protected CgroupMemory sse_decode_cgroup_memory(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_current = sse_decode_opt_box_autoadd_storage(deserializer);
+var var_max = sse_decode_opt_box_autoadd_storage(deserializer);
+var var_high = sse_decode_opt_box_autoadd_storage(deserializer);
+var var_stat = sse_decode_list_record_string_u_64(deserializer);
+var var_events = sse_decode_cgroup_events(deserializer);
+return CgroupMemory(current: var_current, max: var_max, high: var_high, stat: var_stat, events: var_events); }
+
+@protected CgroupNode sse_decode_cgroup_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_containerId = sse_decode_opt_String(deserializer);
+var var_memory = sse_decode_cgroup_memory(deserializer);
+var var_processes = sse_decode_list_prim_u_32_strict(deserializer);
+var var_children = sse_decode_list_cgroup_node(deserializer);
+return CgroupNode(path: var_path, containerId: var_containerId, memory: var_memory, processes: var_processes, children: var_children); }
+
+@protected CompactionStats sse_decode_compaction_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kept = sse_decode_u_64(deserializer);
+var var_removed = sse_decode_u_64(deserializer);
+return CompactionStats(kept: var_kept, removed: var_removed); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected GroupBy sse_decode_group_by(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return GroupBy_Name();case 1: return GroupBy_Exe();case 2: return GroupBy_User();case 3: var var_pattern = sse_decode_String(deserializer);
+return GroupBy_Pattern(pattern: var_pattern); default: throw UnimplementedError(''); }
+             }
+
+@protected HistoryBucket sse_decode_history_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_64(deserializer);
+var var_end = sse_decode_u_64(deserializer);
+var var_min = sse_decode_u_64(deserializer);
+var var_max = sse_decode_u_64(deserializer);
+var var_avg = sse_decode_u_64(deserializer);
+var var_count = sse_decode_u_32(deserializer);
+return HistoryBucket(start: var_start, end: var_end, min: var_min, max: var_max, avg: var_avg, count: var_count); }
+
+@protected HistorySeries sse_decode_history_series(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return HistorySeries_UsedMemory();case 1: return HistorySeries_UsedSwap();case 2: var var_field0 = sse_decode_u_32(deserializer);
+return HistorySeries_ProcessMemory(var_field0);case 3: var var_field0 = sse_decode_u_32(deserializer);
+return HistorySeries_ProcessTotalMemory(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected HistoryStoreOptions sse_decode_history_store_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_recordIntervalMs = sse_decode_u_64(deserializer);
+var var_topProcesses = sse_decode_u_32(deserializer);
+var var_compactionIntervalMs = sse_decode_u_64(deserializer);
+var var_retention = sse_decode_retention_policy(deserializer);
+return HistoryStoreOptions(recordIntervalMs: var_recordIntervalMs, topProcesses: var_topProcesses, compactionIntervalMs: var_compactionIntervalMs, retention: var_retention); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected LeakDetectorOptions sse_decode_leak_detector_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_windowMs = sse_decode_u_64(deserializer);
+var var_minSamples = sse_decode_u_32(deserializer);
+var var_minSlopeBytesPerMinute = sse_decode_u_64(deserializer);
+var var_minRSquared = sse_decode_f_64(deserializer);
+var var_minMonotonicRatio = sse_decode_f_64(deserializer);
+return LeakDetectorOptions(windowMs: var_windowMs, minSamples: var_minSamples, minSlopeBytesPerMinute: var_minSlopeBytesPerMinute, minRSquared: var_minRSquared, minMonotonicRatio: var_minMonotonicRatio); }
+
+@protected LeakReport sse_decode_leak_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_samples = sse_decode_u_32(deserializer);
+var var_slopeBytesPerMinute = sse_decode_f_64(deserializer);
+var var_rSquared = sse_decode_f_64(deserializer);
+var var_monotonicRatio = sse_decode_f_64(deserializer);
+var var_confidence = sse_decode_f_64(deserializer);
+var var_current = sse_decode_storage(deserializer);
+var var_growth = sse_decode_storage(deserializer);
+var var_timeToOomMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return LeakReport(pid: var_pid, name: var_name, samples: var_samples, slopeBytesPerMinute: var_slopeBytesPerMinute, rSquared: var_rSquared, monotonicRatio: var_monotonicRatio, confidence: var_confidence, current: var_current, growth: var_growth, timeToOomMs: var_timeToOomMs); }
+
+@protected List<AlertRule> sse_decode_list_alert_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AlertRule>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_alert_rule(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CgroupNode> sse_decode_list_cgroup_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CgroupNode>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cgroup_node(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HistoryBucket> sse_decode_list_history_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HistoryBucket>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_history_bucket(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LeakReport> sse_decode_list_leak_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LeakReport>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_leak_report(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MappingGroup> sse_decode_list_mapping_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MappingGroup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mapping_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MemoryMapping> sse_decode_list_memory_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MemoryMapping>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_memory_mapping(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<ProcessDiff> sse_decode_list_process_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProcessDiff>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_process_diff(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProcessGroup> sse_decode_list_process_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProcessGroup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_process_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProcessTreeNode> sse_decode_list_process_tree_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProcessTreeNode>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_process_tree_node(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,BigInt)> sse_decode_list_record_string_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,BigInt)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_u_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(int,ProcessMemoryInfo)> sse_decode_list_record_u_32_process_memory_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(int,ProcessMemoryInfo)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_32_process_memory_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SignalResult> sse_decode_list_signal_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SignalResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_signal_result(deserializer)); }
+        return ans_;
+         }
+
+@protected MappingGroup sse_decode_mapping_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_kind = sse_decode_mapping_kind(deserializer);
+var var_count = sse_decode_u_32(deserializer);
+var var_size = sse_decode_storage(deserializer);
+var var_rss = sse_decode_storage(deserializer);
+var var_pss = sse_decode_storage(deserializer);
+var var_dirty = sse_decode_storage(deserializer);
+var var_swap = sse_decode_storage(deserializer);
+return MappingGroup(name: var_name, kind: var_kind, count: var_count, size: var_size, rss: var_rss, pss: var_pss, dirty: var_dirty, swap: var_swap); }
+
+@protected MappingKind sse_decode_mapping_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MappingKind.values[inner]; }
+
+@protected Memory sse_decode_memory(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalMemory = sse_decode_storage(deserializer);
+var var_usedMemory = sse_decode_storage(deserializer);
+var var_totalSwap = sse_decode_storage(deserializer);
+var var_usedSwap = sse_decode_storage(deserializer);
+var var_processes = sse_decode_Map_u_32_process_memory_info_None(deserializer);
+return Memory(totalMemory: var_totalMemory, usedMemory: var_usedMemory, totalSwap: var_totalSwap, usedSwap: var_usedSwap, processes: var_processes); }
+
+@protected MemoryBreakdown sse_decode_memory_breakdown(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_total = sse_decode_storage(deserializer);
+var var_free = sse_decode_storage(deserializer);
+var var_available = sse_decode_storage(deserializer);
+var var_buffers = sse_decode_storage(deserializer);
+var var_cached = sse_decode_storage(deserializer);
+var var_swapCached = sse_decode_storage(deserializer);
+var var_shmem = sse_decode_storage(deserializer);
+var var_slabReclaimable = sse_decode_storage(deserializer);
+var var_slabUnreclaimable = sse_decode_storage(deserializer);
+var var_active = sse_decode_storage(deserializer);
+var var_inactive = sse_decode_storage(deserializer);
+var var_dirty = sse_decode_storage(deserializer);
+var var_writeback = sse_decode_storage(deserializer);
+var var_hugepagesTotal = sse_decode_u_64(deserializer);
+var var_hugepagesFree = sse_decode_u_64(deserializer);
+var var_hugepageSize = sse_decode_storage(deserializer);
+var var_committedAs = sse_decode_storage(deserializer);
+var var_commitLimit = sse_decode_storage(deserializer);
+return MemoryBreakdown(total: var_total, free: var_free, available: var_available, buffers: var_buffers, cached: var_cached, swapCached: var_swapCached, shmem: var_shmem, slabReclaimable: var_slabReclaimable, slabUnreclaimable: var_slabUnreclaimable, active: var_active, inactive: var_inactive, dirty: var_dirty, writeback: var_writeback, hugepagesTotal: var_hugepagesTotal, hugepagesFree: var_hugepagesFree, hugepageSize: var_hugepageSize, committedAs: var_committedAs, commitLimit: var_commitLimit); }
+
+@protected MemoryDiff sse_decode_memory_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_beforeTimestamp = sse_decode_u_64(deserializer);
+var var_afterTimestamp = sse_decode_u_64(deserializer);
+var var_totalMemoryDelta = sse_decode_i_64(deserializer);
+var var_usedMemoryDelta = sse_decode_i_64(deserializer);
+var var_totalSwapDelta = sse_decode_i_64(deserializer);
+var var_usedSwapDelta = sse_decode_i_64(deserializer);
+var var_processes = sse_decode_list_process_diff(deserializer);
+return MemoryDiff(beforeTimestamp: var_beforeTimestamp, afterTimestamp: var_afterTimestamp, totalMemoryDelta: var_totalMemoryDelta, usedMemoryDelta: var_usedMemoryDelta, totalSwapDelta: var_totalSwapDelta, usedSwapDelta: var_usedSwapDelta, processes: var_processes); }
+
+@protected MemoryError sse_decode_memory_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_message = sse_decode_String(deserializer);
+return MemoryError_PermissionDenied(message: var_message);case 1: var var_pid = sse_decode_u_32(deserializer);
+return MemoryError_ProcessGone(pid: var_pid);case 2: var var_message = sse_decode_String(deserializer);
+return MemoryError_NotFound(message: var_message);case 3: var var_message = sse_decode_String(deserializer);
+return MemoryError_TreeInconsistent(message: var_message);case 4: var var_message = sse_decode_String(deserializer);
+return MemoryError_Unsupported(message: var_message);case 5: var var_message = sse_decode_String(deserializer);
+return MemoryError_InvalidInput(message: var_message);case 6: var var_message = sse_decode_String(deserializer);
+return MemoryError_Io(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected MemoryMapping sse_decode_memory_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_64(deserializer);
+var var_end = sse_decode_u_64(deserializer);
+var var_permissions = sse_decode_String(deserializer);
+var var_offset = sse_decode_u_64(deserializer);
+var var_kind = sse_decode_mapping_kind(deserializer);
+var var_path = sse_decode_opt_String(deserializer);
+var var_size = sse_decode_storage(deserializer);
+var var_rss = sse_decode_storage(deserializer);
+var var_pss = sse_decode_storage(deserializer);
+var var_dirty = sse_decode_storage(deserializer);
+var var_swap = sse_decode_storage(deserializer);
+return MemoryMapping(start: var_start, end: var_end, permissions: var_permissions, offset: var_offset, kind: var_kind, path: var_path, size: var_size, rss: var_rss, pss: var_pss, dirty: var_dirty, swap: var_swap); }
+
+@protected MemoryMetric sse_decode_memory_metric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MemoryMetric.values[inner]; }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Storage? sse_decode_opt_box_autoadd_storage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_storage(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected StorageRate? sse_decode_opt_box_autoadd_storage_rate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_storage_rate(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected StorageUnit? sse_decode_opt_box_autoadd_storage_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_storage_unit(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UnitSystem? sse_decode_opt_box_autoadd_unit_system(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_unit_system(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint32List? sse_decode_opt_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_32_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProcessChange sse_decode_process_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProcessChange.values[inner]; }
+
+@protected ProcessDiff sse_decode_process_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_change = sse_decode_process_change(deserializer);
+var var_memoryBefore = sse_decode_u_64(deserializer);
+var var_memoryAfter = sse_decode_u_64(deserializer);
+var var_memoryDelta = sse_decode_i_64(deserializer);
+var var_totalMemoryBefore = sse_decode_u_64(deserializer);
+var var_totalMemoryAfter = sse_decode_u_64(deserializer);
+var var_totalMemoryDelta = sse_decode_i_64(deserializer);
+return ProcessDiff(pid: var_pid, name: var_name, change: var_change, memoryBefore: var_memoryBefore, memoryAfter: var_memoryAfter, memoryDelta: var_memoryDelta, totalMemoryBefore: var_totalMemoryBefore, totalMemoryAfter: var_totalMemoryAfter, totalMemoryDelta: var_totalMemoryDelta); }
+
+@protected ProcessFilter sse_decode_process_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_opt_String(deserializer);
+var var_pattern = sse_decode_opt_String(deserializer);
+var var_minMemory = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_rootsOnly = sse_decode_bool(deserializer);
+var var_pids = sse_decode_opt_list_prim_u_32_strict(deserializer);
+var var_user = sse_decode_opt_String(deserializer);
+return ProcessFilter(text: var_text, pattern: var_pattern, minMemory: var_minMemory, rootsOnly: var_rootsOnly, pids: var_pids, user: var_user); }
+
+@protected ProcessGroup sse_decode_process_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_opt_String(deserializer);
+var var_count = sse_decode_u_32(deserializer);
+var var_pids = sse_decode_list_prim_u_32_strict(deserializer);
+var var_memory = sse_decode_storage(deserializer);
+return ProcessGroup(key: var_key, count: var_count, pids: var_pids, memory: var_memory); }
+
+@protected ProcessMemoryInfo sse_decode_process_memory_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_memory = sse_decode_storage(deserializer);
+var var_rawMemory = sse_decode_u_64(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_exe = sse_decode_opt_String(deserializer);
+var var_parent = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_root = sse_decode_opt_String(deserializer);
+var var_totalMemory = sse_decode_storage(deserializer);
+var var_pss = sse_decode_opt_box_autoadd_storage(deserializer);
+var var_uss = sse_decode_opt_box_autoadd_storage(deserializer);
+var var_swap = sse_decode_opt_box_autoadd_storage(deserializer);
+var var_user = sse_decode_opt_String(deserializer);
+var var_startTime = sse_decode_u_64(deserializer);
+var var_cgroup = sse_decode_opt_String(deserializer);
+var var_growthRate = sse_decode_opt_box_autoadd_storage_rate(deserializer);
+return ProcessMemoryInfo(memory: var_memory, rawMemory: var_rawMemory, name: var_name, exe: var_exe, parent: var_parent, root: var_root, totalMemory: var_totalMemory, pss: var_pss, uss: var_uss, swap: var_swap, user: var_user, startTime: var_startTime, cgroup: var_cgroup, growthRate: var_growthRate); }
+
+@protected ProcessMemoryMaps sse_decode_process_memory_maps(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_mappings = sse_decode_list_memory_mapping(deserializer);
+var var_groups = sse_decode_list_mapping_group(deserializer);
+return ProcessMemoryMaps(pid: var_pid, mappings: var_mappings, groups: var_groups); }
+
+@protected ProcessPage sse_decode_process_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_items = sse_decode_list_record_u_32_process_memory_info(deserializer);
+var var_total = sse_decode_u_32(deserializer);
+return ProcessPage(items: var_items, total: var_total); }
+
+@protected ProcessQuery sse_decode_process_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_filter = sse_decode_process_filter(deserializer);
+var var_sortKey = sse_decode_process_sort_key(deserializer);
+var var_direction = sse_decode_sort_direction(deserializer);
+var var_offset = sse_decode_u_32(deserializer);
+var var_limit = sse_decode_u_32(deserializer);
+return ProcessQuery(filter: var_filter, sortKey: var_sortKey, direction: var_direction, offset: var_offset, limit: var_limit); }
+
+@protected ProcessSortKey sse_decode_process_sort_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProcessSortKey.values[inner]; }
+
+@protected ProcessTreeNode sse_decode_process_tree_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_depth = sse_decode_u_32(deserializer);
+var var_memory = sse_decode_storage(deserializer);
+var var_totalMemory = sse_decode_storage(deserializer);
+var var_children = sse_decode_list_process_tree_node(deserializer);
+return ProcessTreeNode(pid: var_pid, name: var_name, depth: var_depth, memory: var_memory, totalMemory: var_totalMemory, children: var_children); }
+
+@protected (String,BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_u_64(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,ProcessMemoryInfo) sse_decode_record_u_32_process_memory_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_process_memory_info(deserializer);
+return (var_field0, var_field1); }
+
+@protected RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxAgeMs = sse_decode_u_64(deserializer);
+var var_fullResolutionMs = sse_decode_u_64(deserializer);
+var var_compactedIntervalMs = sse_decode_u_64(deserializer);
+return RetentionPolicy(maxAgeMs: var_maxAgeMs, fullResolutionMs: var_fullResolutionMs, compactedIntervalMs: var_compactedIntervalMs); }
+
+@protected Signal sse_decode_signal(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Signal.values[inner]; }
+
+@protected SignalResult sse_decode_signal_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_signal = sse_decode_signal(deserializer);
+var var_status = sse_decode_signal_status(deserializer);
+return SignalResult(pid: var_pid, signal: var_signal, status: var_status); }
+
+@protected SignalStatus sse_decode_signal_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SignalStatus_Sent();case 1: return SignalStatus_PermissionDenied();case 2: return SignalStatus_NotFound();case 3: return SignalStatus_InvalidPid();case 4: return SignalStatus_Unsupported();case 5: var var_message = sse_decode_String(deserializer);
+return SignalStatus_Failed(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected SortDirection sse_decode_sort_direction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SortDirection.values[inner]; }
+
+@protected Storage sse_decode_storage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_quotient = sse_decode_u_64(deserializer);
+var var_remainder = sse_decode_u_64(deserializer);
+var var_unit = sse_decode_storage_unit(deserializer);
+return Storage(quotient: var_quotient, remainder: var_remainder, unit: var_unit); }
+
+@protected StorageFormatOptions sse_decode_storage_format_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_precision = sse_decode_u_32(deserializer);
+var var_unit = sse_decode_opt_box_autoadd_storage_unit(deserializer);
+var var_system = sse_decode_opt_box_autoadd_unit_system(deserializer);
+var var_trimZeros = sse_decode_bool(deserializer);
+var var_style = sse_decode_storage_style(deserializer);
+var var_decimalSeparator = sse_decode_String(deserializer);
+var var_thousandsSeparator = sse_decode_opt_String(deserializer);
+return StorageFormatOptions(precision: var_precision, unit: var_unit, system: var_system, trimZeros: var_trimZeros, style: var_style, decimalSeparator: var_decimalSeparator, thousandsSeparator: var_thousandsSeparator); }
+
+@protected StorageRate sse_decode_storage_rate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bytesPerSecond = sse_decode_f_64(deserializer);
+return StorageRate(bytesPerSecond: var_bytesPerSecond); }
+
+@protected StorageStyle sse_decode_storage_style(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return StorageStyle.values[inner]; }
+
+@protected StorageUnit sse_decode_storage_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return StorageUnit.values[inner]; }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UnitSystem sse_decode_unit_system(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UnitSystem.values[inner]; }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Map_u_32_process_memory_info_None(Map<int, ProcessMemoryInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_process_memory_info(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_StreamSink_alert_event_Sse(RustStreamSink<AlertEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_alert_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_memory_Sse(RustStreamSink<Memory> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_memory,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_alert_condition(AlertCondition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case AlertCondition_UsedMemoryPercentAbove(percent: final percent): sse_encode_i_32(0, serializer); sse_encode_f_64(percent, serializer);
+case AlertCondition_UsedSwapAbove(bytes: final bytes): sse_encode_i_32(1, serializer); sse_encode_u_64(bytes, serializer);
+case AlertCondition_ProcessTotalMemoryAbove(name: final name,bytes: final bytes): sse_encode_i_32(2, serializer); sse_encode_String(name, serializer);
+sse_encode_u_64(bytes, serializer);
+case AlertCondition_ProcessGrowthAbove(bytesPerMinute: final bytesPerMinute): sse_encode_i_32(3, serializer); sse_encode_u_64(bytesPerMinute, serializer);
+  } }
+
+@protected void sse_encode_alert_event(AlertEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.ruleId, serializer);
+sse_encode_String(self.ruleName, serializer);
+sse_encode_alert_event_kind(self.kind, serializer);
+sse_encode_opt_box_autoadd_u_32(self.pid, serializer);
+sse_encode_opt_String(self.processName, serializer);
+sse_encode_f_64(self.value, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_u_64(self.timestamp, serializer);
+ }
+
+@protected void sse_encode_alert_event_kind(AlertEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_alert_rule(AlertRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_alert_condition(self.condition, serializer);
+sse_encode_u_64(self.forMs, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_alert_condition(AlertCondition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_alert_condition(self, serializer); }
+
+@protected void sse_encode_box_autoadd_alert_rule(AlertRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_alert_rule(self, serializer); }
+
+@protected void sse_encode_box_autoadd_group_by(GroupBy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_group_by(self, serializer); }
+
+@protected void sse_encode_box_autoadd_history_series(HistorySeries self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_history_series(self, serializer); }
+
+@protected void sse_encode_box_autoadd_history_store_options(HistoryStoreOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_history_store_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_leak_detector_options(LeakDetectorOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_leak_detector_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_memory(Memory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_memory(self, serializer); }
+
+@protected void sse_encode_box_autoadd_process_query(ProcessQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_process_query(self, serializer); }
+
+@protected void sse_encode_box_autoadd_storage(Storage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_storage(self, serializer); }
+
+@protected void sse_encode_box_autoadd_storage_format_options(StorageFormatOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_storage_format_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_storage_rate(StorageRate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_storage_rate(self, serializer); }
+
+@protected void sse_encode_box_autoadd_storage_unit(StorageUnit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_storage_unit(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_unit_system(UnitSystem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_unit_system(self, serializer); }
+
+@protected void sse_encode_cgroup_events(CgroupEvents self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.low, serializer);
+sse_encode_u_64(self.high, serializer);
+sse_encode_u_64(self.max, serializer);
+sse_encode_u_64(self.oom, serializer);
+sse_encode_u_64(self.oomKill, serializer);
+ }
+
+@protected void sse_encode_cgroup_memory(CgroupMemory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_storage(self.current, serializer);
+sse_encode_opt_box_autoadd_storage(self.max, serializer);
+sse_encode_opt_box_autoadd_storage(self.high, serializer);
+sse_encode_list_record_string_u_64(self.stat, serializer);
+sse_encode_cgroup_events(self.events, serializer);
+ }
+
+@protected void sse_encode_cgroup_node(CgroupNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_opt_String(self.containerId, serializer);
+sse_encode_cgroup_memory(self.memory, serializer);
+sse_encode_list_prim_u_32_strict(self.processes, serializer);
+sse_encode_list_cgroup_node(self.children, serializer);
+ }
+
+@protected void sse_encode_compaction_stats(CompactionStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.kept, serializer);
+sse_encode_u_64(self.removed, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_group_by(GroupBy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case GroupBy_Name(): sse_encode_i_32(0, serializer); case GroupBy_Exe(): sse_encode_i_32(1, serializer); case GroupBy_User(): sse_encode_i_32(2, serializer); case GroupBy_Pattern(pattern: final pattern): sse_encode_i_32(3, serializer); sse_encode_String(pattern, serializer);
+  } }
+
+@protected void sse_encode_history_bucket(HistoryBucket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.start, serializer);
+sse_encode_u_64(self.end, serializer);
+sse_encode_u_64(self.min, serializer);
+sse_encode_u_64(self.max, serializer);
+sse_encode_u_64(self.avg, serializer);
+sse_encode_u_32(self.count, serializer);
+ }
+
+@protected void sse_encode_history_series(HistorySeries self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case HistorySeries_UsedMemory(): sse_encode_i_32(0, serializer); case HistorySeries_UsedSwap(): sse_encode_i_32(1, serializer); case HistorySeries_ProcessMemory(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_u_32(field0, serializer);
+case HistorySeries_ProcessTotalMemory(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_u_32(field0, serializer);
+  } }
+
+@protected void sse_encode_history_store_options(HistoryStoreOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.recordIntervalMs, serializer);
+sse_encode_u_32(self.topProcesses, serializer);
+sse_encode_u_64(self.compactionIntervalMs, serializer);
+sse_encode_retention_policy(self.retention, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_leak_detector_options(LeakDetectorOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.windowMs, serializer);
+sse_encode_u_32(self.minSamples, serializer);
+sse_encode_u_64(self.minSlopeBytesPerMinute, serializer);
+sse_encode_f_64(self.minRSquared, serializer);
+sse_encode_f_64(self.minMonotonicRatio, serializer);
+ }
+
+@protected void sse_encode_leak_report(LeakReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.samples, serializer);
+sse_encode_f_64(self.slopeBytesPerMinute, serializer);
+sse_encode_f_64(self.rSquared, serializer);
+sse_encode_f_64(self.monotonicRatio, serializer);
+sse_encode_f_64(self.confidence, serializer);
+sse_encode_storage(self.current, serializer);
+sse_encode_storage(self.growth, serializer);
+sse_encode_opt_box_autoadd_u_64(self.timeToOomMs, serializer);
+ }
+
+@protected void sse_encode_list_alert_rule(List<AlertRule> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_alert_rule(item, serializer); } }
+
+@protected void sse_encode_list_cgroup_node(List<CgroupNode> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cgroup_node(item, serializer); } }
+
+@protected void sse_encode_list_history_bucket(List<HistoryBucket> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_history_bucket(item, serializer); } }
+
+@protected void sse_encode_list_leak_report(List<LeakReport> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_leak_report(item, serializer); } }
+
+@protected void sse_encode_list_mapping_group(List<MappingGroup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mapping_group(item, serializer); } }
+
+@protected void sse_encode_list_memory_mapping(List<MemoryMapping> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_memory_mapping(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_process_diff(List<ProcessDiff> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_process_diff(item, serializer); } }
+
+@protected void sse_encode_list_process_group(List<ProcessGroup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_process_group(item, serializer); } }
+
+@protected void sse_encode_list_process_tree_node(List<ProcessTreeNode> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_process_tree_node(item, serializer); } }
+
+@protected void sse_encode_list_record_string_u_64(List<(String,BigInt)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_u_64(item, serializer); } }
+
+@protected void sse_encode_list_record_u_32_process_memory_info(List<(int,ProcessMemoryInfo)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_32_process_memory_info(item, serializer); } }
+
+@protected void sse_encode_list_signal_result(List<SignalResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_signal_result(item, serializer); } }
+
+@protected void sse_encode_mapping_group(MappingGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_mapping_kind(self.kind, serializer);
+sse_encode_u_32(self.count, serializer);
+sse_encode_storage(self.size, serializer);
+sse_encode_storage(self.rss, serializer);
+sse_encode_storage(self.pss, serializer);
+sse_encode_storage(self.dirty, serializer);
+sse_encode_storage(self.swap, serializer);
+ }
+
+@protected void sse_encode_mapping_kind(MappingKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_memory(Memory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_storage(self.totalMemory, serializer);
+sse_encode_storage(self.usedMemory, serializer);
+sse_encode_storage(self.totalSwap, serializer);
+sse_encode_storage(self.usedSwap, serializer);
+sse_encode_Map_u_32_process_memory_info_None(self.processes, serializer);
+ }
+
+@protected void sse_encode_memory_breakdown(MemoryBreakdown self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_storage(self.total, serializer);
+sse_encode_storage(self.free, serializer);
+sse_encode_storage(self.available, serializer);
+sse_encode_storage(self.buffers, serializer);
+sse_encode_storage(self.cached, serializer);
+sse_encode_storage(self.swapCached, serializer);
+sse_encode_storage(self.shmem, serializer);
+sse_encode_storage(self.slabReclaimable, serializer);
+sse_encode_storage(self.slabUnreclaimable, serializer);
+sse_encode_storage(self.active, serializer);
+sse_encode_storage(self.inactive, serializer);
+sse_encode_storage(self.dirty, serializer);
+sse_encode_storage(self.writeback, serializer);
+sse_encode_u_64(self.hugepagesTotal, serializer);
+sse_encode_u_64(self.hugepagesFree, serializer);
+sse_encode_storage(self.hugepageSize, serializer);
+sse_encode_storage(self.committedAs, serializer);
+sse_encode_storage(self.commitLimit, serializer);
+ }
+
+@protected void sse_encode_memory_diff(MemoryDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.beforeTimestamp, serializer);
+sse_encode_u_64(self.afterTimestamp, serializer);
+sse_encode_i_64(self.totalMemoryDelta, serializer);
+sse_encode_i_64(self.usedMemoryDelta, serializer);
+sse_encode_i_64(self.totalSwapDelta, serializer);
+sse_encode_i_64(self.usedSwapDelta, serializer);
+sse_encode_list_process_diff(self.processes, serializer);
+ }
+
+@protected void sse_encode_memory_error(MemoryError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case MemoryError_PermissionDenied(message: final message): sse_encode_i_32(0, serializer); sse_encode_String(message, serializer);
+case MemoryError_ProcessGone(pid: final pid): sse_encode_i_32(1, serializer); sse_encode_u_32(pid, serializer);
+case MemoryError_NotFound(message: final message): sse_encode_i_32(2, serializer); sse_encode_String(message, serializer);
+case MemoryError_TreeInconsistent(message: final message): sse_encode_i_32(3, serializer); sse_encode_String(message, serializer);
+case MemoryError_Unsupported(message: final message): sse_encode_i_32(4, serializer); sse_encode_String(message, serializer);
+case MemoryError_InvalidInput(message: final message): sse_encode_i_32(5, serializer); sse_encode_String(message, serializer);
+case MemoryError_Io(message: final message): sse_encode_i_32(6, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_memory_mapping(MemoryMapping self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.start, serializer);
+sse_encode_u_64(self.end, serializer);
+sse_encode_String(self.permissions, serializer);
+sse_encode_u_64(self.offset, serializer);
+sse_encode_mapping_kind(self.kind, serializer);
+sse_encode_opt_String(self.path, serializer);
+sse_encode_storage(self.size, serializer);
+sse_encode_storage(self.rss, serializer);
+sse_encode_storage(self.pss, serializer);
+sse_encode_storage(self.dirty, serializer);
+sse_encode_storage(self.swap, serializer);
+ }
+
+@protected void sse_encode_memory_metric(MemoryMetric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_storage(Storage? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_storage(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_storage_rate(StorageRate? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_storage_rate(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_storage_unit(StorageUnit? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_storage_unit(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_unit_system(UnitSystem? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_unit_system(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_32_strict(Uint32List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_32_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_process_change(ProcessChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_process_diff(ProcessDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_process_change(self.change, serializer);
+sse_encode_u_64(self.memoryBefore, serializer);
+sse_encode_u_64(self.memoryAfter, serializer);
+sse_encode_i_64(self.memoryDelta, serializer);
+sse_encode_u_64(self.totalMemoryBefore, serializer);
+sse_encode_u_64(self.totalMemoryAfter, serializer);
+sse_encode_i_64(self.totalMemoryDelta, serializer);
+ }
+
+@protected void sse_encode_process_filter(ProcessFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.text, serializer);
+sse_encode_opt_String(self.pattern, serializer);
+sse_encode_opt_box_autoadd_u_64(self.minMemory, serializer);
+sse_encode_bool(self.rootsOnly, serializer);
+sse_encode_opt_list_prim_u_32_strict(self.pids, serializer);
+sse_encode_opt_String(self.user, serializer);
+ }
+
+@protected void sse_encode_process_group(ProcessGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.key, serializer);
+sse_encode_u_32(self.count, serializer);
+sse_encode_list_prim_u_32_strict(self.pids, serializer);
+sse_encode_storage(self.memory, serializer);
+ }
+
+@protected void sse_encode_process_memory_info(ProcessMemoryInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_storage(self.memory, serializer);
+sse_encode_u_64(self.rawMemory, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.exe, serializer);
+sse_encode_opt_box_autoadd_u_32(self.parent, serializer);
+sse_encode_opt_String(self.root, serializer);
+sse_encode_storage(self.totalMemory, serializer);
+sse_encode_opt_box_autoadd_storage(self.pss, serializer);
+sse_encode_opt_box_autoadd_storage(self.uss, serializer);
+sse_encode_opt_box_autoadd_storage(self.swap, serializer);
+sse_encode_opt_String(self.user, serializer);
+sse_encode_u_64(self.startTime, serializer);
+sse_encode_opt_String(self.cgroup, serializer);
+sse_encode_opt_box_autoadd_storage_rate(self.growthRate, serializer);
+ }
+
+@protected void sse_encode_process_memory_maps(ProcessMemoryMaps self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_list_memory_mapping(self.mappings, serializer);
+sse_encode_list_mapping_group(self.groups, serializer);
+ }
+
+@protected void sse_encode_process_page(ProcessPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_u_32_process_memory_info(self.items, serializer);
+sse_encode_u_32(self.total, serializer);
+ }
+
+@protected void sse_encode_process_query(ProcessQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_process_filter(self.filter, serializer);
+sse_encode_process_sort_key(self.sortKey, serializer);
+sse_encode_sort_direction(self.direction, serializer);
+sse_encode_u_32(self.offset, serializer);
+sse_encode_u_32(self.limit, serializer);
+ }
+
+@protected void sse_encode_process_sort_key(ProcessSortKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_process_tree_node(ProcessTreeNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.depth, serializer);
+sse_encode_storage(self.memory, serializer);
+sse_encode_storage(self.totalMemory, serializer);
+sse_encode_list_process_tree_node(self.children, serializer);
+ }
+
+@protected void sse_encode_record_string_u_64((String,BigInt) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_u_64(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_process_memory_info((int,ProcessMemoryInfo) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_process_memory_info(self.$2, serializer);
+ }
+
+@protected void sse_encode_retention_policy(RetentionPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.maxAgeMs, serializer);
+sse_encode_u_64(self.fullResolutionMs, serializer);
+sse_encode_u_64(self.compactedIntervalMs, serializer);
+ }
+
+@protected void sse_encode_signal(Signal self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_signal_result(SignalResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_signal(self.signal, serializer);
+sse_encode_signal_status(self.status, serializer);
+ }
+
+@protected void sse_encode_signal_status(SignalStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SignalStatus_Sent(): sse_encode_i_32(0, serializer); case SignalStatus_PermissionDenied(): sse_encode_i_32(1, serializer); case SignalStatus_NotFound(): sse_encode_i_32(2, serializer); case SignalStatus_InvalidPid(): sse_encode_i_32(3, serializer); case SignalStatus_Unsupported(): sse_encode_i_32(4, serializer); case SignalStatus_Failed(message: final message): sse_encode_i_32(5, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_sort_direction(SortDirection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_storage(Storage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.quotient, serializer);
+sse_encode_u_64(self.remainder, serializer);
+sse_encode_storage_unit(self.unit, serializer);
+ }
+
+@protected void sse_encode_storage_format_options(StorageFormatOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.precision, serializer);
+sse_encode_opt_box_autoadd_storage_unit(self.unit, serializer);
+sse_encode_opt_box_autoadd_unit_system(self.system, serializer);
+sse_encode_bool(self.trimZeros, serializer);
+sse_encode_storage_style(self.style, serializer);
+sse_encode_String(self.decimalSeparator, serializer);
+sse_encode_opt_String(self.thousandsSeparator, serializer);
+ }
+
+@protected void sse_encode_storage_rate(StorageRate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.bytesPerSecond, serializer);
+ }
+
+@protected void sse_encode_storage_style(StorageStyle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_storage_unit(StorageUnit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_unit_system(UnitSystem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
@@ -3,238 +3,636 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'alerts.dart';
 import 'api/memory.dart';
+import 'cgroup.dart';
+import 'control.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
+import 'diff.dart';
+import 'error.dart';
+import 'format.dart';
 import 'frb_generated.dart';
+import 'group.dart';
+import 'history.dart';
+import 'leak.dart';
+import 'meminfo.dart';
 import 'memory.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
+import 'query.dart';
+import 'smaps.dart';
+import 'store.dart';
 import 'utils.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  Map<int, ProcessMemoryInfo> dco_decode_Map_u_32_process_memory_info_None(
-      dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+                  
 
-  @protected
-  Storage dco_decode_box_autoadd_storage(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+@protected Map<int, ProcessMemoryInfo> dco_decode_Map_u_32_process_memory_info_None(dynamic raw);
 
-  @protected
-  double dco_decode_f_64(dynamic raw);
+@protected RustStreamSink<AlertEvent> dco_decode_StreamSink_alert_event_Sse(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected RustStreamSink<Memory> dco_decode_StreamSink_memory_Sse(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  List<(int, ProcessMemoryInfo)>
-      dco_decode_list_record_u_32_process_memory_info(dynamic raw);
+@protected AlertCondition dco_decode_alert_condition(dynamic raw);
 
-  @protected
-  Memory dco_decode_memory(dynamic raw);
+@protected AlertEvent dco_decode_alert_event(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected AlertEventKind dco_decode_alert_event_kind(dynamic raw);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+@protected AlertRule dco_decode_alert_rule(dynamic raw);
 
-  @protected
-  ProcessMemoryInfo dco_decode_process_memory_info(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  (int, ProcessMemoryInfo) dco_decode_record_u_32_process_memory_info(
-      dynamic raw);
+@protected AlertCondition dco_decode_box_autoadd_alert_condition(dynamic raw);
 
-  @protected
-  Storage dco_decode_storage(dynamic raw);
+@protected AlertRule dco_decode_box_autoadd_alert_rule(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected GroupBy dco_decode_box_autoadd_group_by(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected HistorySeries dco_decode_box_autoadd_history_series(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected HistoryStoreOptions dco_decode_box_autoadd_history_store_options(dynamic raw);
 
-  @protected
-  Unit dco_decode_unit(dynamic raw);
+@protected LeakDetectorOptions dco_decode_box_autoadd_leak_detector_options(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected Memory dco_decode_box_autoadd_memory(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected ProcessQuery dco_decode_box_autoadd_process_query(dynamic raw);
 
-  @protected
-  Map<int, ProcessMemoryInfo> sse_decode_Map_u_32_process_memory_info_None(
-      SseDeserializer deserializer);
+@protected Storage dco_decode_box_autoadd_storage(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected StorageFormatOptions dco_decode_box_autoadd_storage_format_options(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected StorageRate dco_decode_box_autoadd_storage_rate(dynamic raw);
 
-  @protected
-  Storage sse_decode_box_autoadd_storage(SseDeserializer deserializer);
+@protected StorageUnit dco_decode_box_autoadd_storage_unit(dynamic raw);
 
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected UnitSystem dco_decode_box_autoadd_unit_system(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected CgroupEvents dco_decode_cgroup_events(dynamic raw);
 
-  @protected
-  List<(int, ProcessMemoryInfo)>
-      sse_decode_list_record_u_32_process_memory_info(
-          SseDeserializer deserializer);
+@protected CgroupMemory dco_decode_cgroup_memory(dynamic raw);
 
-  @protected
-  Memory sse_decode_memory(SseDeserializer deserializer);
+@protected CgroupNode dco_decode_cgroup_node(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected CompactionStats dco_decode_compaction_stats(dynamic raw);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  ProcessMemoryInfo sse_decode_process_memory_info(
-      SseDeserializer deserializer);
+@protected GroupBy dco_decode_group_by(dynamic raw);
 
-  @protected
-  (int, ProcessMemoryInfo) sse_decode_record_u_32_process_memory_info(
-      SseDeserializer deserializer);
+@protected HistoryBucket dco_decode_history_bucket(dynamic raw);
 
-  @protected
-  Storage sse_decode_storage(SseDeserializer deserializer);
+@protected HistorySeries dco_decode_history_series(dynamic raw);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected HistoryStoreOptions dco_decode_history_store_options(dynamic raw);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
-  @protected
-  Unit sse_decode_unit(SseDeserializer deserializer);
+@protected LeakDetectorOptions dco_decode_leak_detector_options(dynamic raw);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected LeakReport dco_decode_leak_report(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+@protected List<AlertRule> dco_decode_list_alert_rule(dynamic raw);
 
-  @protected
-  void sse_encode_Map_u_32_process_memory_info_None(
-      Map<int, ProcessMemoryInfo> self, SseSerializer serializer);
+@protected List<CgroupNode> dco_decode_list_cgroup_node(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected List<HistoryBucket> dco_decode_list_history_bucket(dynamic raw);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected List<LeakReport> dco_decode_list_leak_report(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_storage(Storage self, SseSerializer serializer);
+@protected List<MappingGroup> dco_decode_list_mapping_group(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+@protected List<MemoryMapping> dco_decode_list_memory_mapping(dynamic raw);
 
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected List<ProcessDiff> dco_decode_list_process_diff(dynamic raw);
 
-  @protected
-  void sse_encode_list_record_u_32_process_memory_info(
-      List<(int, ProcessMemoryInfo)> self, SseSerializer serializer);
+@protected List<ProcessGroup> dco_decode_list_process_group(dynamic raw);
 
-  @protected
-  void sse_encode_memory(Memory self, SseSerializer serializer);
+@protected List<ProcessTreeNode> dco_decode_list_process_tree_node(dynamic raw);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected List<(String,BigInt)> dco_decode_list_record_string_u_64(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+@protected List<(int,ProcessMemoryInfo)> dco_decode_list_record_u_32_process_memory_info(dynamic raw);
 
-  @protected
-  void sse_encode_process_memory_info(
-      ProcessMemoryInfo self, SseSerializer serializer);
+@protected List<SignalResult> dco_decode_list_signal_result(dynamic raw);
 
-  @protected
-  void sse_encode_record_u_32_process_memory_info(
-      (int, ProcessMemoryInfo) self, SseSerializer serializer);
+@protected MappingGroup dco_decode_mapping_group(dynamic raw);
 
-  @protected
-  void sse_encode_storage(Storage self, SseSerializer serializer);
+@protected MappingKind dco_decode_mapping_kind(dynamic raw);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected Memory dco_decode_memory(dynamic raw);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected MemoryBreakdown dco_decode_memory_breakdown(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected MemoryDiff dco_decode_memory_diff(dynamic raw);
 
-  @protected
-  void sse_encode_unit(Unit self, SseSerializer serializer);
+@protected MemoryError dco_decode_memory_error(dynamic raw);
+
+@protected MemoryMapping dco_decode_memory_mapping(dynamic raw);
+
+@protected MemoryMetric dco_decode_memory_metric(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected Storage? dco_decode_opt_box_autoadd_storage(dynamic raw);
+
+@protected StorageRate? dco_decode_opt_box_autoadd_storage_rate(dynamic raw);
+
+@protected StorageUnit? dco_decode_opt_box_autoadd_storage_unit(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected UnitSystem? dco_decode_opt_box_autoadd_unit_system(dynamic raw);
+
+@protected Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw);
+
+@protected ProcessChange dco_decode_process_change(dynamic raw);
+
+@protected ProcessDiff dco_decode_process_diff(dynamic raw);
+
+@protected ProcessFilter dco_decode_process_filter(dynamic raw);
+
+@protected ProcessGroup dco_decode_process_group(dynamic raw);
+
+@protected ProcessMemoryInfo dco_decode_process_memory_info(dynamic raw);
+
+@protected ProcessMemoryMaps dco_decode_process_memory_maps(dynamic raw);
+
+@protected ProcessPage dco_decode_process_page(dynamic raw);
+
+@protected ProcessQuery dco_decode_process_query(dynamic raw);
+
+@protected ProcessSortKey dco_decode_process_sort_key(dynamic raw);
+
+@protected ProcessTreeNode dco_decode_process_tree_node(dynamic raw);
+
+@protected (String,BigInt) dco_decode_record_string_u_64(dynamic raw);
+
+@protected (int,ProcessMemoryInfo) dco_decode_record_u_32_process_memory_info(dynamic raw);
+
+@protected RetentionPolicy dco_decode_retention_policy(dynamic raw);
+
+@protected Signal dco_decode_signal(dynamic raw);
+
+@protected SignalResult dco_decode_signal_result(dynamic raw);
+
+@protected SignalStatus dco_decode_signal_status(dynamic raw);
+
+@protected SortDirection dco_decode_sort_direction(dynamic raw);
+
+@protected Storage dco_decode_storage(dynamic raw);
+
+@protected StorageFormatOptions dco_decode_storage_format_options(dynamic raw);
+
+@protected StorageRate dco_decode_storage_rate(dynamic raw);
+
+@protected StorageStyle dco_decode_storage_style(dynamic raw);
+
+@protected StorageUnit dco_decode_storage_unit(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected UnitSystem dco_decode_unit_system(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected Map<int, ProcessMemoryInfo> sse_decode_Map_u_32_process_memory_info_None(SseDeserializer deserializer);
+
+@protected RustStreamSink<AlertEvent> sse_decode_StreamSink_alert_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<Memory> sse_decode_StreamSink_memory_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AlertCondition sse_decode_alert_condition(SseDeserializer deserializer);
+
+@protected AlertEvent sse_decode_alert_event(SseDeserializer deserializer);
+
+@protected AlertEventKind sse_decode_alert_event_kind(SseDeserializer deserializer);
+
+@protected AlertRule sse_decode_alert_rule(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AlertCondition sse_decode_box_autoadd_alert_condition(SseDeserializer deserializer);
+
+@protected AlertRule sse_decode_box_autoadd_alert_rule(SseDeserializer deserializer);
+
+@protected GroupBy sse_decode_box_autoadd_group_by(SseDeserializer deserializer);
+
+@protected HistorySeries sse_decode_box_autoadd_history_series(SseDeserializer deserializer);
+
+@protected HistoryStoreOptions sse_decode_box_autoadd_history_store_options(SseDeserializer deserializer);
+
+@protected LeakDetectorOptions sse_decode_box_autoadd_leak_detector_options(SseDeserializer deserializer);
+
+@protected Memory sse_decode_box_autoadd_memory(SseDeserializer deserializer);
+
+@protected ProcessQuery sse_decode_box_autoadd_process_query(SseDeserializer deserializer);
+
+@protected Storage sse_decode_box_autoadd_storage(SseDeserializer deserializer);
+
+@protected StorageFormatOptions sse_decode_box_autoadd_storage_format_options(SseDeserializer deserializer);
+
+@protected StorageRate sse_decode_box_autoadd_storage_rate(SseDeserializer deserializer);
+
+@protected StorageUnit sse_decode_box_autoadd_storage_unit(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected UnitSystem sse_decode_box_autoadd_unit_system(SseDeserializer deserializer);
+
+@protected CgroupEvents sse_decode_cgroup_events(SseDeserializer deserializer);
+
+@protected CgroupMemory sse_decode_cgroup_memory(SseDeserializer deserializer);
+
+@protected CgroupNode sse_decode_cgroup_node(SseDeserializer deserializer);
+
+@protected CompactionStats sse_decode_compaction_stats(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected GroupBy sse_decode_group_by(SseDeserializer deserializer);
+
+@protected HistoryBucket sse_decode_history_bucket(SseDeserializer deserializer);
+
+@protected HistorySeries sse_decode_history_series(SseDeserializer deserializer);
+
+@protected HistoryStoreOptions sse_decode_history_store_options(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected LeakDetectorOptions sse_decode_leak_detector_options(SseDeserializer deserializer);
+
+@protected LeakReport sse_decode_leak_report(SseDeserializer deserializer);
+
+@protected List<AlertRule> sse_decode_list_alert_rule(SseDeserializer deserializer);
+
+@protected List<CgroupNode> sse_decode_list_cgroup_node(SseDeserializer deserializer);
+
+@protected List<HistoryBucket> sse_decode_list_history_bucket(SseDeserializer deserializer);
+
+@protected List<LeakReport> sse_decode_list_leak_report(SseDeserializer deserializer);
+
+@protected List<MappingGroup> sse_decode_list_mapping_group(SseDeserializer deserializer);
+
+@protected List<MemoryMapping> sse_decode_list_memory_mapping(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<ProcessDiff> sse_decode_list_process_diff(SseDeserializer deserializer);
+
+@protected List<ProcessGroup> sse_decode_list_process_group(SseDeserializer deserializer);
+
+@protected List<ProcessTreeNode> sse_decode_list_process_tree_node(SseDeserializer deserializer);
+
+@protected List<(String,BigInt)> sse_decode_list_record_string_u_64(SseDeserializer deserializer);
+
+@protected List<(int,ProcessMemoryInfo)> sse_decode_list_record_u_32_process_memory_info(SseDeserializer deserializer);
+
+@protected List<SignalResult> sse_decode_list_signal_result(SseDeserializer deserializer);
+
+@protected MappingGroup sse_decode_mapping_group(SseDeserializer deserializer);
+
+@protected MappingKind sse_decode_mapping_kind(SseDeserializer deserializer);
+
+@protected Memory sse_decode_memory(SseDeserializer deserializer);
+
+@protected MemoryBreakdown sse_decode_memory_breakdown(SseDeserializer deserializer);
+
+@protected MemoryDiff sse_decode_memory_diff(SseDeserializer deserializer);
+
+@protected MemoryError sse_decode_memory_error(SseDeserializer deserializer);
+
+@protected MemoryMapping sse_decode_memory_mapping(SseDeserializer deserializer);
+
+@protected MemoryMetric sse_decode_memory_metric(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected Storage? sse_decode_opt_box_autoadd_storage(SseDeserializer deserializer);
+
+@protected StorageRate? sse_decode_opt_box_autoadd_storage_rate(SseDeserializer deserializer);
+
+@protected StorageUnit? sse_decode_opt_box_autoadd_storage_unit(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected UnitSystem? sse_decode_opt_box_autoadd_unit_system(SseDeserializer deserializer);
+
+@protected Uint32List? sse_decode_opt_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected ProcessChange sse_decode_process_change(SseDeserializer deserializer);
+
+@protected ProcessDiff sse_decode_process_diff(SseDeserializer deserializer);
+
+@protected ProcessFilter sse_decode_process_filter(SseDeserializer deserializer);
+
+@protected ProcessGroup sse_decode_process_group(SseDeserializer deserializer);
+
+@protected ProcessMemoryInfo sse_decode_process_memory_info(SseDeserializer deserializer);
+
+@protected ProcessMemoryMaps sse_decode_process_memory_maps(SseDeserializer deserializer);
+
+@protected ProcessPage sse_decode_process_page(SseDeserializer deserializer);
+
+@protected ProcessQuery sse_decode_process_query(SseDeserializer deserializer);
+
+@protected ProcessSortKey sse_decode_process_sort_key(SseDeserializer deserializer);
+
+@protected ProcessTreeNode sse_decode_process_tree_node(SseDeserializer deserializer);
+
+@protected (String,BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer);
+
+@protected (int,ProcessMemoryInfo) sse_decode_record_u_32_process_memory_info(SseDeserializer deserializer);
+
+@protected RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer);
+
+@protected Signal sse_decode_signal(SseDeserializer deserializer);
+
+@protected SignalResult sse_decode_signal_result(SseDeserializer deserializer);
+
+@protected SignalStatus sse_decode_signal_status(SseDeserializer deserializer);
+
+@protected SortDirection sse_decode_sort_direction(SseDeserializer deserializer);
+
+@protected Storage sse_decode_storage(SseDeserializer deserializer);
+
+@protected StorageFormatOptions sse_decode_storage_format_options(SseDeserializer deserializer);
+
+@protected StorageRate sse_decode_storage_rate(SseDeserializer deserializer);
+
+@protected StorageStyle sse_decode_storage_style(SseDeserializer deserializer);
+
+@protected StorageUnit sse_decode_storage_unit(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UnitSystem sse_decode_unit_system(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Map_u_32_process_memory_info_None(Map<int, ProcessMemoryInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_alert_event_Sse(RustStreamSink<AlertEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_memory_Sse(RustStreamSink<Memory> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_alert_condition(AlertCondition self, SseSerializer serializer);
+
+@protected void sse_encode_alert_event(AlertEvent self, SseSerializer serializer);
+
+@protected void sse_encode_alert_event_kind(AlertEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_alert_rule(AlertRule self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_alert_condition(AlertCondition self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_alert_rule(AlertRule self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_group_by(GroupBy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_history_series(HistorySeries self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_history_store_options(HistoryStoreOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_leak_detector_options(LeakDetectorOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_memory(Memory self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_process_query(ProcessQuery self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_storage(Storage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_storage_format_options(StorageFormatOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_storage_rate(StorageRate self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_storage_unit(StorageUnit self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_unit_system(UnitSystem self, SseSerializer serializer);
+
+@protected void sse_encode_cgroup_events(CgroupEvents self, SseSerializer serializer);
+
+@protected void sse_encode_cgroup_memory(CgroupMemory self, SseSerializer serializer);
+
+@protected void sse_encode_cgroup_node(CgroupNode self, SseSerializer serializer);
+
+@protected void sse_encode_compaction_stats(CompactionStats self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_group_by(GroupBy self, SseSerializer serializer);
+
+@protected void sse_encode_history_bucket(HistoryBucket self, SseSerializer serializer);
+
+@protected void sse_encode_history_series(HistorySeries self, SseSerializer serializer);
+
+@protected void sse_encode_history_store_options(HistoryStoreOptions self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_leak_detector_options(LeakDetectorOptions self, SseSerializer serializer);
+
+@protected void sse_encode_leak_report(LeakReport self, SseSerializer serializer);
+
+@protected void sse_encode_list_alert_rule(List<AlertRule> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cgroup_node(List<CgroupNode> self, SseSerializer serializer);
+
+@protected void sse_encode_list_history_bucket(List<HistoryBucket> self, SseSerializer serializer);
+
+@protected void sse_encode_list_leak_report(List<LeakReport> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mapping_group(List<MappingGroup> self, SseSerializer serializer);
+
+@protected void sse_encode_list_memory_mapping(List<MemoryMapping> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_process_diff(List<ProcessDiff> self, SseSerializer serializer);
+
+@protected void sse_encode_list_process_group(List<ProcessGroup> self, SseSerializer serializer);
+
+@protected void sse_encode_list_process_tree_node(List<ProcessTreeNode> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_u_64(List<(String,BigInt)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_u_32_process_memory_info(List<(int,ProcessMemoryInfo)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_signal_result(List<SignalResult> self, SseSerializer serializer);
+
+@protected void sse_encode_mapping_group(MappingGroup self, SseSerializer serializer);
+
+@protected void sse_encode_mapping_kind(MappingKind self, SseSerializer serializer);
+
+@protected void sse_encode_memory(Memory self, SseSerializer serializer);
+
+@protected void sse_encode_memory_breakdown(MemoryBreakdown self, SseSerializer serializer);
+
+@protected void sse_encode_memory_diff(MemoryDiff self, SseSerializer serializer);
+
+@protected void sse_encode_memory_error(MemoryError self, SseSerializer serializer);
+
+@protected void sse_encode_memory_mapping(MemoryMapping self, SseSerializer serializer);
+
+@protected void sse_encode_memory_metric(MemoryMetric self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_storage(Storage? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_storage_rate(StorageRate? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_storage_unit(StorageUnit? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_unit_system(UnitSystem? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_32_strict(Uint32List? self, SseSerializer serializer);
+
+@protected void sse_encode_process_change(ProcessChange self, SseSerializer serializer);
+
+@protected void sse_encode_process_diff(ProcessDiff self, SseSerializer serializer);
+
+@protected void sse_encode_process_filter(ProcessFilter self, SseSerializer serializer);
+
+@protected void sse_encode_process_group(ProcessGroup self, SseSerializer serializer);
+
+@protected void sse_encode_process_memory_info(ProcessMemoryInfo self, SseSerializer serializer);
+
+@protected void sse_encode_process_memory_maps(ProcessMemoryMaps self, SseSerializer serializer);
+
+@protected void sse_encode_process_page(ProcessPage self, SseSerializer serializer);
+
+@protected void sse_encode_process_query(ProcessQuery self, SseSerializer serializer);
+
+@protected void sse_encode_process_sort_key(ProcessSortKey self, SseSerializer serializer);
+
+@protected void sse_encode_process_tree_node(ProcessTreeNode self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_u_64((String,BigInt) self, SseSerializer serializer);
+
+@protected void sse_encode_record_u_32_process_memory_info((int,ProcessMemoryInfo) self, SseSerializer serializer);
+
+@protected void sse_encode_retention_policy(RetentionPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_signal(Signal self, SseSerializer serializer);
+
+@protected void sse_encode_signal_result(SignalResult self, SseSerializer serializer);
+
+@protected void sse_encode_signal_status(SignalStatus self, SseSerializer serializer);
+
+@protected void sse_encode_sort_direction(SortDirection self, SseSerializer serializer);
+
+@protected void sse_encode_storage(Storage self, SseSerializer serializer);
+
+@protected void sse_encode_storage_format_options(StorageFormatOptions self, SseSerializer serializer);
+
+@protected void sse_encode_storage_rate(StorageRate self, SseSerializer serializer);
+
+@protected void sse_encode_storage_style(StorageStyle self, SseSerializer serializer);
+
+@protected void sse_encode_storage_unit(StorageUnit self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_unit_system(UnitSystem self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-}
+            
+        }
+        
\ No newline at end of file
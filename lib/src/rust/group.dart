@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'utils.dart';
+part 'group.freezed.dart';
+
+            
+
+            
+
+            @freezed
+                sealed class GroupBy with _$GroupBy  {
+                    const GroupBy._();
+
+                     const factory GroupBy.name() = GroupBy_Name;
+ const factory GroupBy.exe() = GroupBy_Exe;
+ const factory GroupBy.user() = GroupBy_User;
+ /// Matched against the process name. The first capture group, or the
+/// whole match if there is none, is the group key. Processes that do not
+/// match are left out.
+const factory GroupBy.pattern({   required String pattern , }) = GroupBy_Pattern;
+
+                    
+
+                    
+                }
+
+/// Processes sharing the same key.
+class ProcessGroup  {
+                /// `None` for processes whose executable or user could not be read.
+final String? key;
+final int count;
+/// Sorted by PID.
+final Uint32List pids;
+/// Sum of the processes' own memory, by the requested metric.
+final Storage memory;
+
+                const ProcessGroup({this.key ,required this.count ,required this.pids ,required this.memory ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^count.hashCode^pids.hashCode^memory.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessGroup &&
+                runtimeType == other.runtimeType
+                && key == other.key&& count == other.count&& pids == other.pids&& memory == other.memory;
+        
+            }
+            
\ No newline at end of file
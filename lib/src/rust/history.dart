@@ -0,0 +1,59 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'history.freezed.dart';
+
+            
+
+            
+
+            /// Downsampled values of a series over `[start, end)`, in bytes.
+class HistoryBucket  {
+                final BigInt start;
+final BigInt end;
+final BigInt min;
+final BigInt max;
+final BigInt avg;
+final int count;
+
+                const HistoryBucket({required this.start ,required this.end ,required this.min ,required this.max ,required this.avg ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode^min.hashCode^max.hashCode^avg.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistoryBucket &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end&& min == other.min&& max == other.max&& avg == other.avg&& count == other.count;
+        
+            }
+
+@freezed
+                sealed class HistorySeries with _$HistorySeries  {
+                    const HistorySeries._();
+
+                     const factory HistorySeries.usedMemory() = HistorySeries_UsedMemory;
+ const factory HistorySeries.usedSwap() = HistorySeries_UsedSwap;
+ /// Resident memory of a single process.
+const factory HistorySeries.processMemory(  int field0,) = HistorySeries_ProcessMemory;
+ /// Aggregated memory of a process and its descendants.
+const factory HistorySeries.processTotalMemory(  int field0,) = HistorySeries_ProcessTotalMemory;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
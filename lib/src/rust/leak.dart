@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'utils.dart';
+
+
+            
+
+            
+
+            class LeakDetectorOptions  {
+                /// Sliding window the trend is fitted over.
+final BigInt windowMs;
+/// Processes with fewer samples in the window are not considered.
+final int minSamples;
+final BigInt minSlopeBytesPerMinute;
+/// Minimum goodness of fit of the linear trend, between 0 and 1.
+final double minRSquared;
+/// Minimum share of sample-to-sample steps that did not shrink, between
+/// 0 and 1.
+final double minMonotonicRatio;
+
+                const LeakDetectorOptions({required this.windowMs ,required this.minSamples ,required this.minSlopeBytesPerMinute ,required this.minRSquared ,required this.minMonotonicRatio ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => windowMs.hashCode^minSamples.hashCode^minSlopeBytesPerMinute.hashCode^minRSquared.hashCode^minMonotonicRatio.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LeakDetectorOptions &&
+                runtimeType == other.runtimeType
+                && windowMs == other.windowMs&& minSamples == other.minSamples&& minSlopeBytesPerMinute == other.minSlopeBytesPerMinute&& minRSquared == other.minRSquared&& minMonotonicRatio == other.minMonotonicRatio;
+        
+            }
+
+/// A process whose resident memory grew steadily over the window.
+class LeakReport  {
+                final int pid;
+final String name;
+final int samples;
+/// Slope of the fitted linear trend.
+final double slopeBytesPerMinute;
+final double rSquared;
+final double monotonicRatio;
+/// `r_squared` weighted by `monotonic_ratio`, between 0 and 1.
+final double confidence;
+final Storage current;
+/// Growth between the first and last sample in the window.
+final Storage growth;
+/// Time until the system runs out of available memory if the process
+/// keeps growing at this rate.
+final BigInt? timeToOomMs;
+
+                const LeakReport({required this.pid ,required this.name ,required this.samples ,required this.slopeBytesPerMinute ,required this.rSquared ,required this.monotonicRatio ,required this.confidence ,required this.current ,required this.growth ,this.timeToOomMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^name.hashCode^samples.hashCode^slopeBytesPerMinute.hashCode^rSquared.hashCode^monotonicRatio.hashCode^confidence.hashCode^current.hashCode^growth.hashCode^timeToOomMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LeakReport &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& name == other.name&& samples == other.samples&& slopeBytesPerMinute == other.slopeBytesPerMinute&& rSquared == other.rSquared&& monotonicRatio == other.monotonicRatio&& confidence == other.confidence&& current == other.current&& growth == other.growth&& timeToOomMs == other.timeToOomMs;
+        
+            }
+            
\ No newline at end of file
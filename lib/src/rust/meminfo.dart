@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'utils.dart';
+
+
+            
+
+            
+
+            /// System-wide memory breakdown from `/proc/meminfo`.
+///
+/// `used_memory` alone cannot tell page cache, which the kernel drops on
+/// demand, apart from memory that is actually pinned; these counters can.
+class MemoryBreakdown  {
+                final Storage total;
+final Storage free;
+/// Estimate of how much memory can be allocated without swapping.
+final Storage available;
+final Storage buffers;
+/// Page cache, excluding swap cache.
+final Storage cached;
+final Storage swapCached;
+/// Shared memory and tmpfs; counted in `cached` but not reclaimable.
+final Storage shmem;
+final Storage slabReclaimable;
+final Storage slabUnreclaimable;
+final Storage active;
+final Storage inactive;
+final Storage dirty;
+final Storage writeback;
+final BigInt hugepagesTotal;
+final BigInt hugepagesFree;
+final Storage hugepageSize;
+/// Memory that would be needed to back every allocation made so far.
+final Storage committedAs;
+final Storage commitLimit;
+
+                const MemoryBreakdown({required this.total ,required this.free ,required this.available ,required this.buffers ,required this.cached ,required this.swapCached ,required this.shmem ,required this.slabReclaimable ,required this.slabUnreclaimable ,required this.active ,required this.inactive ,required this.dirty ,required this.writeback ,required this.hugepagesTotal ,required this.hugepagesFree ,required this.hugepageSize ,required this.committedAs ,required this.commitLimit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => total.hashCode^free.hashCode^available.hashCode^buffers.hashCode^cached.hashCode^swapCached.hashCode^shmem.hashCode^slabReclaimable.hashCode^slabUnreclaimable.hashCode^active.hashCode^inactive.hashCode^dirty.hashCode^writeback.hashCode^hugepagesTotal.hashCode^hugepagesFree.hashCode^hugepageSize.hashCode^committedAs.hashCode^commitLimit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MemoryBreakdown &&
+                runtimeType == other.runtimeType
+                && total == other.total&& free == other.free&& available == other.available&& buffers == other.buffers&& cached == other.cached&& swapCached == other.swapCached&& shmem == other.shmem&& slabReclaimable == other.slabReclaimable&& slabUnreclaimable == other.slabUnreclaimable&& active == other.active&& inactive == other.inactive&& dirty == other.dirty&& writeback == other.writeback&& hugepagesTotal == other.hugepagesTotal&& hugepagesFree == other.hugepagesFree&& hugepageSize == other.hugepageSize&& committedAs == other.committedAs&& commitLimit == other.commitLimit;
+        
+            }
+            
\ No newline at end of file
@@ -7,80 +7,128 @@ import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'utils.dart';
 
-class Memory {
-  final Storage totalMemory;
-  final Storage usedMemory;
-  final Storage totalSwap;
-  final Storage usedSwap;
-  final Map<int, ProcessMemoryInfo> processes;
-
-  const Memory({
-    required this.totalMemory,
-    required this.usedMemory,
-    required this.totalSwap,
-    required this.usedSwap,
-    required this.processes,
-  });
-
-  @override
-  int get hashCode =>
-      totalMemory.hashCode ^
-      usedMemory.hashCode ^
-      totalSwap.hashCode ^
-      usedSwap.hashCode ^
-      processes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Memory &&
-          runtimeType == other.runtimeType &&
-          totalMemory == other.totalMemory &&
-          usedMemory == other.usedMemory &&
-          totalSwap == other.totalSwap &&
-          usedSwap == other.usedSwap &&
-          processes == other.processes;
-}
-
-class ProcessMemoryInfo {
-  final Storage memory;
-  final BigInt rawMemory;
-  final String name;
-  final String? exe;
-  final int? parent;
-  final String? root;
-  final Storage totalMemory;
-
-  const ProcessMemoryInfo({
-    required this.memory,
-    required this.rawMemory,
-    required this.name,
-    this.exe,
-    this.parent,
-    this.root,
-    required this.totalMemory,
-  });
-
-  @override
-  int get hashCode =>
-      memory.hashCode ^
-      rawMemory.hashCode ^
-      name.hashCode ^
-      exe.hashCode ^
-      parent.hashCode ^
-      root.hashCode ^
-      totalMemory.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ProcessMemoryInfo &&
-          runtimeType == other.runtimeType &&
-          memory == other.memory &&
-          rawMemory == other.rawMemory &&
-          name == other.name &&
-          exe == other.exe &&
-          parent == other.parent &&
-          root == other.root &&
-          totalMemory == other.totalMemory;
-}
+
+            
+
+            
+
+            class Memory  {
+                final Storage totalMemory;
+final Storage usedMemory;
+final Storage totalSwap;
+final Storage usedSwap;
+final Map<int, ProcessMemoryInfo> processes;
+
+                const Memory({required this.totalMemory ,required this.usedMemory ,required this.totalSwap ,required this.usedSwap ,required this.processes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalMemory.hashCode^usedMemory.hashCode^totalSwap.hashCode^usedSwap.hashCode^processes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Memory &&
+                runtimeType == other.runtimeType
+                && totalMemory == other.totalMemory&& usedMemory == other.usedMemory&& totalSwap == other.totalSwap&& usedSwap == other.usedSwap&& processes == other.processes;
+        
+            }
+
+/// Which per-process figure `total_memory` sums over a subtree.
+enum MemoryMetric {
+                    /// Resident set size, as reported by sysinfo. Cheap, but counts shared
+/// pages once per process.
+rss,
+/// Proportional set size. Falls back to RSS for processes whose
+/// `smaps_rollup` cannot be read.
+pss,
+/// Unique set size. Falls back to RSS like [`MemoryMetric::Pss`].
+uss,
+                    ;
+                    
+                }
+
+class ProcessMemoryInfo  {
+                final Storage memory;
+final BigInt rawMemory;
+final String name;
+final String? exe;
+final int? parent;
+final String? root;
+final Storage totalMemory;
+/// Proportional set size; only collected on Linux when the sampler
+/// aggregates by [`MemoryMetric::Pss`] or [`MemoryMetric::Uss`].
+final Storage? pss;
+/// Unique set size, collected alongside `pss`.
+final Storage? uss;
+/// Swapped-out memory, collected alongside `pss`.
+final Storage? swap;
+/// Name of the user owning the process, when it can be resolved.
+final String? user;
+/// When the process started, in seconds since the Unix epoch.
+final BigInt startTime;
+/// Path of the process' cgroup v2, such as `/system.slice/sshd.service`.
+/// Only read on Linux.
+final String? cgroup;
+/// Change of the sampler's metric since the previous sample, when the
+/// same process was present in both.
+final StorageRate? growthRate;
+
+                const ProcessMemoryInfo({required this.memory ,required this.rawMemory ,required this.name ,this.exe ,this.parent ,this.root ,required this.totalMemory ,this.pss ,this.uss ,this.swap ,this.user ,required this.startTime ,this.cgroup ,this.growthRate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => memory.hashCode^rawMemory.hashCode^name.hashCode^exe.hashCode^parent.hashCode^root.hashCode^totalMemory.hashCode^pss.hashCode^uss.hashCode^swap.hashCode^user.hashCode^startTime.hashCode^cgroup.hashCode^growthRate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessMemoryInfo &&
+                runtimeType == other.runtimeType
+                && memory == other.memory&& rawMemory == other.rawMemory&& name == other.name&& exe == other.exe&& parent == other.parent&& root == other.root&& totalMemory == other.totalMemory&& pss == other.pss&& uss == other.uss&& swap == other.swap&& user == other.user&& startTime == other.startTime&& cgroup == other.cgroup&& growthRate == other.growthRate;
+        
+            }
+
+/// A process in the parent/child hierarchy, in a shape that can cross the
+/// FFI boundary (unlike [`Tree`]).
+class ProcessTreeNode  {
+                final int pid;
+final String name;
+/// Distance from the top of the hierarchy; processes without a known
+/// parent are at depth 0.
+final int depth;
+final Storage memory;
+final Storage totalMemory;
+/// Sorted by `total_memory`, largest first.
+final List<ProcessTreeNode> children;
+
+                const ProcessTreeNode({required this.pid ,required this.name ,required this.depth ,required this.memory ,required this.totalMemory ,required this.children ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^name.hashCode^depth.hashCode^memory.hashCode^totalMemory.hashCode^children.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessTreeNode &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& name == other.name&& depth == other.depth&& memory == other.memory&& totalMemory == other.totalMemory&& children == other.children;
+        
+            }
+            
\ No newline at end of file
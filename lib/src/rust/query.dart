@@ -0,0 +1,121 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'memory.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'utils.dart';
+
+
+            
+
+            
+
+            /// Conditions a process must meet to be listed. Unset fields match every
+/// process.
+class ProcessFilter  {
+                /// Case-insensitive substring of the name or executable path.
+final String? text;
+/// Regular expression matched against the name or executable path.
+final String? pattern;
+/// Minimum of the process' own memory, in bytes.
+final BigInt? minMemory;
+/// Only list top-level processes, whose parent is unknown or init.
+final bool rootsOnly;
+final Uint32List? pids;
+final String? user;
+
+                const ProcessFilter({this.text ,this.pattern ,this.minMemory ,required this.rootsOnly ,this.pids ,this.user ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^pattern.hashCode^minMemory.hashCode^rootsOnly.hashCode^pids.hashCode^user.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessFilter &&
+                runtimeType == other.runtimeType
+                && text == other.text&& pattern == other.pattern&& minMemory == other.minMemory&& rootsOnly == other.rootsOnly&& pids == other.pids&& user == other.user;
+        
+            }
+
+/// One page of a process listing.
+class ProcessPage  {
+                final List<(int,ProcessMemoryInfo)> items;
+/// Number of processes matching the filter, across all pages.
+final int total;
+
+                const ProcessPage({required this.items ,required this.total ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => items.hashCode^total.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessPage &&
+                runtimeType == other.runtimeType
+                && items == other.items&& total == other.total;
+        
+            }
+
+class ProcessQuery  {
+                final ProcessFilter filter;
+final ProcessSortKey sortKey;
+final SortDirection direction;
+final int offset;
+/// Maximum number of items in the page; 0 returns everything after
+/// `offset`.
+final int limit;
+
+                const ProcessQuery({required this.filter ,required this.sortKey ,required this.direction ,required this.offset ,required this.limit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => filter.hashCode^sortKey.hashCode^direction.hashCode^offset.hashCode^limit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessQuery &&
+                runtimeType == other.runtimeType
+                && filter == other.filter&& sortKey == other.sortKey&& direction == other.direction&& offset == other.offset&& limit == other.limit;
+        
+            }
+
+enum ProcessSortKey {
+                    memory,
+totalMemory,
+name,
+pid,
+startTime,
+                    ;
+                    
+                }
+
+enum SortDirection {
+                    ascending,
+descending,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,122 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'utils.dart';
+
+
+            
+
+            
+
+            /// Mappings sharing the same backing file or label, summed.
+class MappingGroup  {
+                /// Backing file, kernel label, or `[anon]` for anonymous memory.
+final String name;
+final MappingKind kind;
+final int count;
+final Storage size;
+final Storage rss;
+final Storage pss;
+final Storage dirty;
+final Storage swap;
+
+                const MappingGroup({required this.name ,required this.kind ,required this.count ,required this.size ,required this.rss ,required this.pss ,required this.dirty ,required this.swap ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^kind.hashCode^count.hashCode^size.hashCode^rss.hashCode^pss.hashCode^dirty.hashCode^swap.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MappingGroup &&
+                runtimeType == other.runtimeType
+                && name == other.name&& kind == other.kind&& count == other.count&& size == other.size&& rss == other.rss&& pss == other.pss&& dirty == other.dirty&& swap == other.swap;
+        
+            }
+
+enum MappingKind {
+                    heap,
+stack,
+anonymous,
+file,
+/// Kernel-provided mappings such as `[vdso]` or `[vvar]`.
+special,
+                    ;
+                    
+                }
+
+/// One entry of `/proc/<pid>/smaps`.
+class MemoryMapping  {
+                final BigInt start;
+final BigInt end;
+/// Permission string as printed by the kernel, e.g. `r-xp`.
+final String permissions;
+final BigInt offset;
+final MappingKind kind;
+/// Backing file or kernel label (e.g. `[heap]`); `None` for plain
+/// anonymous memory.
+final String? path;
+final Storage size;
+final Storage rss;
+final Storage pss;
+/// Shared and private dirty pages.
+final Storage dirty;
+final Storage swap;
+
+                const MemoryMapping({required this.start ,required this.end ,required this.permissions ,required this.offset ,required this.kind ,this.path ,required this.size ,required this.rss ,required this.pss ,required this.dirty ,required this.swap ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode^permissions.hashCode^offset.hashCode^kind.hashCode^path.hashCode^size.hashCode^rss.hashCode^pss.hashCode^dirty.hashCode^swap.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MemoryMapping &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end&& permissions == other.permissions&& offset == other.offset&& kind == other.kind&& path == other.path&& size == other.size&& rss == other.rss&& pss == other.pss&& dirty == other.dirty&& swap == other.swap;
+        
+            }
+
+class ProcessMemoryMaps  {
+                final int pid;
+/// In address order, as listed by the kernel.
+final List<MemoryMapping> mappings;
+/// Sorted by PSS, largest first.
+final List<MappingGroup> groups;
+
+                const ProcessMemoryMaps({required this.pid ,required this.mappings ,required this.groups ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^mappings.hashCode^groups.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessMemoryMaps &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& mappings == other.mappings&& groups == other.groups;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,99 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            class CompactionStats  {
+                final BigInt kept;
+final BigInt removed;
+
+                const CompactionStats({required this.kept ,required this.removed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kept.hashCode^removed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompactionStats &&
+                runtimeType == other.runtimeType
+                && kept == other.kept&& removed == other.removed;
+        
+            }
+
+class HistoryStoreOptions  {
+                /// Minimum time between two stored samples; samples arriving faster are
+/// skipped.
+final BigInt recordIntervalMs;
+/// How many processes, largest `total_memory` first, are stored with
+/// each sample.
+final int topProcesses;
+/// How often the store should be compacted while recording, see
+/// [`HistoryStore::compaction_due`].
+final BigInt compactionIntervalMs;
+final RetentionPolicy retention;
+
+                const HistoryStoreOptions({required this.recordIntervalMs ,required this.topProcesses ,required this.compactionIntervalMs ,required this.retention ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => recordIntervalMs.hashCode^topProcesses.hashCode^compactionIntervalMs.hashCode^retention.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistoryStoreOptions &&
+                runtimeType == other.runtimeType
+                && recordIntervalMs == other.recordIntervalMs&& topProcesses == other.topProcesses&& compactionIntervalMs == other.compactionIntervalMs&& retention == other.retention;
+        
+            }
+
+/// How long stored samples are kept, and at which resolution.
+class RetentionPolicy  {
+                /// Samples older than this are deleted on compaction.
+final BigInt maxAgeMs;
+/// Samples younger than this are kept at the resolution they were
+/// recorded at.
+final BigInt fullResolutionMs;
+/// Older samples are thinned out to one per this many milliseconds.
+final BigInt compactedIntervalMs;
+
+                const RetentionPolicy({required this.maxAgeMs ,required this.fullResolutionMs ,required this.compactedIntervalMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxAgeMs.hashCode^fullResolutionMs.hashCode^compactedIntervalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetentionPolicy &&
+                runtimeType == other.runtimeType
+                && maxAgeMs == other.maxAgeMs&& fullResolutionMs == other.fullResolutionMs&& compactedIntervalMs == other.compactedIntervalMs;
+        
+            }
+            
\ No newline at end of file
@@ -6,36 +6,89 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-class Storage {
-  final BigInt quotient;
-  final BigInt remainder;
-  final Unit unit;
-
-  const Storage({
-    required this.quotient,
-    required this.remainder,
-    required this.unit,
-  });
-
-  @override
-  int get hashCode => quotient.hashCode ^ remainder.hashCode ^ unit.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Storage &&
-          runtimeType == other.runtimeType &&
-          quotient == other.quotient &&
-          remainder == other.remainder &&
-          unit == other.unit;
-}
-
-enum Unit {
-  b,
-  kb,
-  mb,
-  gb,
-  tb,
-  pb,
-  ;
-}
+
+            
+
+            
+
+            /// An amount of bytes, written in a unit.
+///
+/// In Rust, comparison and hashing only look at the amount: `1 KiB` equals
+/// `1024 B`.
+class Storage  {
+                final BigInt quotient;
+final BigInt remainder;
+final StorageUnit unit;
+
+                const Storage({required this.quotient ,required this.remainder ,required this.unit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => quotient.hashCode^remainder.hashCode^unit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Storage &&
+                runtimeType == other.runtimeType
+                && quotient == other.quotient&& remainder == other.remainder&& unit == other.unit;
+        
+            }
+
+/// Signed change of a [`Storage`] amount per second, such as memory growth
+/// or I/O throughput.
+class StorageRate  {
+                final double bytesPerSecond;
+
+                const StorageRate({required this.bytesPerSecond ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bytesPerSecond.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StorageRate &&
+                runtimeType == other.runtimeType
+                && bytesPerSecond == other.bytesPerSecond;
+        
+            }
+
+/// Units of binary (IEC) and decimal (SI) prefixes. Bytes belong to both.
+enum StorageUnit {
+                    b,
+kiB,
+miB,
+giB,
+tiB,
+piB,
+kb,
+mb,
+gb,
+tb,
+pb,
+                    ;
+                    
+                }
+
+/// Family of units a [`Storage`] is scaled to.
+enum UnitSystem {
+                    /// Powers of 1024 (KiB, MiB, ...), as memory is sized.
+iec,
+/// Powers of 1000 (kB, MB, ...), as disks are usually sold.
+si,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,555 @@
+use crate::{memory::Memory, utils::Storage};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Trailing window over which process growth rates are measured.
+const GROWTH_WINDOW_MS: u64 = 60 * 1000;
+
+/// Growth is only reported once samples span at least this long, so a
+/// single jump between two samples is not extrapolated to a rate.
+const MIN_GROWTH_SPAN_MS: u64 = 10 * 1000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlertCondition {
+    /// `used_memory` above `percent` of `total_memory`.
+    UsedMemoryPercentAbove {
+        percent: f64,
+    },
+    UsedSwapAbove {
+        bytes: u64,
+    },
+    /// `total_memory` of any process called `name` above `bytes`.
+    ProcessTotalMemoryAbove {
+        name: String,
+        bytes: u64,
+    },
+    /// Resident memory of any process growing faster than
+    /// `bytes_per_minute`, measured over the last minute.
+    ProcessGrowthAbove {
+        bytes_per_minute: u64,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    pub id: u32,
+    pub name: String,
+    pub condition: AlertCondition,
+    /// How long the condition has to hold before the alert fires.
+    pub for_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertEventKind {
+    Fired,
+    Resolved,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertEvent {
+    pub rule_id: u32,
+    pub rule_name: String,
+    pub kind: AlertEventKind,
+    /// Set for rules evaluated per process.
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+    /// Observed value when the event was raised, in the unit of the
+    /// condition (percent, bytes or bytes per minute).
+    pub value: f64,
+    pub message: String,
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// A rule either applies to the whole system or separately to each process.
+type InstanceKey = (u32, Option<u32>);
+
+#[derive(Debug, Clone)]
+struct Instance {
+    pending_since: u64,
+    firing: bool,
+    process_name: Option<String>,
+    /// Start time of the process, to tell it apart from a later one
+    /// reusing its PID.
+    start_time: Option<u64>,
+}
+
+/// Recent `(timestamp, bytes)` readings of one process.
+#[derive(Debug)]
+struct Growth {
+    start_time: u64,
+    samples: VecDeque<(u64, u64)>,
+}
+
+/// A condition that currently holds, before the `for_ms` debounce.
+struct Breach {
+    pid: Option<u32>,
+    process_name: Option<String>,
+    value: f64,
+    message: String,
+}
+
+/// Evaluates [`AlertRule`]s against successive [`Memory`] samples.
+#[derive(Debug, Default)]
+pub struct AlertEngine {
+    rules: Vec<AlertRule>,
+    next_id: u32,
+    instances: HashMap<InstanceKey, Instance>,
+    growth: HashMap<u32, Growth>,
+}
+
+impl AlertEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    /// Adds a rule and returns its id. The `id` field of `rule` is ignored.
+    pub fn add_rule(&mut self, mut rule: AlertRule) -> u32 {
+        self.next_id += 1;
+        rule.id = self.next_id;
+        self.rules.push(rule);
+        self.next_id
+    }
+
+    /// Replaces the rule with the same id and resets its alert state, so the
+    /// new rule is evaluated from scratch on the next sample.
+    ///
+    /// Returns `None` if there is no such rule, and otherwise the resolved
+    /// events of the alerts that were firing.
+    pub fn update_rule(&mut self, rule: AlertRule, timestamp: u64) -> Option<Vec<AlertEvent>> {
+        let index = self.rules.iter().position(|r| r.id == rule.id)?;
+        let events = self.resolve_rule(&self.rules[index].clone(), timestamp);
+        self.rules[index] = rule;
+        Some(events)
+    }
+
+    /// Removes the rule with `id`, see [`AlertEngine::update_rule`] for the
+    /// returned events.
+    pub fn remove_rule(&mut self, id: u32, timestamp: u64) -> Option<Vec<AlertEvent>> {
+        let index = self.rules.iter().position(|r| r.id == id)?;
+        let rule = self.rules.remove(index);
+        Some(self.resolve_rule(&rule, timestamp))
+    }
+
+    /// Drops the alert state of `rule`, resolving the alerts that were
+    /// firing.
+    fn resolve_rule(&mut self, rule: &AlertRule, timestamp: u64) -> Vec<AlertEvent> {
+        let keys = self
+            .instances
+            .keys()
+            .filter(|(rule_id, _)| *rule_id == rule.id)
+            .copied()
+            .sorted()
+            .collect_vec();
+        keys.into_iter()
+            .filter_map(|key| {
+                let instance = self.instances.remove(&key)?;
+                instance
+                    .firing
+                    .then(|| resolved(rule, key.1, instance.process_name, timestamp))
+            })
+            .collect_vec()
+    }
+
+    /// Evaluates every rule against `memory`, taken at `timestamp`, and
+    /// returns the alerts that fired or resolved since the last call.
+    pub fn evaluate(&mut self, memory: &Memory, timestamp: u64) -> Vec<AlertEvent> {
+        self.track_growth(memory, timestamp);
+
+        // Alerts of a process whose PID was since reused are resolved, so
+        // the new process starts over.
+        let rules: HashMap<u32, &AlertRule> = self.rules.iter().map(|r| (r.id, r)).collect();
+        let reused = self
+            .instances
+            .iter()
+            .filter(|((_, pid), instance)| {
+                pid.and_then(|pid| memory.processes.get(&pid))
+                    .is_some_and(|process| instance.start_time != Some(process.start_time))
+            })
+            .map(|(key, _)| *key)
+            .sorted()
+            .collect_vec();
+        let mut events = Vec::new();
+        for key @ (rule_id, pid) in reused {
+            if let Some(instance) = self.instances.remove(&key)
+                && instance.firing
+                && let Some(rule) = rules.get(&rule_id)
+            {
+                events.push(resolved(rule, pid, instance.process_name, timestamp));
+            }
+        }
+
+        let mut active: HashSet<InstanceKey> = HashSet::new();
+
+        for rule in &self.rules {
+            for breach in self.breaches(rule, memory) {
+                let key = (rule.id, breach.pid);
+                active.insert(key);
+
+                let instance = self.instances.entry(key).or_insert(Instance {
+                    pending_since: timestamp,
+                    firing: false,
+                    process_name: None,
+                    start_time: breach
+                        .pid
+                        .and_then(|pid| memory.processes.get(&pid))
+                        .map(|process| process.start_time),
+                });
+                instance.process_name.clone_from(&breach.process_name);
+                if !instance.firing
+                    && timestamp.saturating_sub(instance.pending_since) >= rule.for_ms
+                {
+                    instance.firing = true;
+                    events.push(AlertEvent {
+                        rule_id: rule.id,
+                        rule_name: rule.name.clone(),
+                        kind: AlertEventKind::Fired,
+                        pid: breach.pid,
+                        process_name: breach.process_name,
+                        value: breach.value,
+                        message: breach.message,
+                        timestamp,
+                    });
+                }
+            }
+        }
+
+        let inactive = self
+            .instances
+            .iter()
+            .filter(|(key, _)| !active.contains(key))
+            .map(|(key, _)| *key)
+            .sorted()
+            .collect_vec();
+        for key @ (rule_id, pid) in inactive {
+            let Some(instance) = self.instances.remove(&key) else {
+                continue;
+            };
+            if !instance.firing {
+                continue;
+            }
+            let Some(rule) = rules.get(&rule_id) else {
+                continue;
+            };
+            events.push(resolved(rule, pid, instance.process_name, timestamp));
+        }
+
+        events
+    }
+
+    fn track_growth(&mut self, memory: &Memory, timestamp: u64) {
+        // Readings of an exited process, or of an earlier one with the same
+        // PID, are dropped.
+        self.growth.retain(|pid, growth| {
+            memory
+                .processes
+                .get(pid)
+                .is_some_and(|process| process.start_time == growth.start_time)
+        });
+        for (pid, process) in &memory.processes {
+            let growth = self.growth.entry(*pid).or_insert_with(|| Growth {
+                start_time: process.start_time,
+                samples: VecDeque::new(),
+            });
+            let samples = &mut growth.samples;
+            samples.push_back((timestamp, process.raw_memory));
+            while samples
+                .front()
+                .is_some_and(|(t, _)| timestamp.saturating_sub(*t) > GROWTH_WINDOW_MS)
+            {
+                samples.pop_front();
+            }
+        }
+    }
+
+    /// Growth of `pid` in bytes per minute over the trailing window.
+    fn growth_rate(&self, pid: u32) -> Option<f64> {
+        let samples = &self.growth.get(&pid)?.samples;
+        let (first_t, first_bytes) = *samples.front()?;
+        let (last_t, last_bytes) = *samples.back()?;
+        let span = last_t.saturating_sub(first_t);
+        if span < MIN_GROWTH_SPAN_MS {
+            return None;
+        }
+        Some((last_bytes as f64 - first_bytes as f64) * 60_000.0 / span as f64)
+    }
+
+    fn breaches(&self, rule: &AlertRule, memory: &Memory) -> Vec<Breach> {
+        match &rule.condition {
+            AlertCondition::UsedMemoryPercentAbove { percent } => {
+                let total = memory.total_memory.to_bytes();
+                if total == 0 {
+                    return Vec::new();
+                }
+                let used = memory.used_memory.to_bytes() as f64 * 100.0 / total as f64;
+                if used <= *percent {
+                    return Vec::new();
+                }
+                vec![Breach {
+                    pid: None,
+                    process_name: None,
+                    value: used,
+                    message: format!("used memory at {used:.1}%, above {percent:.1}%"),
+                }]
+            }
+            AlertCondition::UsedSwapAbove { bytes } => {
+                let used = memory.used_swap.to_bytes();
+                if used <= *bytes {
+                    return Vec::new();
+                }
+                vec![Breach {
+                    pid: None,
+                    process_name: None,
+                    value: used as f64,
+                    message: format!(
+                        "used swap at {}, above {}",
+                        memory.used_swap,
+                        Storage::from_bytes(*bytes)
+                    ),
+                }]
+            }
+            AlertCondition::ProcessTotalMemoryAbove { name, bytes } => memory
+                .processes
+                .iter()
+                .filter(|(_, p)| &p.name == name && p.total_memory.to_bytes() > *bytes)
+                .map(|(pid, p)| Breach {
+                    pid: Some(*pid),
+                    process_name: Some(p.name.clone()),
+                    value: p.total_memory.to_bytes() as f64,
+                    message: format!(
+                        "{} ({pid}) total memory at {}, above {}",
+                        p.name,
+                        p.total_memory,
+                        Storage::from_bytes(*bytes)
+                    ),
+                })
+                .collect_vec(),
+            AlertCondition::ProcessGrowthAbove { bytes_per_minute } => memory
+                .processes
+                .iter()
+                .filter_map(|(pid, p)| {
+                    let rate = self.growth_rate(*pid)?;
+                    (rate > *bytes_per_minute as f64).then(|| Breach {
+                        pid: Some(*pid),
+                        process_name: Some(p.name.clone()),
+                        value: rate,
+                        message: format!(
+                            "{} ({pid}) growing by {}/min, above {}/min",
+                            p.name,
+                            Storage::from_bytes(rate as u64),
+                            Storage::from_bytes(*bytes_per_minute)
+                        ),
+                    })
+                })
+                .collect_vec(),
+        }
+    }
+}
+
+fn resolved(
+    rule: &AlertRule,
+    pid: Option<u32>,
+    process_name: Option<String>,
+    timestamp: u64,
+) -> AlertEvent {
+    AlertEvent {
+        rule_id: rule.id,
+        rule_name: rule.name.clone(),
+        kind: AlertEventKind::Resolved,
+        pid,
+        process_name,
+        value: 0.0,
+        message: format!("{} resolved", rule.name),
+        timestamp,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{source::RawProcess, test_support};
+
+    const MB: u64 = 1024 * 1024;
+
+    fn memory(used: u64, processes: &[(u32, &str, u64)]) -> Memory {
+        let mut memory =
+            test_support::memory(processes.iter().map(|&(pid, name, bytes)| RawProcess {
+                name: name.to_string(),
+                ..test_support::process(pid, None, bytes)
+            }));
+        memory.total_memory = Storage::from_bytes(1000 * MB);
+        memory.used_memory = Storage::from_bytes(used * MB);
+        memory
+    }
+
+    fn rule(condition: AlertCondition, for_ms: u64) -> AlertRule {
+        AlertRule {
+            id: 0,
+            name: String::from("rule"),
+            condition,
+            for_ms,
+        }
+    }
+
+    fn kinds(events: &[AlertEvent]) -> Vec<(AlertEventKind, Option<u32>)> {
+        events.iter().map(|e| (e.kind, e.pid)).collect_vec()
+    }
+
+    #[test]
+    fn test_fires_after_duration_and_resolves() {
+        let mut engine = AlertEngine::new();
+        engine.add_rule(rule(
+            AlertCondition::UsedMemoryPercentAbove { percent: 90.0 },
+            30_000,
+        ));
+
+        assert!(engine.evaluate(&memory(950, &[]), 0).is_empty());
+        assert!(engine.evaluate(&memory(950, &[]), 20_000).is_empty());
+
+        let events = engine.evaluate(&memory(950, &[]), 30_000);
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Fired, None)]);
+        assert!((events[0].value - 95.0).abs() < 1e-9);
+
+        assert!(engine.evaluate(&memory(950, &[]), 40_000).is_empty());
+        let events = engine.evaluate(&memory(500, &[]), 50_000);
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Resolved, None)]);
+    }
+
+    #[test]
+    fn test_short_breach_does_not_fire() {
+        let mut engine = AlertEngine::new();
+        engine.add_rule(rule(
+            AlertCondition::UsedMemoryPercentAbove { percent: 90.0 },
+            30_000,
+        ));
+
+        assert!(engine.evaluate(&memory(950, &[]), 0).is_empty());
+        assert!(engine.evaluate(&memory(500, &[]), 10_000).is_empty());
+        assert!(engine.evaluate(&memory(950, &[]), 20_000).is_empty());
+        assert!(engine.evaluate(&memory(950, &[]), 40_000).is_empty());
+    }
+
+    #[test]
+    fn test_process_rules_are_per_process() {
+        let mut engine = AlertEngine::new();
+        engine.add_rule(rule(
+            AlertCondition::ProcessTotalMemoryAbove {
+                name: String::from("chrome"),
+                bytes: 100 * MB,
+            },
+            0,
+        ));
+
+        let sample = memory(
+            0,
+            &[
+                (1, "chrome", 200 * MB),
+                (2, "chrome", MB),
+                (3, "x", 500 * MB),
+            ],
+        );
+        let events = engine.evaluate(&sample, 0);
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Fired, Some(1))]);
+        assert_eq!(events[0].process_name.as_deref(), Some("chrome"));
+
+        // The process exiting resolves its alert.
+        let events = engine.evaluate(&memory(0, &[(2, "chrome", MB)]), 1000);
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Resolved, Some(1))]);
+    }
+
+    #[test]
+    fn test_growth_rate() {
+        let mut engine = AlertEngine::new();
+        engine.add_rule(rule(
+            AlertCondition::ProcessGrowthAbove {
+                bytes_per_minute: 100 * MB,
+            },
+            0,
+        ));
+
+        // 30 MB every 10 s is 180 MB/min for pid 1; pid 2 stays flat.
+        let mut events = Vec::new();
+        for i in 0..4 {
+            let sample = memory(0, &[(1, "leaky", i * 30 * MB), (2, "flat", 10 * MB)]);
+            events.extend(engine.evaluate(&sample, i * 10_000));
+        }
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Fired, Some(1))]);
+        assert!((events[0].value - (180 * MB) as f64).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_reused_pids_start_over() {
+        let mut engine = AlertEngine::new();
+        engine.add_rule(rule(
+            AlertCondition::ProcessGrowthAbove {
+                bytes_per_minute: 100 * MB,
+            },
+            0,
+        ));
+
+        let mut events = Vec::new();
+        for i in 0..4 {
+            let sample = memory(0, &[(1, "leaky", i * 30 * MB)]);
+            events.extend(engine.evaluate(&sample, i * 10_000));
+        }
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Fired, Some(1))]);
+
+        // Another, smaller process gets PID 1: the alert resolves, and its
+        // memory is not compared with the old process'.
+        let mut sample = memory(0, &[(1, "other", MB)]);
+        sample.processes.get_mut(&1).unwrap().start_time = 42;
+        let events = engine.evaluate(&sample, 40_000);
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Resolved, Some(1))]);
+        assert_eq!(events[0].process_name.as_deref(), Some("leaky"));
+        assert_eq!(engine.growth[&1].samples.len(), 1);
+    }
+
+    #[test]
+    fn test_rule_management() {
+        let mut engine = AlertEngine::new();
+        let id = engine.add_rule(rule(AlertCondition::UsedSwapAbove { bytes: 0 }, 0));
+        assert_eq!(engine.rules()[0].id, id);
+
+        let mut updated = engine.rules()[0].clone();
+        updated.name = String::from("swap");
+        assert_eq!(engine.update_rule(updated, 0), Some(Vec::new()));
+        assert_eq!(engine.rules()[0].name, "swap");
+
+        assert_eq!(engine.remove_rule(id, 0), Some(Vec::new()));
+        assert_eq!(engine.remove_rule(id, 0), None);
+        assert!(engine.rules().is_empty());
+    }
+
+    #[test]
+    fn test_changing_rules_resolves_firing_alerts() {
+        let mut engine = AlertEngine::new();
+        let id = engine.add_rule(rule(
+            AlertCondition::ProcessTotalMemoryAbove {
+                name: String::from("chrome"),
+                bytes: 100 * MB,
+            },
+            0,
+        ));
+        let sample = memory(0, &[(1, "chrome", 200 * MB)]);
+        assert_eq!(engine.evaluate(&sample, 0).len(), 1);
+
+        let mut updated = engine.rules()[0].clone();
+        updated.name = String::from("chrome");
+        let events = engine.update_rule(updated, 1000).unwrap();
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Resolved, Some(1))]);
+        assert_eq!(events[0].rule_name, "rule");
+        assert_eq!(events[0].process_name.as_deref(), Some("chrome"));
+
+        // The updated rule starts over and fires again.
+        let events = engine.evaluate(&sample, 2000);
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Fired, Some(1))]);
+
+        let events = engine.remove_rule(id, 3000).unwrap();
+        assert_eq!(kinds(&events), vec![(AlertEventKind::Resolved, Some(1))]);
+        assert!(engine.evaluate(&sample, 4000).is_empty());
+    }
+}
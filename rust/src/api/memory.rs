@@ -1,29 +1,560 @@
 use crate::{
-    memory::{Memory, ProcessMemoryInfo},
-    utils::{Storage, Unit},
+    alerts::{AlertCondition, AlertEngine, AlertEvent, AlertRule},
+    cgroup::{CgroupFs, CgroupNode},
+    control::{self, Signal, SignalResult},
+    diff::MemoryDiff,
+    error::MemoryError,
+    format::{StorageFormatOptions, StorageFormatter},
+    frb_generated::StreamSink,
+    group::{self, GroupBy, ProcessGroup},
+    history::{self, History, HistoryBucket, HistorySeries},
+    leak::{self, LeakDetectorOptions, LeakReport},
+    meminfo::MemoryBreakdown,
+    memory::{Memory, MemoryMetric, ProcessMemoryInfo, ProcessTreeNode},
+    monitor::{self, MonitorControl, Subscriber},
+    query::{ProcessPage, ProcessQuery},
+    recording::{self, Recorder, ReplaySource},
+    sampler::{self, Sampler},
+    smaps::ProcessMemoryMaps,
+    source::{MemorySource, RawSnapshot, SysinfoSource},
+    store::{CompactionStats, HistoryStore, HistoryStoreOptions},
+    utils::{self, Storage, StorageUnit, UnitSystem},
 };
+use std::{
+    sync::{Arc, LazyLock, Mutex, MutexGuard, PoisonError},
+    thread,
+    time::Duration,
+};
+
+const DEFAULT_STREAM_INTERVAL: Duration = Duration::from_secs(1);
+
+type DynSampler = Sampler<Box<dyn MemorySource + Send>>;
+
+static SAMPLER: LazyLock<Mutex<DynSampler>> = LazyLock::new(|| {
+    Mutex::new(Sampler::with_source(
+        Box::new(SysinfoSource::new()),
+        sampler::DEFAULT_MAX_AGE,
+    ))
+});
+
+static MEMORY_STREAM: LazyLock<Arc<MonitorControl>> =
+    LazyLock::new(|| Arc::new(MonitorControl::new(DEFAULT_STREAM_INTERVAL)));
+
+static MEMORY_STREAM_SUBSCRIBER: Subscriber = Subscriber::new();
+
+static HISTORY: LazyLock<Mutex<History>> = LazyLock::new(Mutex::default);
+
+static STORE: Mutex<Option<HistoryStore>> = Mutex::new(None);
+
+static ALERTS: LazyLock<Mutex<AlertEngine>> = LazyLock::new(Mutex::default);
+
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+static ALERT_SINKS: Mutex<Vec<StreamSink<AlertEvent>>> = Mutex::new(Vec::new());
+
+fn sampler() -> MutexGuard<'static, DynSampler> {
+    SAMPLER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn history() -> MutexGuard<'static, History> {
+    HISTORY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn store() -> MutexGuard<'static, Option<HistoryStore>> {
+    STORE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn recorder() -> MutexGuard<'static, Option<Recorder>> {
+    RECORDER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn alerts() -> MutexGuard<'static, AlertEngine> {
+    ALERTS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn alert_sinks() -> MutexGuard<'static, Vec<StreamSink<AlertEvent>>> {
+    ALERT_SINKS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn send_alert_events(events: &[AlertEvent]) {
+    if !events.is_empty() {
+        alert_sinks().retain(|sink| events.iter().all(|event| sink.add(event.clone()).is_ok()));
+    }
+}
+
+fn no_store() -> MemoryError {
+    MemoryError::NotFound {
+        message: String::from("no history store is open"),
+    }
+}
+
+fn no_alert_rule(id: u32) -> MemoryError {
+    MemoryError::NotFound {
+        message: format!("no alert rule with id {id}"),
+    }
+}
+
+/// Returns the shared snapshot, taking a new one if it is stale.
+fn snapshot() -> Memory {
+    snapshot_with_metric().0
+}
+
+/// Same as [`snapshot`], along with the metric its totals were aggregated
+/// with, read under the same lock.
+fn snapshot_with_metric() -> (Memory, MemoryMetric) {
+    let mut sampler = sampler();
+    let previous = sampler.taken_at();
+    let memory = sampler.snapshot().clone();
+    let is_new = sampler.taken_at() != previous;
+    let (timestamp, replayed) = (sampler.timestamp(), sampler.is_replayed());
+    let metric = sampler.metric();
+    drop(sampler);
+
+    if is_new {
+        observe(&memory, timestamp.unwrap_or_default(), replayed);
+    }
+    (memory, metric)
+}
+
+/// Takes a new snapshot regardless of the age of the shared one.
+fn refresh() -> Memory {
+    let mut sampler = sampler();
+    let memory = sampler.refresh().clone();
+    let (timestamp, replayed) = (sampler.timestamp(), sampler.is_replayed());
+    drop(sampler);
+
+    observe(&memory, timestamp.unwrap_or_default(), replayed);
+    memory
+}
+
+/// Feeds a freshly taken snapshot to everything that tracks samples over
+/// time. Replayed snapshots are not recorded again.
+fn observe(memory: &Memory, timestamp: u64, replayed: bool) {
+    // Disk errors are dropped so that monitoring goes on.
+    if !replayed && let Some(recorder) = recorder().as_mut() {
+        let _ = recorder.record(&RawSnapshot::from(memory), timestamp);
+    }
+
+    let events = alerts().evaluate(memory, timestamp);
+    send_alert_events(&events);
+
+    let mut history = history();
+    history.record_memory(memory, timestamp);
+
+    if let Some(store) = store().as_mut()
+        && let Some(sample) = history.latest()
+    {
+        let _ = store.record(sample, &history);
+        if store.compaction_due(timestamp) {
+            compact_in_background(store, timestamp);
+        }
+    }
+}
+
+/// Time of the newest sample, which during a replay is the time it was
+/// recorded at.
+fn latest_timestamp(history: &History) -> u64 {
+    history
+        .latest()
+        .map_or_else(history::now_millis, |sample| sample.timestamp)
+}
+
+fn compact_in_background(current: &mut HistoryStore, now: u64) {
+    let Ok(pending) = current.begin_compaction(now) else {
+        return;
+    };
+    let _ = thread::Builder::new()
+        .name("history-compaction".into())
+        .spawn(move || {
+            if let Ok(prepared) = pending.run()
+                && let Some(store) = store().as_mut()
+            {
+                let _ = store.finish_compaction(prepared);
+            }
+        });
+}
+
+pub fn get_memory_info() -> Result<Memory, MemoryError> {
+    Ok(snapshot())
+}
+
+pub fn get_first_process_memory_usage(
+    n: usize,
+) -> Result<Vec<(u32, ProcessMemoryInfo)>, MemoryError> {
+    Ok(snapshot().first(n))
+}
+
+/// Lists the processes of the current snapshot matching `query`, one page at
+/// a time.
+pub fn query_processes(query: ProcessQuery) -> Result<ProcessPage, MemoryError> {
+    query.run(&snapshot())
+}
+
+/// Rolls up the processes of the current snapshot by `by`, summing the
+/// metric the sampler aggregates with.
+pub fn get_process_groups(by: GroupBy) -> Result<Vec<ProcessGroup>, MemoryError> {
+    let (memory, metric) = snapshot_with_metric();
+    group::group_processes(&memory, &by, metric)
+}
+
+/// Returns the detailed system memory breakdown. Only supported on Linux.
+pub fn get_memory_breakdown() -> Result<MemoryBreakdown, MemoryError> {
+    MemoryBreakdown::read().map_err(|err| MemoryError::io(&err, "failed to read /proc/meminfo"))
+}
+
+/// Selects what `total_memory` sums over each subtree. PSS and USS are only
+/// available on Linux; elsewhere they fall back to RSS.
+pub fn set_memory_metric(metric: MemoryMetric) -> Result<(), MemoryError> {
+    sampler().set_metric(metric);
+    Ok(())
+}
+
+pub fn get_memory_metric() -> Result<MemoryMetric, MemoryError> {
+    Ok(sampler().metric())
+}
+
+pub fn get_process_tree() -> Result<Vec<ProcessTreeNode>, MemoryError> {
+    let (memory, metric) = snapshot_with_metric();
+    memory.process_tree(metric)
+}
+
+/// Returns the memory mappings of `pid`, individually and grouped by backing
+/// file. Only supported on Linux.
+pub fn get_process_memory_maps(pid: u32) -> Result<ProcessMemoryMaps, MemoryError> {
+    ProcessMemoryMaps::read(pid).map_err(|err| {
+        MemoryError::process_io(
+            pid,
+            &err,
+            format_args!("failed to read memory maps of process {pid}"),
+        )
+    })
+}
+
+/// Sends `signal` to `pid` if it is still the process started at
+/// `start_time`, as reported in its [`ProcessMemoryInfo`]. Init is never
+/// signalled.
+pub fn send_signal(pid: u32, start_time: u64, signal: Signal) -> Result<SignalResult, MemoryError> {
+    Ok(control::send_signal_to(pid, start_time, signal))
+}
+
+/// Terminates `pid` and all its descendants, escalating from TERM to KILL
+/// for the processes still alive after `grace_ms`.
+pub fn kill_process_tree(pid: u32, grace_ms: u64) -> Result<Vec<SignalResult>, MemoryError> {
+    let memory = refresh();
+    control::kill_subtree(&memory, pid, Duration::from_millis(grace_ms))
+}
+
+/// Returns the cgroup v2 hierarchy with the memory usage, limits and OOM
+/// events of every cgroup. Only supported on Linux.
+pub fn get_cgroup_tree() -> Result<CgroupNode, MemoryError> {
+    let fs = CgroupFs::default();
+    fs.read_tree().map_err(|err| {
+        MemoryError::io(
+            &err,
+            format_args!("failed to read cgroups under {}", fs.root().display()),
+        )
+    })
+}
+
+/// Returns `series` over `[start_ms, end_ms)` (milliseconds since the Unix
+/// epoch), downsampled into buckets `resolution_ms` wide. A resolution of 0
+/// returns every recorded sample.
+pub fn query_memory_history(
+    series: HistorySeries,
+    start_ms: u64,
+    end_ms: u64,
+    resolution_ms: u64,
+) -> Result<Vec<HistoryBucket>, MemoryError> {
+    Ok(history().query(series, start_ms, end_ms, resolution_ms))
+}
+
+/// Sets how many samples the in-memory history keeps before dropping the
+/// oldest ones.
+pub fn set_memory_history_capacity(capacity: usize) -> Result<(), MemoryError> {
+    history().set_capacity(capacity);
+    Ok(())
+}
 
-pub fn get_memory_info() -> anyhow::Result<Memory> {
-    Ok(Memory::get())
+pub fn clear_memory_history() -> Result<(), MemoryError> {
+    history().clear();
+    Ok(())
 }
 
-pub fn get_first_process_memory_usage(n: usize) -> anyhow::Result<Vec<(u32, ProcessMemoryInfo)>> {
-    let info = Memory::get();
-    Ok(info.first(n))
+/// Compares two snapshots taken with [`get_memory_info`].
+pub fn diff_memory_snapshots(before: Memory, after: Memory) -> Result<MemoryDiff, MemoryError> {
+    Ok(MemoryDiff::between(&before, &after))
+}
+
+/// Compares the recorded samples nearest to `before_ms` and `after_ms`
+/// (milliseconds since the Unix epoch).
+pub fn diff_memory_history(before_ms: u64, after_ms: u64) -> Result<MemoryDiff, MemoryError> {
+    MemoryDiff::between_history(&history(), before_ms, after_ms).ok_or_else(|| {
+        MemoryError::NotFound {
+            message: String::from("the memory history is empty"),
+        }
+    })
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn storage_to_float(storage: &Storage) -> anyhow::Result<f64> {
+pub fn default_history_store_options() -> Result<HistoryStoreOptions, MemoryError> {
+    Ok(HistoryStoreOptions::default())
+}
+
+/// Starts persisting samples to `path`, replacing any store opened before.
+/// Existing data at `path` is kept and compacted.
+pub fn open_history_store(path: String, options: HistoryStoreOptions) -> Result<(), MemoryError> {
+    let opened = HistoryStore::open(path, options, history::now_millis())?;
+    *store() = Some(opened);
+    Ok(())
+}
+
+pub fn close_history_store() -> Result<(), MemoryError> {
+    store().take();
+    Ok(())
+}
+
+/// Same as [`query_memory_history`], over the samples persisted on disk.
+pub fn query_stored_history(
+    series: HistorySeries,
+    start_ms: u64,
+    end_ms: u64,
+    resolution_ms: u64,
+) -> Result<Vec<HistoryBucket>, MemoryError> {
+    let reader = store()
+        .as_ref()
+        .map(HistoryStore::reader)
+        .ok_or_else(no_store)?;
+    Ok(reader.query(series, start_ms, end_ms, resolution_ms)?)
+}
+
+pub fn compact_history_store() -> Result<CompactionStats, MemoryError> {
+    let now = history::now_millis();
+    let pending = store()
+        .as_mut()
+        .ok_or_else(no_store)?
+        .begin_compaction(now)?;
+    let prepared = pending.run()?;
+
+    let mut store = store();
+    let store = store.as_mut().ok_or_else(no_store)?;
+    match store.finish_compaction(prepared)? {
+        Some(stats) => Ok(stats),
+        // Rewritten in the meantime.
+        None => Ok(store.compact(now)?),
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn default_leak_detector_options() -> Result<LeakDetectorOptions, MemoryError> {
+    Ok(LeakDetectorOptions::default())
+}
+
+/// Looks for processes whose memory grew steadily over the recent history.
+pub fn detect_memory_leaks(options: LeakDetectorOptions) -> Result<Vec<LeakReport>, MemoryError> {
+    let history = history();
+    Ok(leak::detect_leaks(
+        &history,
+        latest_timestamp(&history),
+        &options,
+    ))
+}
+
+/// Adds an alert rule, evaluated against every new sample, and returns its
+/// id.
+pub fn add_alert_rule(
+    name: String,
+    condition: AlertCondition,
+    for_ms: u64,
+) -> Result<u32, MemoryError> {
+    Ok(alerts().add_rule(AlertRule {
+        id: 0,
+        name,
+        condition,
+        for_ms,
+    }))
+}
+
+/// Replaces the rule with the same id. Alerts it was firing are resolved.
+pub fn update_alert_rule(rule: AlertRule) -> Result<(), MemoryError> {
+    let id = rule.id;
+    let timestamp = latest_timestamp(&history());
+    let events = alerts()
+        .update_rule(rule, timestamp)
+        .ok_or_else(|| no_alert_rule(id))?;
+    send_alert_events(&events);
+    Ok(())
+}
+
+/// Removes a rule. Alerts it was firing are resolved.
+pub fn remove_alert_rule(id: u32) -> Result<(), MemoryError> {
+    let timestamp = latest_timestamp(&history());
+    let events = alerts()
+        .remove_rule(id, timestamp)
+        .ok_or_else(|| no_alert_rule(id))?;
+    send_alert_events(&events);
+    Ok(())
+}
+
+pub fn list_alert_rules() -> Result<Vec<AlertRule>, MemoryError> {
+    Ok(alerts().rules().to_vec())
+}
+
+/// Delivers fired and resolved alerts to `sink` until Dart cancels the
+/// stream. Rules are only evaluated while samples are being taken, e.g.
+/// through [`memory_stream`].
+pub fn alert_stream(sink: StreamSink<AlertEvent>) -> Result<(), MemoryError> {
+    alert_sinks().push(sink);
+    Ok(())
+}
+
+/// Starts writing every new sample to a recording at `path`, replacing the
+/// file if it exists.
+pub fn start_recording(path: String) -> Result<(), MemoryError> {
+    let created = Recorder::create(&path)
+        .map_err(|err| MemoryError::io(&err, format_args!("failed to create {path}")))?;
+    *recorder() = Some(created);
+    Ok(())
+}
+
+pub fn stop_recording() -> Result<(), MemoryError> {
+    recorder().take();
+    Ok(())
+}
+
+/// Replaces the live system with the recording at `path`, played back
+/// `speed` times as fast as it was recorded. Everything fed by samples,
+/// including [`memory_stream`], then sees the recorded data, stamped with
+/// the time it was recorded. The memory history starts over.
+pub fn start_replay(path: String, speed: f64) -> Result<(), MemoryError> {
+    if !recording::is_valid_speed(speed) {
+        return Err(MemoryError::InvalidInput {
+            message: format!("invalid replay speed {speed}"),
+        });
+    }
+    let replay = ReplaySource::open(&path, speed)
+        .map_err(|err| MemoryError::io(&err, format_args!("failed to read {path}")))?;
+    sampler().replace_source(Box::new(replay));
+    history().clear();
+    Ok(())
+}
+
+/// Switches back from a replay to the live system. The memory history
+/// starts over.
+pub fn stop_replay() -> Result<(), MemoryError> {
+    sampler().replace_source(Box::new(SysinfoSource::new()));
+    history().clear();
+    Ok(())
+}
+
+/// Pushes a fresh [`Memory`] snapshot to `sink` every `interval_ms`
+/// milliseconds, sampled on a dedicated thread, until Dart cancels the
+/// stream. Subscribing again replaces the previous sink.
+pub fn memory_stream(sink: StreamSink<Memory>, interval_ms: u64) -> Result<(), MemoryError> {
+    let control = MEMORY_STREAM.clone();
+    control.set_interval(Duration::from_millis(interval_ms));
+    control.resume();
+
+    MEMORY_STREAM_SUBSCRIBER.subscribe(Box::new(move |memory| sink.add(memory).is_ok()), || {
+        thread::Builder::new()
+            .name("memory-stream".into())
+            .spawn(move || {
+                monitor::run(&control, refresh, |memory| {
+                    MEMORY_STREAM_SUBSCRIBER.emit(memory)
+                })
+            })
+            .map(drop)
+    })?;
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_memory_stream_interval(interval_ms: u64) -> Result<(), MemoryError> {
+    MEMORY_STREAM.set_interval(Duration::from_millis(interval_ms));
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn pause_memory_stream() -> Result<(), MemoryError> {
+    MEMORY_STREAM.pause();
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn resume_memory_stream() -> Result<(), MemoryError> {
+    MEMORY_STREAM.resume();
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn storage_to_float(storage: &Storage) -> Result<f64, MemoryError> {
     Ok(storage.to_float())
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn unit_to_string(unit: Unit) -> anyhow::Result<String> {
+pub fn unit_to_string(unit: StorageUnit) -> Result<String, MemoryError> {
     Ok(unit.to_string())
 }
 
+/// Formats `storage` in `system`, or in the global unit system if `None`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn storage_to_string(
+    storage: &Storage,
+    system: Option<UnitSystem>,
+) -> Result<String, MemoryError> {
+    Ok(storage.format(system))
+}
+
+/// Parses a size such as `512M`, `2 GB` or `1.5 GiB`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn parse_storage(input: String) -> Result<Storage, MemoryError> {
+    Ok(input.parse::<Storage>()?)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn default_storage_format_options() -> Result<StorageFormatOptions, MemoryError> {
+    Ok(StorageFormatOptions::default())
+}
+
+/// Default format options with the separators of `locale`, e.g. `de-DE`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn storage_format_options_for_locale(
+    locale: String,
+) -> Result<StorageFormatOptions, MemoryError> {
+    Ok(StorageFormatOptions::for_locale(&locale))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn format_storage(
+    storage: &Storage,
+    options: StorageFormatOptions,
+) -> Result<String, MemoryError> {
+    Ok(StorageFormatter::from(options).format(storage))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn convert_storage(storage: &Storage, unit: StorageUnit) -> Result<Storage, MemoryError> {
+    Ok(storage.to_unit(unit))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_unit_system() -> Result<UnitSystem, MemoryError> {
+    Ok(utils::default_unit_system())
+}
+
+/// Sets the unit system used by [`storage_to_string`] when the call does not
+/// pick one.
+///
+/// Amounts keep the unit they were created in, so [`storage_to_float`] and
+/// [`unit_to_string`] are not affected; display amounts through
+/// [`storage_to_string`] to follow this setting.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_unit_system(system: UnitSystem) -> Result<(), MemoryError> {
+    utils::set_default_unit_system(system);
+    Ok(())
+}
+
 #[flutter_rust_bridge::frb(init)]
-pub fn init_app() -> anyhow::Result<bool> {
+pub fn init_app() -> Result<bool, MemoryError> {
     flutter_rust_bridge::setup_default_user_utils();
     Ok(true)
 }
@@ -0,0 +1,253 @@
+//! Command-line front end for the memory monitor, for machines without the
+//! Flutter UI.
+
+mod output;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use output::{Cell, Format, Table};
+use rust_lib_flutter_demo::{
+    history,
+    memory::{Memory, MemoryMetric, ProcessTreeNode},
+    monitor::{self, MonitorControl},
+    sampler::Sampler,
+    utils::{self, UnitSystem},
+};
+use serde_json::{Value, json};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+    time::Duration,
+};
+
+#[derive(Parser)]
+#[command(version, about = "Inspect system and per-process memory usage")]
+struct Cli {
+    #[arg(long, short, value_enum, default_value_t = Format::Table, global = true)]
+    format: Format,
+    /// What each process' memory is measured and summed by.
+    #[arg(long, short, value_enum, default_value_t = Metric::Rss, global = true)]
+    metric: Metric,
+    /// Whether sizes are printed in binary (KiB, MiB) or decimal (kB, MB)
+    /// units.
+    #[arg(long, short, value_enum, default_value_t = Units::Iec, global = true)]
+    units: Units,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print system memory and swap usage.
+    Snapshot,
+    /// List the top-level processes using the most memory, descendants
+    /// included.
+    Top {
+        #[arg(default_value_t = 10)]
+        n: usize,
+    },
+    /// Print the process hierarchy.
+    Tree,
+    /// Print system memory usage repeatedly.
+    Watch {
+        /// Milliseconds between two samples.
+        #[arg(long, short, default_value_t = 1000)]
+        interval: u64,
+        /// Stop after this many samples.
+        #[arg(long, short)]
+        count: Option<usize>,
+    },
+    /// Write every process to a file, or to stdout.
+    Export {
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Metric {
+    Rss,
+    Pss,
+    Uss,
+}
+
+impl From<Metric> for MemoryMetric {
+    fn from(metric: Metric) -> Self {
+        match metric {
+            Metric::Rss => MemoryMetric::Rss,
+            Metric::Pss => MemoryMetric::Pss,
+            Metric::Uss => MemoryMetric::Uss,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Units {
+    Iec,
+    Si,
+}
+
+impl From<Units> for UnitSystem {
+    fn from(units: Units) -> Self {
+        match units {
+            Units::Iec => UnitSystem::Iec,
+            Units::Si => UnitSystem::Si,
+        }
+    }
+}
+
+const SYSTEM_HEADERS: &[&str] = &[
+    "timestamp",
+    "used_memory",
+    "total_memory",
+    "used_swap",
+    "total_swap",
+    "processes",
+];
+
+const PROCESS_HEADERS: &[&str] = &["pid", "parent", "name", "user", "memory", "total_memory"];
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        // Piping into `head` closes stdout early; that is not an error.
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("memmon: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: &Cli) -> io::Result<()> {
+    let mut sampler = Sampler::new();
+    sampler.set_metric(cli.metric.into());
+    utils::set_default_unit_system(cli.units.into());
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    match &cli.command {
+        Command::Snapshot => {
+            let mut table = Table::new(SYSTEM_HEADERS);
+            table.push(system_row(sampler.refresh()));
+            table.write(&mut out, cli.format)
+        }
+        Command::Top { n } => {
+            let memory = sampler.refresh();
+            let mut table = Table::new(PROCESS_HEADERS);
+            for (pid, _) in memory.first(*n) {
+                table.push(process_row(memory, pid));
+            }
+            table.write(&mut out, cli.format)
+        }
+        Command::Tree => {
+            let memory = sampler.refresh();
+            let roots = memory
+                .process_tree(cli.metric.into())
+                .map_err(io::Error::other)?;
+            write_tree(&mut out, cli.format, &roots)
+        }
+        Command::Watch { interval, count } => {
+            let control = MonitorControl::new(Duration::from_millis(*interval));
+            let mut written = 0;
+            let mut result = Ok(());
+            monitor::run(
+                &control,
+                || sampler.refresh().clone(),
+                |memory| {
+                    let mut table = Table::new(SYSTEM_HEADERS);
+                    table.push(system_row(&memory));
+                    result = table
+                        .write_rows(&mut out, cli.format, written == 0)
+                        .and_then(|()| out.flush());
+                    written += 1;
+                    result.is_ok() && count.is_none_or(|count| written < count)
+                },
+            );
+            result
+        }
+        Command::Export { output } => {
+            let memory = sampler.refresh();
+            let mut table = Table::new(PROCESS_HEADERS);
+            let mut pids = memory.processes.keys().copied().collect::<Vec<_>>();
+            pids.sort_unstable();
+            for pid in pids {
+                table.push(process_row(memory, pid));
+            }
+
+            match output {
+                Some(path) => {
+                    let mut file = BufWriter::new(File::create(path)?);
+                    table.write(&mut file, cli.format)?;
+                    file.flush()
+                }
+                None => table.write(&mut out, cli.format),
+            }
+        }
+    }
+}
+
+fn system_row(memory: &Memory) -> Vec<Cell> {
+    vec![
+        Cell::Number(history::now_millis()),
+        Cell::Bytes(memory.used_memory.to_bytes()),
+        Cell::Bytes(memory.total_memory.to_bytes()),
+        Cell::Bytes(memory.used_swap.to_bytes()),
+        Cell::Bytes(memory.total_swap.to_bytes()),
+        Cell::Number(memory.processes.len() as u64),
+    ]
+}
+
+fn process_row(memory: &Memory, pid: u32) -> Vec<Cell> {
+    let process = &memory.processes[&pid];
+    vec![
+        Cell::Number(pid.into()),
+        Cell::optional(process.parent.map(|parent| parent.to_string())),
+        Cell::text(&process.name),
+        Cell::optional(process.user.as_deref()),
+        Cell::Bytes(process.raw_memory),
+        Cell::Bytes(process.total_memory.to_bytes()),
+    ]
+}
+
+fn write_tree(out: &mut impl Write, format: Format, roots: &[ProcessTreeNode]) -> io::Result<()> {
+    if format == Format::Json {
+        let roots = roots.iter().map(tree_json).collect::<Vec<_>>();
+        serde_json::to_writer_pretty(&mut *out, &roots)?;
+        return writeln!(out);
+    }
+
+    fn flatten(table: &mut Table, nodes: &[ProcessTreeNode], indent: bool) {
+        for node in nodes {
+            let name = if indent {
+                format!("{}{}", "  ".repeat(node.depth as usize), node.name)
+            } else {
+                node.name.clone()
+            };
+            table.push(vec![
+                Cell::Number(node.pid.into()),
+                Cell::Number(node.depth.into()),
+                Cell::Text(name),
+                Cell::Bytes(node.memory.to_bytes()),
+                Cell::Bytes(node.total_memory.to_bytes()),
+            ]);
+            flatten(table, &node.children, indent);
+        }
+    }
+
+    let mut table = Table::new(&["pid", "depth", "name", "memory", "total_memory"]);
+    flatten(&mut table, roots, format == Format::Table);
+    table.write(out, format)
+}
+
+fn tree_json(node: &ProcessTreeNode) -> Value {
+    json!({
+        "pid": node.pid,
+        "name": node.name,
+        "memory": node.memory.to_bytes(),
+        "total_memory": node.total_memory.to_bytes(),
+        "children": node.children.iter().map(tree_json).collect::<Vec<_>>(),
+    })
+}
@@ -0,0 +1,176 @@
+use clap::ValueEnum;
+use rust_lib_flutter_demo::utils::Storage;
+use serde_json::{Map, Value};
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Aligned columns with human-readable sizes.
+    Table,
+    /// JSON, with sizes in bytes.
+    Json,
+    /// Comma-separated values, with sizes in bytes.
+    Csv,
+}
+
+pub enum Cell {
+    Text(String),
+    Number(u64),
+    Bytes(u64),
+}
+
+impl Cell {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    pub fn optional(text: Option<impl Into<String>>) -> Self {
+        Self::Text(text.map(Into::into).unwrap_or_default())
+    }
+
+    fn human(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Number(number) => number.to_string(),
+            Self::Bytes(bytes) => Storage::from_bytes(*bytes).format(None),
+        }
+    }
+
+    fn raw(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Number(number) | Self::Bytes(number) => number.to_string(),
+        }
+    }
+
+    fn json(&self) -> Value {
+        match self {
+            Self::Text(text) => Value::from(text.as_str()),
+            Self::Number(number) | Self::Bytes(number) => Value::from(*number),
+        }
+    }
+}
+
+/// Rows of named columns, rendered in any [`Format`].
+pub struct Table {
+    pub headers: &'static [&'static str],
+    pub rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    pub fn new(headers: &'static [&'static str]) -> Self {
+        Self {
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<Cell>) {
+        debug_assert_eq!(row.len(), self.headers.len());
+        self.rows.push(row);
+    }
+
+    pub fn write(&self, out: &mut impl Write, format: Format) -> io::Result<()> {
+        match format {
+            Format::Table => self.write_table(out, true),
+            Format::Json => {
+                let rows = self.rows.iter().map(|row| self.json_row(row)).collect();
+                serde_json::to_writer_pretty(&mut *out, &Value::Array(rows))?;
+                writeln!(out)
+            }
+            Format::Csv => self.write_csv(out, true),
+        }
+    }
+
+    /// Writes the rows one at a time for streaming output, such as `watch`.
+    /// Headers are only written along with the first rows.
+    pub fn write_rows(&self, out: &mut impl Write, format: Format, first: bool) -> io::Result<()> {
+        match format {
+            Format::Table => self.write_table(out, first),
+            Format::Json => {
+                for row in &self.rows {
+                    serde_json::to_writer(&mut *out, &self.json_row(row))?;
+                    writeln!(out)?;
+                }
+                Ok(())
+            }
+            Format::Csv => self.write_csv(out, first),
+        }
+    }
+
+    fn json_row(&self, row: &[Cell]) -> Value {
+        let object = self
+            .headers
+            .iter()
+            .zip(row)
+            .map(|(header, cell)| (header.to_string(), cell.json()))
+            .collect::<Map<_, _>>();
+        Value::Object(object)
+    }
+
+    fn write_table(&self, out: &mut impl Write, headers: bool) -> io::Result<()> {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(Cell::human).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let widths = self
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                rows.iter()
+                    .map(|row| row[i].chars().count())
+                    .chain([header.len()])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        let write_line = |out: &mut dyn Write, cells: Vec<&str>| {
+            let line = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(out, "{}", line.trim_end())
+        };
+
+        if headers {
+            let upper = self
+                .headers
+                .iter()
+                .map(|h| h.to_uppercase())
+                .collect::<Vec<_>>();
+            write_line(out, upper.iter().map(String::as_str).collect())?;
+        }
+        for row in &rows {
+            write_line(out, row.iter().map(String::as_str).collect())?;
+        }
+        Ok(())
+    }
+
+    fn write_csv(&self, out: &mut impl Write, headers: bool) -> io::Result<()> {
+        if headers {
+            writeln!(out, "{}", self.headers.join(","))?;
+        }
+        for row in &self.rows {
+            let line = row
+                .iter()
+                .map(|cell| csv_escape(&cell.raw()))
+                .collect::<Vec<_>>()
+                .join(",");
+            writeln!(out, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,419 @@
+use rust_lib_flutter_demo::{
+    control::{self, Signal, SignalStatus},
+    memory::{Memory, ProcessTreeNode},
+    sampler::Sampler,
+    source::{MemorySource, SysinfoSource},
+    utils::Storage,
+};
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_TOP_N: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Top,
+    Tree,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    TotalMemory,
+    Memory,
+    Name,
+    Pid,
+}
+
+impl SortKey {
+    pub fn label(self) -> &'static str {
+        match self {
+            SortKey::TotalMemory => "total",
+            SortKey::Memory => "memory",
+            SortKey::Name => "name",
+            SortKey::Pid => "pid",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            SortKey::TotalMemory => SortKey::Memory,
+            SortKey::Memory => SortKey::Name,
+            SortKey::Name => SortKey::Pid,
+            SortKey::Pid => SortKey::TotalMemory,
+        }
+    }
+}
+
+/// A line of the process table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub pid: u32,
+    pub name: String,
+    pub user: Option<String>,
+    pub depth: u32,
+    pub memory: Storage,
+    pub total_memory: Storage,
+    /// `None` for processes without children.
+    pub expanded: Option<bool>,
+}
+
+/// A process the user is about to signal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KillRequest {
+    pub pid: u32,
+    pub name: String,
+    /// Tells the process apart from a later one reusing its PID.
+    pub start_time: u64,
+}
+
+/// Everything the dashboard shows, independent of the terminal.
+pub struct App<S = SysinfoSource> {
+    sampler: Sampler<S>,
+    pub memory: Memory,
+    refreshed_at: Instant,
+    pub view: View,
+    pub sort: SortKey,
+    pub descending: bool,
+    pub top_n: usize,
+    expanded: HashSet<u32>,
+    pub rows: Vec<Row>,
+    pub selected: usize,
+    /// Process waiting for the user to pick a signal.
+    pub confirm_kill: Option<KillRequest>,
+    pub status: Option<String>,
+    pub should_quit: bool,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self::with_sampler(Sampler::new())
+    }
+}
+
+impl<S: MemorySource> App<S> {
+    pub fn with_sampler(mut sampler: Sampler<S>) -> Self {
+        let memory = sampler.refresh().clone();
+        let mut app = Self {
+            sampler,
+            memory,
+            refreshed_at: Instant::now(),
+            view: View::Top,
+            sort: SortKey::TotalMemory,
+            descending: true,
+            top_n: DEFAULT_TOP_N,
+            expanded: HashSet::new(),
+            rows: Vec::new(),
+            selected: 0,
+            confirm_kill: None,
+            status: None,
+            should_quit: false,
+        };
+        app.rebuild_rows();
+        app
+    }
+
+    /// Time left until the next sample is due.
+    pub fn until_refresh(&self) -> Duration {
+        REFRESH_INTERVAL.saturating_sub(self.refreshed_at.elapsed())
+    }
+
+    pub fn refresh(&mut self) {
+        self.memory = self.sampler.refresh().clone();
+        self.refreshed_at = Instant::now();
+        self.rebuild_rows();
+    }
+
+    pub fn toggle_view(&mut self) {
+        self.view = match self.view {
+            View::Top => View::Tree,
+            View::Tree => View::Top,
+        };
+        self.selected = 0;
+        self.rebuild_rows();
+    }
+
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        // Names and PIDs read naturally A to Z, sizes largest first.
+        self.descending = matches!(self.sort, SortKey::TotalMemory | SortKey::Memory);
+        self.rebuild_rows();
+    }
+
+    pub fn reverse_sort(&mut self) {
+        self.descending = !self.descending;
+        self.rebuild_rows();
+    }
+
+    pub fn change_top_n(&mut self, delta: isize) {
+        self.top_n = self.top_n.saturating_add_signed(delta).max(1);
+        self.rebuild_rows();
+    }
+
+    pub fn select(&mut self, delta: isize) {
+        let last = self.rows.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    pub fn selected_row(&self) -> Option<&Row> {
+        self.rows.get(self.selected)
+    }
+
+    /// Expands or collapses the selected tree node.
+    pub fn set_expanded(&mut self, expanded: bool) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+        if row.expanded.is_none() {
+            return;
+        }
+        let pid = row.pid;
+        if expanded {
+            self.expanded.insert(pid);
+        } else {
+            self.expanded.remove(&pid);
+        }
+        self.rebuild_rows();
+    }
+
+    pub fn toggle_expanded(&mut self) {
+        let expanded = self.selected_row().and_then(|row| row.expanded);
+        if let Some(expanded) = expanded {
+            self.set_expanded(!expanded);
+        }
+    }
+
+    pub fn request_kill(&mut self) {
+        self.confirm_kill = self.selected_row().map(|row| KillRequest {
+            pid: row.pid,
+            name: row.name.clone(),
+            start_time: self
+                .memory
+                .processes
+                .get(&row.pid)
+                .map_or(0, |process| process.start_time),
+        });
+    }
+
+    pub fn cancel_kill(&mut self) {
+        self.confirm_kill = None;
+    }
+
+    pub fn kill(&mut self, signal: Signal) {
+        let Some(KillRequest {
+            pid,
+            name,
+            start_time,
+        }) = self.confirm_kill.take()
+        else {
+            return;
+        };
+        let result = control::send_signal_to(pid, start_time, signal);
+        self.status = Some(match result.status {
+            SignalStatus::Sent => format!("Sent {signal:?} to {name} ({pid})"),
+            SignalStatus::PermissionDenied => format!("Not allowed to signal {name} ({pid})"),
+            SignalStatus::NotFound => format!("{name} ({pid}) has already exited"),
+            SignalStatus::InvalidPid => format!("Refusing to signal PID {pid}"),
+            SignalStatus::Unsupported => String::from("Signals are not supported here"),
+            SignalStatus::Failed { message } => format!("Failed to signal {pid}: {message}"),
+        });
+        self.refresh();
+    }
+
+    fn rebuild_rows(&mut self) {
+        let selected_pid = self.selected_row().map(|row| row.pid);
+        self.rows = match self.view {
+            View::Top => self.top_rows(),
+            View::Tree => self.tree_rows(),
+        };
+        // Keep the cursor on the same process across refreshes.
+        self.selected = selected_pid
+            .and_then(|pid| self.rows.iter().position(|row| row.pid == pid))
+            .unwrap_or(self.selected)
+            .min(self.rows.len().saturating_sub(1));
+    }
+
+    fn top_rows(&self) -> Vec<Row> {
+        let mut rows = self
+            .memory
+            .first(self.top_n)
+            .into_iter()
+            .map(|(pid, process)| Row {
+                pid,
+                name: process.name,
+                user: process.user,
+                depth: 0,
+                memory: process.memory,
+                total_memory: process.total_memory,
+                expanded: None,
+            })
+            .collect::<Vec<_>>();
+        rows.sort_by(|a, b| self.compare(a, b));
+        rows
+    }
+
+    fn tree_rows(&self) -> Vec<Row> {
+        let roots = self
+            .memory
+            .process_tree(self.sampler.metric())
+            .unwrap_or_default();
+        let mut rows = Vec::new();
+        self.flatten(&roots, &mut rows);
+        rows
+    }
+
+    fn flatten(&self, nodes: &[ProcessTreeNode], rows: &mut Vec<Row>) {
+        let mut nodes = nodes.iter().collect::<Vec<_>>();
+        nodes.sort_by(|a, b| self.compare(&self.node_row(a, None), &self.node_row(b, None)));
+        for node in nodes {
+            let expanded = self.expanded.contains(&node.pid);
+            let has_children = !node.children.is_empty();
+            rows.push(self.node_row(node, has_children.then_some(expanded)));
+            if has_children && expanded {
+                self.flatten(&node.children, rows);
+            }
+        }
+    }
+
+    fn compare(&self, a: &Row, b: &Row) -> Ordering {
+        let ordering = match self.sort {
+            SortKey::TotalMemory => a.total_memory.cmp(&b.total_memory),
+            SortKey::Memory => a.memory.cmp(&b.memory),
+            SortKey::Name => a.name.cmp(&b.name),
+            SortKey::Pid => Ordering::Equal,
+        }
+        .then(a.pid.cmp(&b.pid));
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    fn node_row(&self, node: &ProcessTreeNode, expanded: Option<bool>) -> Row {
+        Row {
+            pid: node.pid,
+            name: node.name.clone(),
+            user: self
+                .memory
+                .processes
+                .get(&node.pid)
+                .and_then(|process| process.user.clone()),
+            depth: node.depth,
+            memory: node.memory.clone(),
+            total_memory: node.total_memory.clone(),
+            expanded,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_lib_flutter_demo::source::{FixtureSource, RawProcess, RawSnapshot};
+
+    fn app() -> App<FixtureSource> {
+        let raw = |pid, parent, memory| RawProcess {
+            pid,
+            name: format!("p{pid}"),
+            parent,
+            memory,
+            ..RawProcess::default()
+        };
+        let source = FixtureSource::new(RawSnapshot {
+            processes: vec![
+                raw(1, None, 10),
+                raw(10, Some(1), 100),
+                raw(11, Some(10), 300),
+                raw(12, Some(10), 50),
+                raw(20, Some(1), 200),
+            ],
+            ..RawSnapshot::default()
+        });
+        App::with_sampler(Sampler::with_source(source, Duration::ZERO))
+    }
+
+    fn pids(app: &App<FixtureSource>) -> Vec<u32> {
+        app.rows.iter().map(|row| row.pid).collect()
+    }
+
+    #[test]
+    fn test_top_sorting() {
+        let mut app = app();
+        assert_eq!(pids(&app), vec![10, 20, 1]);
+
+        app.cycle_sort();
+        assert_eq!(app.sort, SortKey::Memory);
+        assert_eq!(pids(&app), vec![20, 10, 1]);
+
+        app.reverse_sort();
+        assert_eq!(pids(&app), vec![1, 10, 20]);
+
+        app.change_top_n(-18);
+        assert_eq!(app.top_n, 2);
+        assert_eq!(pids(&app).len(), 2);
+    }
+
+    #[test]
+    fn test_tree_expansion() {
+        let mut app = app();
+        app.toggle_view();
+        assert_eq!(pids(&app), vec![1]);
+        assert_eq!(app.rows[0].expanded, Some(false));
+
+        app.toggle_expanded();
+        assert_eq!(pids(&app), vec![1, 10, 20]);
+
+        app.select(1);
+        app.set_expanded(true);
+        assert_eq!(pids(&app), vec![1, 10, 11, 12, 20]);
+        assert_eq!(app.rows[2].depth, 2);
+        assert_eq!(app.rows[2].expanded, None);
+
+        // The selection follows the process when rows move.
+        app.select(-1);
+        app.set_expanded(false);
+        assert_eq!(pids(&app), vec![1]);
+        assert_eq!(app.selected_row().unwrap().pid, 1);
+    }
+
+    #[test]
+    fn test_selection_is_clamped() {
+        let mut app = app();
+        app.select(-5);
+        assert_eq!(app.selected, 0);
+        app.select(100);
+        assert_eq!(app.selected, 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_checks_start_time() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        // Same PID, but a different process than the one in the snapshot.
+        let source = FixtureSource::new(RawSnapshot {
+            processes: vec![RawProcess {
+                pid: child.id(),
+                name: String::from("sleep"),
+                start_time: 1,
+                ..RawProcess::default()
+            }],
+            ..RawSnapshot::default()
+        });
+        let mut app = App::with_sampler(Sampler::with_source(source, Duration::ZERO));
+        app.request_kill();
+        assert_eq!(app.confirm_kill.as_ref().unwrap().start_time, 1);
+
+        app.kill(Signal::Kill);
+        assert!(app.status.as_ref().unwrap().contains("already exited"));
+        assert!(child.try_wait().unwrap().is_none());
+        child.kill().unwrap();
+        child.wait().unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+//! Terminal dashboard for the memory monitor, showing the same data as the
+//! Flutter UI.
+
+mod app;
+mod ui;
+
+use app::{App, View};
+use ratatui::{
+    DefaultTerminal,
+    crossterm::event::{self, Event, KeyCode, KeyEvent},
+};
+use rust_lib_flutter_demo::control::Signal;
+use std::io;
+
+fn main() -> io::Result<()> {
+    ratatui::run(|terminal| run(terminal, App::new()))
+}
+
+fn run(terminal: &mut DefaultTerminal, mut app: App) -> io::Result<()> {
+    while !app.should_quit {
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+
+        if event::poll(app.until_refresh())? {
+            if let Event::Key(key) = event::read()?
+                && key.is_press()
+            {
+                handle_key(&mut app, key);
+            }
+        } else {
+            app.refresh();
+        }
+    }
+    Ok(())
+}
+
+fn handle_key(app: &mut App, key: KeyEvent) {
+    if app.confirm_kill.is_some() {
+        match key.code {
+            KeyCode::Char('t') => app.kill(Signal::Term),
+            // Not `k`, which moves the selection up everywhere else.
+            KeyCode::Char('9') => app.kill(Signal::Kill),
+            KeyCode::Esc | KeyCode::Char('n') => app.cancel_kill(),
+            _ => {}
+        }
+        return;
+    }
+
+    app.status = None;
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => app.should_quit = true,
+        KeyCode::Tab => app.toggle_view(),
+        KeyCode::Char('s') => app.cycle_sort(),
+        KeyCode::Char('r') => app.reverse_sort(),
+        KeyCode::Char('x') => app.request_kill(),
+        KeyCode::Up | KeyCode::Char('k') => app.select(-1),
+        KeyCode::Down | KeyCode::Char('j') => app.select(1),
+        KeyCode::PageUp => app.select(-10),
+        KeyCode::PageDown => app.select(10),
+        KeyCode::Home => app.select(isize::MIN),
+        KeyCode::End => app.select(isize::MAX),
+        KeyCode::Char('+') if app.view == View::Top => app.change_top_n(1),
+        KeyCode::Char('-') if app.view == View::Top => app.change_top_n(-1),
+        KeyCode::Enter | KeyCode::Char(' ') => app.toggle_expanded(),
+        KeyCode::Right | KeyCode::Char('l') => app.set_expanded(true),
+        KeyCode::Left | KeyCode::Char('h') => app.set_expanded(false),
+        _ => {}
+    }
+}
@@ -0,0 +1,180 @@
+use crate::app::{App, View};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Gauge, Paragraph, Row, Table, TableState},
+};
+use rust_lib_flutter_demo::{source::MemorySource, utils::Storage};
+
+pub fn draw<S: MemorySource>(frame: &mut Frame, app: &App<S>) {
+    let [gauges, table, footer] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    draw_gauges(frame, app, gauges);
+    draw_table(frame, app, table);
+    draw_footer(frame, app, footer);
+
+    if let Some(request) = &app.confirm_kill {
+        draw_kill_dialog(frame, request.pid, &request.name);
+    }
+}
+
+fn draw_gauges<S: MemorySource>(frame: &mut Frame, app: &App<S>, area: Rect) {
+    let [memory, swap] =
+        Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).areas(area);
+    let memory_info = &app.memory;
+    frame.render_widget(
+        gauge(
+            "Memory",
+            &memory_info.used_memory,
+            &memory_info.total_memory,
+            Color::Green,
+        ),
+        memory,
+    );
+    frame.render_widget(
+        gauge(
+            "Swap",
+            &memory_info.used_swap,
+            &memory_info.total_swap,
+            Color::Magenta,
+        ),
+        swap,
+    );
+}
+
+fn gauge<'a>(title: &'a str, used: &Storage, total: &Storage, color: Color) -> Gauge<'a> {
+    let ratio = used.ratio(total).unwrap_or(0.0);
+    Gauge::default()
+        .block(Block::bordered().title(title))
+        .gauge_style(Style::new().fg(color))
+        .ratio(ratio.clamp(0.0, 1.0))
+        .label(format!("{used} / {total} ({:.1}%)", ratio * 100.0))
+}
+
+fn draw_table<S: MemorySource>(frame: &mut Frame, app: &App<S>, area: Rect) {
+    let rows = app.rows.iter().map(|row| {
+        let marker = match row.expanded {
+            Some(true) => "▾ ",
+            Some(false) => "▸ ",
+            None if app.view == View::Tree => "  ",
+            None => "",
+        };
+        let indent = "  ".repeat(row.depth as usize);
+        Row::new([
+            row.pid.to_string(),
+            format!("{indent}{marker}{}", row.name),
+            row.user.clone().unwrap_or_default(),
+            row.memory.to_string(),
+            row.total_memory.to_string(),
+        ])
+    });
+
+    let arrow = if app.descending { "↓" } else { "↑" };
+    let title = match app.view {
+        View::Top => format!(" Top {} ", app.top_n),
+        View::Tree => String::from(" Process tree "),
+    };
+    let table =
+        Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Fill(1),
+                Constraint::Length(12),
+                Constraint::Length(12),
+                Constraint::Length(12),
+            ],
+        )
+        .header(Row::new(["PID", "NAME", "USER", "MEMORY", "TOTAL"]).bold())
+        .block(Block::bordered().title(title).title_bottom(
+            Line::from(format!(" sort: {} {arrow} ", app.sort.label())).right_aligned(),
+        ))
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+    let mut state = TableState::new().with_selected(Some(app.selected));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn draw_footer<S: MemorySource>(frame: &mut Frame, app: &App<S>, area: Rect) {
+    let text = match &app.status {
+        Some(status) => status.clone(),
+        None => {
+            let keys = "q quit  tab view  s sort  r reverse  x kill";
+            match app.view {
+                View::Top => format!("{keys}  +/- count"),
+                View::Tree => format!("{keys}  enter/←/→ expand"),
+            }
+        }
+    };
+    frame.render_widget(Paragraph::new(text).dim(), area);
+}
+
+fn draw_kill_dialog(frame: &mut Frame, pid: u32, name: &str) {
+    let [area] = Layout::horizontal([Constraint::Length(44)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Length(4)])
+        .flex(Flex::Center)
+        .areas(area);
+
+    let text = vec![
+        Line::from(format!("Signal {name} ({pid})?")),
+        Line::from("t TERM  9 KILL  esc cancel").dim(),
+    ];
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(text).block(Block::bordered().title(" Kill ").red()),
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+    use rust_lib_flutter_demo::{
+        sampler::Sampler,
+        source::{FixtureSource, RawProcess, RawSnapshot},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_draw() {
+        let source = FixtureSource::new(RawSnapshot {
+            total_memory: 4 << 30,
+            used_memory: 1 << 30,
+            processes: vec![RawProcess {
+                pid: 42,
+                name: String::from("postgres"),
+                memory: 64 << 20,
+                ..RawProcess::default()
+            }],
+            ..RawSnapshot::default()
+        });
+        let mut app = App::with_sampler(Sampler::with_source(source, Duration::ZERO));
+        app.request_kill();
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+
+        let screen = terminal
+            .backend()
+            .buffer()
+            .content()
+            .chunks(80)
+            .map(|line| line.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(screen.contains("1.00 GiB / 4.00 GiB (25.0%)"), "{screen}");
+        assert!(screen.contains("postgres"), "{screen}");
+        assert!(screen.contains("64.00 MiB"), "{screen}");
+        assert!(screen.contains("Signal postgres (42)?"), "{screen}");
+    }
+}
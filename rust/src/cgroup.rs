@@ -0,0 +1,310 @@
+use crate::{procfs, utils::Storage};
+use itertools::Itertools;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where the unified cgroup v2 hierarchy is usually mounted.
+pub const DEFAULT_CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+/// Parses `/proc/<pid>/cgroup` and returns the cgroup v2 path of the
+/// process, such as `/system.slice/sshd.service`.
+///
+/// Only the unified hierarchy (the `0::` entry) is looked at; processes that
+/// only appear in v1 controllers have no path.
+pub fn parse_process_cgroup(text: &str) -> Option<String> {
+    text.lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_process_cgroup(pid: u32) -> io::Result<Option<String>> {
+    let text = fs::read_to_string(procfs::pid_path(pid, "cgroup"))?;
+    Ok(parse_process_cgroup(&text))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_process_cgroup(_pid: u32) -> io::Result<Option<String>> {
+    Err(io::ErrorKind::Unsupported.into())
+}
+
+/// Extracts the ID of the docker or podman container a cgroup path belongs
+/// to.
+///
+/// Recognizes the layouts of both the systemd and the cgroupfs drivers:
+/// `.../docker-<id>.scope`, `/docker/<id>`, `.../libpod-<id>.scope` and
+/// `/libpod_parent/libpod-<id>`.
+pub fn container_id(path: &str) -> Option<String> {
+    path.split('/').rev().find_map(|component| {
+        let id = component.strip_suffix(".scope").unwrap_or(component);
+        let id = ["docker-", "libpod-"]
+            .iter()
+            .find_map(|prefix| id.strip_prefix(prefix))
+            .or_else(|| path.contains("/docker/").then_some(id))?;
+        (id.len() == 64 && id.bytes().all(|b| b.is_ascii_hexdigit())).then(|| id.to_string())
+    })
+}
+
+/// Counters from `memory.events`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CgroupEvents {
+    pub low: u64,
+    pub high: u64,
+    pub max: u64,
+    pub oom: u64,
+    pub oom_kill: u64,
+}
+
+impl CgroupEvents {
+    pub fn parse(text: &str) -> Self {
+        let mut events = Self::default();
+        for (key, value) in parse_flat_keyed(text) {
+            match key {
+                "low" => events.low = value,
+                "high" => events.high = value,
+                "max" => events.max = value,
+                "oom" => events.oom = value,
+                "oom_kill" => events.oom_kill = value,
+                _ => {}
+            }
+        }
+        events
+    }
+}
+
+/// Memory controller files of a single cgroup. Files missing from the
+/// cgroup, such as `memory.current` of the root cgroup, are left unset.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CgroupMemory {
+    pub current: Option<Storage>,
+    /// `None` when unset or unlimited.
+    pub max: Option<Storage>,
+    /// `None` when unset or unlimited.
+    pub high: Option<Storage>,
+    /// Entries of `memory.stat`, in bytes for the size entries, in file
+    /// order.
+    pub stat: Vec<(String, u64)>,
+    pub events: CgroupEvents,
+}
+
+/// A cgroup and its descendants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CgroupNode {
+    /// Path relative to the cgroup root, `/` for the root itself, matching
+    /// the paths in `/proc/<pid>/cgroup`.
+    pub path: String,
+    pub container_id: Option<String>,
+    pub memory: CgroupMemory,
+    /// PIDs listed in `cgroup.procs`.
+    pub processes: Vec<u32>,
+    /// Sorted by path.
+    pub children: Vec<CgroupNode>,
+}
+
+/// Reader for a cgroup v2 hierarchy mounted at `root`.
+#[derive(Debug, Clone)]
+pub struct CgroupFs {
+    root: PathBuf,
+}
+
+impl Default for CgroupFs {
+    fn default() -> Self {
+        Self::new(DEFAULT_CGROUP_ROOT)
+    }
+}
+
+impl CgroupFs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Reads the memory controller files of the cgroup at `path`.
+    pub fn memory(&self, path: &str) -> io::Result<CgroupMemory> {
+        let dir = self.dir(path);
+        if !dir.is_dir() {
+            return Err(io::ErrorKind::NotFound.into());
+        }
+
+        let read = |file: &str| fs::read_to_string(dir.join(file)).ok();
+        let limit = |file: &str| read(file).as_deref().and_then(parse_limit);
+        Ok(CgroupMemory {
+            current: limit("memory.current"),
+            max: limit("memory.max"),
+            high: limit("memory.high"),
+            stat: read("memory.stat")
+                .map(|text| {
+                    parse_flat_keyed(&text)
+                        .map(|(key, value)| (key.to_string(), value))
+                        .collect_vec()
+                })
+                .unwrap_or_default(),
+            events: read("memory.events")
+                .map(|text| CgroupEvents::parse(&text))
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Reads the whole hierarchy. Only supported on Linux.
+    #[cfg(target_os = "linux")]
+    pub fn read_tree(&self) -> io::Result<CgroupNode> {
+        self.tree("/")
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read_tree(&self) -> io::Result<CgroupNode> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    /// Reads the whole hierarchy below `path`.
+    pub fn tree(&self, path: &str) -> io::Result<CgroupNode> {
+        let memory = self.memory(path)?;
+        let processes = fs::read_to_string(self.dir(path).join("cgroup.procs"))
+            .map(|text| {
+                text.lines()
+                    .filter_map(|line| line.trim().parse().ok())
+                    .collect_vec()
+            })
+            .unwrap_or_default();
+
+        let mut children = Vec::new();
+        for entry in fs::read_dir(self.dir(path))? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            let child = format!("{}/{name}", path.trim_end_matches('/'));
+            // Cgroups can disappear while the hierarchy is being walked.
+            match self.tree(&child) {
+                Ok(node) => children.push(node),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+        children.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(CgroupNode {
+            path: path.to_string(),
+            container_id: container_id(path),
+            memory,
+            processes,
+            children,
+        })
+    }
+
+    fn dir(&self, path: &str) -> PathBuf {
+        self.root.join(path.trim_start_matches('/'))
+    }
+}
+
+/// Parses `key value` lines as found in `memory.stat` and `memory.events`.
+fn parse_flat_keyed(text: &str) -> impl Iterator<Item = (&str, u64)> {
+    text.lines().filter_map(|line| {
+        let (key, value) = line.split_once(' ')?;
+        Some((key, value.trim().parse().ok()?))
+    })
+}
+
+/// Parses a single-value file such as `memory.max`, where `max` means no
+/// limit.
+fn parse_limit(text: &str) -> Option<Storage> {
+    text.trim().parse().ok().map(Storage::from_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCKER_ID: &str = "3f1b6b7d0a8c4e2f9a5d6c7b8e9f0a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6e7f";
+
+    #[test]
+    fn test_parse_process_cgroup() {
+        assert_eq!(
+            parse_process_cgroup("0::/system.slice/sshd.service\n"),
+            Some(String::from("/system.slice/sshd.service"))
+        );
+        assert_eq!(
+            parse_process_cgroup("4:memory:/user\n0::/\n"),
+            Some(String::from("/"))
+        );
+        assert_eq!(parse_process_cgroup("4:memory:/user\n"), None);
+    }
+
+    #[test]
+    fn test_container_id() {
+        let id = Some(String::from(DOCKER_ID));
+        assert_eq!(
+            container_id(&format!("/system.slice/docker-{DOCKER_ID}.scope")),
+            id
+        );
+        assert_eq!(container_id(&format!("/docker/{DOCKER_ID}")), id);
+        assert_eq!(
+            container_id(&format!(
+                "/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-{DOCKER_ID}.scope/container"
+            )),
+            id
+        );
+        assert_eq!(
+            container_id(&format!("/machine.slice/libpod-{DOCKER_ID}.scope")),
+            id
+        );
+        assert_eq!(container_id("/system.slice/docker.service"), None);
+        assert_eq!(container_id("/user.slice/libpod-abc.scope"), None);
+    }
+
+    #[test]
+    fn test_tree() {
+        let root = tempfile::tempdir().unwrap();
+        let write = |path: &str, text: &str| {
+            let path = root.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        };
+        let container = format!("system.slice/docker-{DOCKER_ID}.scope");
+
+        write("cgroup.procs", "1\n");
+        write("memory.stat", "anon 100\nfile 200\n");
+        write("system.slice/memory.current", "4096\n");
+        write("system.slice/memory.max", "max\n");
+        write(&format!("{container}/memory.current"), "1024\n");
+        write(&format!("{container}/memory.max"), "2048\n");
+        write(&format!("{container}/memory.high"), "max\n");
+        write(
+            &format!("{container}/memory.events"),
+            "low 0\nhigh 3\nmax 7\noom 2\noom_kill 1\n",
+        );
+        write(&format!("{container}/cgroup.procs"), "42\n43\n");
+
+        let tree = CgroupFs::new(root.path()).tree("/").unwrap();
+        assert_eq!(tree.path, "/");
+        assert_eq!(tree.processes, vec![1]);
+        assert_eq!(tree.memory.current, None);
+        assert_eq!(
+            tree.memory.stat,
+            vec![(String::from("anon"), 100), (String::from("file"), 200)]
+        );
+
+        let slice = &tree.children[0];
+        assert_eq!(slice.path, "/system.slice");
+        assert_eq!(slice.memory.current, Some(Storage::from_bytes(4096)));
+        assert_eq!(slice.memory.max, None);
+        assert_eq!(slice.container_id, None);
+
+        let container = &slice.children[0];
+        assert_eq!(container.container_id.as_deref(), Some(DOCKER_ID));
+        assert_eq!(container.memory.max, Some(Storage::from_bytes(2048)));
+        assert_eq!(container.memory.high, None);
+        assert_eq!(container.memory.events.oom_kill, 1);
+        assert_eq!(container.memory.events.max, 7);
+        assert_eq!(container.processes, vec![42, 43]);
+
+        assert!(CgroupFs::new(root.path()).tree("/missing").is_err());
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{error::MemoryError, memory::Memory};
+use itertools::Itertools;
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, System};
+
+/// How often [`kill_subtree`] checks whether terminated processes are gone.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    /// Asks the process to terminate; it can clean up or ignore it.
+    Term,
+    /// Terminates the process immediately.
+    Kill,
+    /// Suspends the process until it receives [`Signal::Cont`].
+    Stop,
+    Cont,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignalStatus {
+    Sent,
+    /// The process belongs to another user.
+    PermissionDenied,
+    /// The process does not exist (anymore).
+    NotFound,
+    /// PIDs that would address more than one process, such as 0, are
+    /// rejected.
+    InvalidPid,
+    /// Signals are not supported on this platform.
+    Unsupported,
+    Failed {
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalResult {
+    pub pid: u32,
+    pub signal: Signal,
+    pub status: SignalStatus,
+}
+
+#[cfg(unix)]
+impl Signal {
+    fn number(self) -> libc::c_int {
+        match self {
+            Signal::Term => libc::SIGTERM,
+            Signal::Kill => libc::SIGKILL,
+            Signal::Stop => libc::SIGSTOP,
+            Signal::Cont => libc::SIGCONT,
+        }
+    }
+}
+
+/// Converts `pid` for `kill(2)`, which treats 0 and negative values as
+/// process groups.
+#[cfg(unix)]
+fn target(pid: u32) -> Option<libc::pid_t> {
+    libc::pid_t::try_from(pid).ok().filter(|pid| *pid > 0)
+}
+
+#[cfg(unix)]
+fn raw_kill(pid: libc::pid_t, signal: libc::c_int) -> SignalStatus {
+    // SAFETY: kill(2) has no memory safety requirements, and `pid` is
+    // positive so it only ever addresses a single process.
+    if unsafe { libc::kill(pid, signal) } == 0 {
+        return SignalStatus::Sent;
+    }
+
+    let err = std::io::Error::last_os_error();
+    match err.raw_os_error() {
+        Some(libc::EPERM) => SignalStatus::PermissionDenied,
+        Some(libc::ESRCH) => SignalStatus::NotFound,
+        _ => SignalStatus::Failed {
+            message: err.to_string(),
+        },
+    }
+}
+
+#[cfg(unix)]
+pub fn send_signal(pid: u32, signal: Signal) -> SignalResult {
+    let status = match target(pid) {
+        Some(target) => raw_kill(target, signal.number()),
+        None => SignalStatus::InvalidPid,
+    };
+    SignalResult {
+        pid,
+        signal,
+        status,
+    }
+}
+
+#[cfg(not(unix))]
+pub fn send_signal(pid: u32, signal: Signal) -> SignalResult {
+    SignalResult {
+        pid,
+        signal,
+        status: SignalStatus::Unsupported,
+    }
+}
+
+/// Whether `pid` is still the process that was started at `start_time`,
+/// rather than a new one that reused its PID. Zombies have already exited
+/// and count as gone.
+fn is_running(system: &mut System, pid: u32, start_time: u64) -> bool {
+    let pid = Pid::from_u32(pid);
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing(),
+    );
+    system.process(pid).is_some_and(|process| {
+        process.start_time() == start_time && process.status() != ProcessStatus::Zombie
+    })
+}
+
+fn send_signal_if_running(
+    system: &mut System,
+    pid: u32,
+    start_time: u64,
+    signal: Signal,
+) -> SignalResult {
+    if is_running(system, pid, start_time) {
+        send_signal(pid, signal)
+    } else {
+        SignalResult {
+            pid,
+            signal,
+            status: SignalStatus::NotFound,
+        }
+    }
+}
+
+/// Like [`send_signal`], but only if `pid` is still the process that was
+/// started at `start_time`. Otherwise, the PID was reused, and the result is
+/// [`SignalStatus::NotFound`].
+///
+/// The idle process and init (PIDs 0 and 1) are rejected with
+/// [`SignalStatus::InvalidPid`].
+pub fn send_signal_to(pid: u32, start_time: u64, signal: Signal) -> SignalResult {
+    if pid <= 1 {
+        return SignalResult {
+            pid,
+            signal,
+            status: SignalStatus::InvalidPid,
+        };
+    }
+    send_signal_if_running(&mut System::new(), pid, start_time, signal)
+}
+
+/// `pid` followed by all its descendants in `memory`, parents before their
+/// children.
+///
+/// The idle process and init (PIDs 0 and 1) are rejected, as their subtree
+/// is the whole system.
+pub fn subtree(memory: &Memory, pid: u32) -> Result<Vec<u32>, MemoryError> {
+    if pid <= 1 {
+        return Err(MemoryError::InvalidInput {
+            message: format!("refusing to terminate the subtree of PID {pid}"),
+        });
+    }
+
+    let tree = memory.tree()?;
+    if tree.get_node_by_id(&pid).is_none() {
+        return Err(MemoryError::ProcessGone { pid });
+    }
+
+    let mut pids = Vec::new();
+    let mut pending = vec![pid];
+    while let Some(pid) = pending.pop() {
+        pids.push(pid);
+        if let Some(node) = tree.get_node_by_id(&pid) {
+            let mut children = node.get_children_ids().unwrap_or_default();
+            children.sort_unstable_by(|a, b| b.cmp(a));
+            pending.extend(children);
+        }
+    }
+    Ok(pids)
+}
+
+/// Terminates `pid` and all its descendants.
+///
+/// Every process is sent [`Signal::Term`] first. Processes still alive after
+/// `grace` are sent [`Signal::Kill`]. Returns the last signal sent to each
+/// process, in the order of [`subtree`].
+///
+/// Processes are only signalled while their start time matches `memory`, so
+/// a PID reused in the meantime is reported as [`SignalStatus::NotFound`].
+pub fn kill_subtree(
+    memory: &Memory,
+    pid: u32,
+    grace: Duration,
+) -> Result<Vec<SignalResult>, MemoryError> {
+    let mut system = System::new();
+    let start_time = |pid: u32| memory.processes.get(&pid).map_or(0, |p| p.start_time);
+
+    let mut results = subtree(memory, pid)?
+        .into_iter()
+        .map(|pid| send_signal_if_running(&mut system, pid, start_time(pid), Signal::Term))
+        .collect_vec();
+
+    let pending = |system: &mut System, results: &[SignalResult]| {
+        results
+            .iter()
+            .filter(|result| {
+                result.status == SignalStatus::Sent
+                    && is_running(system, result.pid, start_time(result.pid))
+            })
+            .map(|result| result.pid)
+            .collect_vec()
+    };
+
+    let deadline = Instant::now() + grace;
+    while !pending(&mut system, &results).is_empty() && Instant::now() < deadline {
+        thread::sleep(POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now())));
+    }
+
+    for pid in pending(&mut system, &results) {
+        if let Some(result) = results.iter_mut().find(|result| result.pid == pid) {
+            *result = send_signal_if_running(&mut system, pid, start_time(pid), Signal::Kill);
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::{source::RawProcess, test_support};
+    use std::process::{Child, Command};
+
+    fn spawn(script: &str) -> Child {
+        Command::new("sh").args(["-c", script]).spawn().unwrap()
+    }
+
+    /// Start time of a running process, as sysinfo reports it.
+    fn start_time(pid: u32) -> u64 {
+        let mut system = System::new();
+        system.refresh_processes(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), true);
+        system.process(Pid::from_u32(pid)).unwrap().start_time()
+    }
+
+    fn memory(processes: &[(u32, Option<u32>, u64)]) -> Memory {
+        test_support::memory(
+            processes
+                .iter()
+                .map(|&(pid, parent, start_time)| RawProcess {
+                    name: String::from("sh"),
+                    start_time,
+                    ..test_support::process(pid, parent, 0)
+                }),
+        )
+    }
+
+    #[test]
+    fn test_send_signal() {
+        let mut child = spawn("exec sleep 30");
+        let pid = child.id();
+
+        let result = send_signal(pid, Signal::Stop);
+        assert_eq!(result.status, SignalStatus::Sent);
+        assert_eq!(send_signal(pid, Signal::Cont).status, SignalStatus::Sent);
+        assert_eq!(send_signal(pid, Signal::Term).status, SignalStatus::Sent);
+        child.wait().unwrap();
+
+        assert_eq!(
+            send_signal(pid, Signal::Term).status,
+            SignalStatus::NotFound
+        );
+        assert_eq!(
+            send_signal(0, Signal::Term).status,
+            SignalStatus::InvalidPid
+        );
+        assert_eq!(
+            send_signal(u32::MAX, Signal::Term).status,
+            SignalStatus::InvalidPid
+        );
+    }
+
+    #[test]
+    fn test_send_signal_to() {
+        let mut child = spawn("exec sleep 30");
+        let pid = child.id();
+
+        let reused = send_signal_to(pid, start_time(pid) + 1, Signal::Term);
+        assert_eq!(reused.status, SignalStatus::NotFound);
+        let result = send_signal_to(pid, start_time(pid), Signal::Term);
+        assert_eq!(result.status, SignalStatus::Sent);
+        child.wait().unwrap();
+
+        for pid in [0, 1] {
+            assert_eq!(
+                send_signal_to(pid, start_time(1), Signal::Cont).status,
+                SignalStatus::InvalidPid
+            );
+        }
+    }
+
+    #[test]
+    fn test_permission_denied() {
+        // Unprivileged users may not signal init.
+        // SAFETY: geteuid(2) cannot fail.
+        if unsafe { libc::geteuid() } != 0 {
+            assert_eq!(
+                send_signal(1, Signal::Cont).status,
+                SignalStatus::PermissionDenied
+            );
+        }
+    }
+
+    #[test]
+    fn test_kill_subtree() {
+        let mut parent = spawn("exec sleep 30");
+        // Ignored signals stay ignored across exec.
+        let mut stubborn = spawn("trap '' TERM; exec sleep 30");
+        let (parent_pid, stubborn_pid) = (parent.id(), stubborn.id());
+        let memory = memory(&[
+            (parent_pid, None, start_time(parent_pid)),
+            (stubborn_pid, Some(parent_pid), start_time(stubborn_pid)),
+        ]);
+
+        assert_eq!(
+            subtree(&memory, parent_pid).unwrap(),
+            vec![parent_pid, stubborn_pid]
+        );
+        // Wait for the trap to be installed before signaling.
+        thread::sleep(Duration::from_millis(200));
+
+        let start = Instant::now();
+        let results = kill_subtree(&memory, parent_pid, Duration::from_millis(300)).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        // The parent is not reaped until here, and as a zombie it does not
+        // get escalated.
+        parent.wait().unwrap();
+        stubborn.wait().unwrap();
+
+        assert_eq!(
+            results,
+            vec![
+                SignalResult {
+                    pid: parent_pid,
+                    signal: Signal::Term,
+                    status: SignalStatus::Sent,
+                },
+                SignalResult {
+                    pid: stubborn_pid,
+                    signal: Signal::Kill,
+                    status: SignalStatus::Sent,
+                },
+            ]
+        );
+
+        assert_eq!(
+            kill_subtree(&memory, 999_999_999, Duration::ZERO),
+            Err(MemoryError::ProcessGone { pid: 999_999_999 })
+        );
+        for pid in [0, 1] {
+            assert!(matches!(
+                kill_subtree(&memory, pid, Duration::ZERO),
+                Err(MemoryError::InvalidInput { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_kill_subtree_returns_once_exited() {
+        let mut child = spawn("exec sleep 30");
+        let pid = child.id();
+        let memory = memory(&[(pid, None, start_time(pid))]);
+
+        let start = Instant::now();
+        let results = kill_subtree(&memory, pid, Duration::from_secs(10)).unwrap();
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(results[0].signal, Signal::Term);
+        child.wait().unwrap();
+    }
+
+    #[test]
+    fn test_kill_subtree_skips_reused_pids() {
+        let mut child = spawn("exec sleep 30");
+        let pid = child.id();
+        let memory = memory(&[(pid, None, start_time(pid) - 1)]);
+
+        let results = kill_subtree(&memory, pid, Duration::ZERO).unwrap();
+        assert_eq!(results[0].status, SignalStatus::NotFound);
+        assert_eq!(send_signal(pid, Signal::Kill).status, SignalStatus::Sent);
+        child.wait().unwrap();
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    history::{History, Sample},
+    memory::Memory,
+};
+use itertools::{EitherOrBoth, Itertools};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessChange {
+    Started,
+    Exited,
+    Changed,
+}
+
+/// How a single process changed between two captures, in bytes.
+///
+/// Values missing on one side, such as `before` of a started process, are 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessDiff {
+    pub pid: u32,
+    pub name: String,
+    pub change: ProcessChange,
+    pub memory_before: u64,
+    pub memory_after: u64,
+    pub memory_delta: i64,
+    pub total_memory_before: u64,
+    pub total_memory_after: u64,
+    /// Change of the memory of the process and all its descendants.
+    pub total_memory_delta: i64,
+}
+
+/// Differences between two captures, in bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryDiff {
+    /// Milliseconds since the Unix epoch, 0 when diffing two [`Memory`]
+    /// snapshots.
+    pub before_timestamp: u64,
+    pub after_timestamp: u64,
+    pub total_memory_delta: i64,
+    pub used_memory_delta: i64,
+    pub total_swap_delta: i64,
+    pub used_swap_delta: i64,
+    /// Started, exited and changed processes, largest absolute change
+    /// first. Processes whose memory did not change are left out.
+    pub processes: Vec<ProcessDiff>,
+}
+
+fn delta(before: u64, after: u64) -> i64 {
+    after as i64 - before as i64
+}
+
+impl MemoryDiff {
+    pub fn between(before: &Memory, after: &Memory) -> Self {
+        Self::between_samples(
+            &Sample::from_memory(before, 0),
+            &Sample::from_memory(after, 0),
+            |pid| {
+                after
+                    .processes
+                    .get(&pid)
+                    .or_else(|| before.processes.get(&pid))
+                    .map(|process| process.name.clone())
+            },
+        )
+    }
+
+    /// Diffs the samples of `history` nearest to `before` and `after`.
+    pub fn between_history(history: &History, before: u64, after: u64) -> Option<Self> {
+        let before = history.nearest(before)?;
+        let after = history.nearest(after)?;
+        Some(Self::between_samples(before, after, |pid| {
+            history.name(pid).map(str::to_string)
+        }))
+    }
+
+    pub fn between_samples(
+        before: &Sample,
+        after: &Sample,
+        name: impl Fn(u32) -> Option<String>,
+    ) -> Self {
+        // Both process lists are sorted by PID. A process missing from a
+        // truncated sample may just have been too small to be kept.
+        let processes = before
+            .processes
+            .iter()
+            .merge_join_by(&after.processes, |a, b| a.pid.cmp(&b.pid))
+            .filter_map(|pair| {
+                let (pid, change, before, after) = match pair {
+                    EitherOrBoth::Left(_) if after.truncated => return None,
+                    EitherOrBoth::Right(_) if before.truncated => return None,
+                    EitherOrBoth::Left(b) => (b.pid, ProcessChange::Exited, Some(b), None),
+                    EitherOrBoth::Right(a) => (a.pid, ProcessChange::Started, None, Some(a)),
+                    EitherOrBoth::Both(b, a) => (b.pid, ProcessChange::Changed, Some(b), Some(a)),
+                };
+                let memory_before = before.map_or(0, |p| p.memory);
+                let memory_after = after.map_or(0, |p| p.memory);
+                let total_memory_before = before.map_or(0, |p| p.total_memory);
+                let total_memory_after = after.map_or(0, |p| p.total_memory);
+                if change == ProcessChange::Changed
+                    && memory_before == memory_after
+                    && total_memory_before == total_memory_after
+                {
+                    return None;
+                }
+
+                Some(ProcessDiff {
+                    pid,
+                    name: name(pid).unwrap_or_default(),
+                    change,
+                    memory_before,
+                    memory_after,
+                    memory_delta: delta(memory_before, memory_after),
+                    total_memory_before,
+                    total_memory_after,
+                    total_memory_delta: delta(total_memory_before, total_memory_after),
+                })
+            })
+            .sorted_by(|a, b| {
+                b.memory_delta
+                    .unsigned_abs()
+                    .cmp(&a.memory_delta.unsigned_abs())
+                    .then_with(|| {
+                        b.total_memory_delta
+                            .unsigned_abs()
+                            .cmp(&a.total_memory_delta.unsigned_abs())
+                    })
+                    .then(a.pid.cmp(&b.pid))
+            })
+            .collect_vec();
+
+        Self {
+            before_timestamp: before.timestamp,
+            after_timestamp: after.timestamp,
+            total_memory_delta: delta(before.total_memory, after.total_memory),
+            used_memory_delta: delta(before.used_memory, after.used_memory),
+            total_swap_delta: delta(before.total_swap, after.total_swap),
+            used_swap_delta: delta(before.used_swap, after.used_swap),
+            processes,
+        }
+    }
+
+    pub fn started(&self) -> impl Iterator<Item = &ProcessDiff> {
+        self.with_change(ProcessChange::Started)
+    }
+
+    pub fn exited(&self) -> impl Iterator<Item = &ProcessDiff> {
+        self.with_change(ProcessChange::Exited)
+    }
+
+    fn with_change(&self, change: ProcessChange) -> impl Iterator<Item = &ProcessDiff> {
+        self.processes.iter().filter(move |p| p.change == change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::ProcessSample;
+
+    fn sample(timestamp: u64, used_memory: u64, processes: &[(u32, u64, u64)]) -> Sample {
+        Sample {
+            timestamp,
+            total_memory: 1000,
+            used_memory,
+            total_swap: 0,
+            used_swap: 0,
+            processes: processes
+                .iter()
+                .map(|&(pid, memory, total_memory)| ProcessSample {
+                    pid,
+                    start_time: 0,
+                    memory,
+                    total_memory,
+                })
+                .collect_vec(),
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn test_between_samples() {
+        let before = sample(1, 500, &[(1, 100, 300), (2, 50, 50), (3, 20, 20)]);
+        let after = sample(2, 450, &[(1, 100, 330), (3, 10, 10), (4, 40, 40)]);
+
+        let diff = MemoryDiff::between_samples(&before, &after, |pid| Some(format!("p{pid}")));
+        assert_eq!(diff.before_timestamp, 1);
+        assert_eq!(diff.after_timestamp, 2);
+        assert_eq!(diff.used_memory_delta, -50);
+        assert_eq!(diff.total_memory_delta, 0);
+
+        let order = diff
+            .processes
+            .iter()
+            .map(|p| (p.pid, p.change, p.memory_delta))
+            .collect_vec();
+        assert_eq!(
+            order,
+            vec![
+                (2, ProcessChange::Exited, -50),
+                (4, ProcessChange::Started, 40),
+                (3, ProcessChange::Changed, -10),
+                // Own memory unchanged, but a child grew.
+                (1, ProcessChange::Changed, 0),
+            ]
+        );
+        assert_eq!(diff.processes[3].total_memory_delta, 30);
+        assert_eq!(diff.processes[0].name, "p2");
+        assert_eq!(diff.started().map(|p| p.pid).collect_vec(), vec![4]);
+        assert_eq!(diff.exited().map(|p| p.pid).collect_vec(), vec![2]);
+    }
+
+    #[test]
+    fn test_unchanged_processes_are_skipped() {
+        let before = sample(1, 500, &[(1, 100, 100)]);
+        let diff = MemoryDiff::between_samples(&before, &before, |_| None);
+        assert!(diff.processes.is_empty());
+        assert_eq!(diff.used_memory_delta, 0);
+    }
+
+    #[test]
+    fn test_truncated_samples() {
+        let before = sample(1, 500, &[(1, 100, 100), (2, 50, 50)]);
+        let mut after = sample(2, 500, &[(1, 120, 120), (3, 60, 60)]);
+        after.truncated = true;
+        let diff = MemoryDiff::between_samples(&before, &after, |_| None);
+        assert_eq!(diff.exited().count(), 0);
+        assert_eq!(diff.started().map(|p| p.pid).collect_vec(), vec![3]);
+    }
+
+    #[test]
+    fn test_between_history() {
+        let mut history = History::new(10);
+        history.set_name(2, "worker");
+        history.record(sample(1000, 500, &[(1, 100, 100)]));
+        history.record(sample(2000, 600, &[(1, 100, 200), (2, 100, 100)]));
+
+        let diff = MemoryDiff::between_history(&history, 900, 2100).unwrap();
+        assert_eq!(diff.before_timestamp, 1000);
+        assert_eq!(diff.after_timestamp, 2000);
+        assert_eq!(diff.used_memory_delta, 100);
+        assert_eq!(diff.started().next().unwrap().name, "worker");
+
+        assert!(MemoryDiff::between_history(&History::new(10), 0, 1).is_none());
+    }
+}
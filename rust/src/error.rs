@@ -0,0 +1,161 @@
+use crate::utils::ParseStorageError;
+use std::{fmt, io};
+
+/// Error returned by the public API, structured so that Dart can match on
+/// the cause instead of parsing messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemoryError {
+    /// The OS refused access, typically to another user's process.
+    PermissionDenied {
+        message: String,
+    },
+    /// The process exited while it was being inspected.
+    ProcessGone {
+        pid: u32,
+    },
+    NotFound {
+        message: String,
+    },
+    /// The process hierarchy could not be built.
+    TreeInconsistent {
+        message: String,
+    },
+    /// Not available on this platform.
+    Unsupported {
+        message: String,
+    },
+    InvalidInput {
+        message: String,
+    },
+    Io {
+        message: String,
+    },
+}
+
+impl MemoryError {
+    /// Classifies `err` by its kind, prefixing the message with `context`.
+    pub fn io(err: &io::Error, context: impl fmt::Display) -> Self {
+        Self::from_kind(err.kind(), format!("{context}: {err}"))
+    }
+
+    /// Like [`MemoryError::io`] for files under `/proc/<pid>`, which
+    /// disappear when the process exits.
+    pub fn process_io(pid: u32, err: &io::Error, context: impl fmt::Display) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => Self::ProcessGone { pid },
+            _ => Self::io(err, context),
+        }
+    }
+
+    fn from_kind(kind: io::ErrorKind, message: String) -> Self {
+        match kind {
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied { message },
+            io::ErrorKind::NotFound => Self::NotFound { message },
+            io::ErrorKind::Unsupported => Self::Unsupported { message },
+            io::ErrorKind::InvalidInput => Self::InvalidInput { message },
+            _ => Self::Io { message },
+        }
+    }
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProcessGone { pid } => write!(f, "process {pid} no longer exists"),
+            Self::PermissionDenied { message }
+            | Self::NotFound { message }
+            | Self::TreeInconsistent { message }
+            | Self::Unsupported { message }
+            | Self::InvalidInput { message }
+            | Self::Io { message } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+impl From<io::Error> for MemoryError {
+    fn from(err: io::Error) -> Self {
+        Self::io(&err, "I/O error")
+    }
+}
+
+impl From<regex::Error> for MemoryError {
+    fn from(err: regex::Error) -> Self {
+        Self::InvalidInput {
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<ParseStorageError> for MemoryError {
+    fn from(err: ParseStorageError) -> Self {
+        Self::InvalidInput {
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for MemoryError {
+    fn from(err: anyhow::Error) -> Self {
+        let kind = err
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<io::Error>())
+            .map_or(io::ErrorKind::Other, io::Error::kind);
+        Self::from_kind(kind, format!("{err:#}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_io_classification() {
+        let denied = io::Error::from(io::ErrorKind::PermissionDenied);
+        assert!(matches!(
+            MemoryError::io(&denied, "reading"),
+            MemoryError::PermissionDenied { .. }
+        ));
+
+        let missing = io::Error::from(io::ErrorKind::NotFound);
+        assert_eq!(
+            MemoryError::process_io(42, &missing, "reading"),
+            MemoryError::ProcessGone { pid: 42 }
+        );
+        assert!(matches!(
+            MemoryError::io(&missing, "reading"),
+            MemoryError::NotFound { .. }
+        ));
+
+        let other = io::Error::other("disk on fire");
+        assert_eq!(
+            MemoryError::io(&other, "writing"),
+            MemoryError::Io {
+                message: String::from("writing: disk on fire")
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_anyhow() {
+        let err = Err::<(), _>(io::Error::from(io::ErrorKind::PermissionDenied))
+            .context("failed to open store")
+            .unwrap_err();
+        let err = MemoryError::from(err);
+        assert!(matches!(err, MemoryError::PermissionDenied { .. }));
+        assert!(err.to_string().starts_with("failed to open store: "));
+
+        let err = MemoryError::from(anyhow::anyhow!("corrupt"));
+        assert_eq!(err.to_string(), "corrupt");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            MemoryError::ProcessGone { pid: 7 }.to_string(),
+            "process 7 no longer exists"
+        );
+    }
+}
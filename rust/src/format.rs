@@ -0,0 +1,425 @@
+use crate::utils::{self, Storage, StorageRate, StorageUnit, UnitSystem};
+
+/// Largest supported [`StorageFormatOptions::precision`]; `f64` has no more
+/// significant digits to show anyway.
+pub const MAX_PRECISION: u32 = 20;
+
+/// How the unit is written after the number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageStyle {
+    /// `1.50 GiB`
+    #[default]
+    Short,
+    /// `1.50G`, as printed by `ls -h`.
+    Compact,
+    /// `1.50 gibibytes`
+    Long,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageFormatOptions {
+    /// Digits after the decimal separator, at most 20.
+    pub precision: u32,
+    /// Always print in this unit, however small or large the number gets.
+    pub unit: Option<StorageUnit>,
+    /// Print in the best fitting unit of this system. Falls back to
+    /// [`utils::default_unit_system`]. Ignored if `unit` is set.
+    pub system: Option<UnitSystem>,
+    /// Drops trailing zeros after the decimal separator, and the separator
+    /// itself if nothing is left.
+    pub trim_zeros: bool,
+    pub style: StorageStyle,
+    pub decimal_separator: String,
+    /// Groups the integer part by thousands if set.
+    pub thousands_separator: Option<String>,
+}
+
+impl Default for StorageFormatOptions {
+    fn default() -> Self {
+        Self {
+            precision: 2,
+            unit: None,
+            system: None,
+            trim_zeros: false,
+            style: StorageStyle::Short,
+            decimal_separator: String::from("."),
+            thousands_separator: None,
+        }
+    }
+}
+
+impl StorageFormatOptions {
+    /// Default options with the separators of `locale`, a BCP 47 or POSIX
+    /// tag such as `de-CH` or `fr_FR`. Unknown locales use English
+    /// separators.
+    pub fn for_locale(locale: &str) -> Self {
+        let mut parts = locale.split(['-', '_', '.']);
+        let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let region = parts.next().unwrap_or_default().to_ascii_uppercase();
+
+        let (decimal, thousands) = match (language.as_str(), region.as_str()) {
+            ("de" | "fr" | "it" | "rm", "CH" | "LI") => (".", "\u{2019}"),
+            ("fr", _) => (",", "\u{202f}"),
+            (
+                "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+                | "sr" | "vi",
+                _,
+            ) => (",", "."),
+            (
+                "ru" | "uk" | "be" | "pl" | "cs" | "sk" | "hu" | "bg" | "sv" | "fi" | "nb" | "nn"
+                | "no" | "lt" | "lv" | "et",
+                _,
+            ) => (",", "\u{a0}"),
+            _ => (".", ","),
+        };
+
+        Self {
+            decimal_separator: decimal.to_string(),
+            thousands_separator: Some(thousands.to_string()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Builder for formatting [`Storage`] values.
+///
+/// ```
+/// use rust_lib_flutter_demo::{format::StorageFormatter, utils::{Storage, UnitSystem}};
+///
+/// let size = Storage::from_bytes(1536 * 1024 * 1024);
+/// let formatter = StorageFormatter::new().system(UnitSystem::Iec).precision(1);
+/// assert_eq!(formatter.format(&size), "1.5 GiB");
+/// assert_eq!(formatter.compact().format(&size), "1.5G");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StorageFormatter {
+    options: StorageFormatOptions,
+}
+
+impl From<StorageFormatOptions> for StorageFormatter {
+    fn from(options: StorageFormatOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl StorageFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A formatter using the separators of `locale`, see
+    /// [`StorageFormatOptions::for_locale`].
+    pub fn locale(locale: &str) -> Self {
+        StorageFormatOptions::for_locale(locale).into()
+    }
+
+    pub fn options(&self) -> &StorageFormatOptions {
+        &self.options
+    }
+
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.options.precision = precision;
+        self
+    }
+
+    pub fn unit(mut self, unit: StorageUnit) -> Self {
+        self.options.unit = Some(unit);
+        self
+    }
+
+    pub fn system(mut self, system: UnitSystem) -> Self {
+        self.options.system = Some(system);
+        self
+    }
+
+    pub fn trim_zeros(mut self, trim_zeros: bool) -> Self {
+        self.options.trim_zeros = trim_zeros;
+        self
+    }
+
+    pub fn style(mut self, style: StorageStyle) -> Self {
+        self.options.style = style;
+        self
+    }
+
+    pub fn compact(self) -> Self {
+        self.style(StorageStyle::Compact)
+    }
+
+    pub fn long(self) -> Self {
+        self.style(StorageStyle::Long)
+    }
+
+    pub fn decimal_separator(mut self, separator: impl Into<String>) -> Self {
+        self.options.decimal_separator = separator.into();
+        self
+    }
+
+    pub fn thousands_separator(mut self, separator: Option<impl Into<String>>) -> Self {
+        self.options.thousands_separator = separator.map(Into::into);
+        self
+    }
+
+    pub fn format(&self, storage: &Storage) -> String {
+        let options = &self.options;
+        let storage = match (options.unit, options.system) {
+            (Some(unit), _) => storage.to_unit(unit),
+            (None, system) => {
+                let system = system.unwrap_or_else(utils::default_unit_system);
+                self.round_up_unit(storage.to_system(system), system)
+            }
+        };
+
+        let number = self.number(storage.to_float());
+        let unit = storage.unit();
+        match options.style {
+            StorageStyle::Short => format!("{number} {unit}"),
+            StorageStyle::Compact => format!("{number}{}", compact_symbol(unit)),
+            StorageStyle::Long => {
+                let plural = if number == "1" { "" } else { "s" };
+                format!("{number} {}{plural}", long_name(unit))
+            }
+        }
+    }
+
+    /// Formats `rate` like its magnitude, followed by `/s`, or ` per second`
+    /// in the long style.
+    pub fn format_rate(&self, rate: &StorageRate) -> String {
+        let per_second = match self.options.style {
+            StorageStyle::Long => " per second",
+            StorageStyle::Short | StorageStyle::Compact => "/s",
+        };
+        format!(
+            "{}{}{per_second}",
+            rate.sign(),
+            self.format(&rate.magnitude())
+        )
+    }
+
+    fn clamped_precision(&self) -> u32 {
+        self.options.precision.min(MAX_PRECISION)
+    }
+
+    /// Moves `storage` to the next larger unit if rounding would print a
+    /// full unit of that, such as `1024.00 KiB`.
+    fn round_up_unit(&self, storage: Storage, system: UnitSystem) -> Storage {
+        let units = system.units();
+        let Some(next) = units
+            .iter()
+            .position(|unit| *unit == storage.unit())
+            .and_then(|index| units.get(index + 1))
+        else {
+            return storage;
+        };
+
+        let scale = 10f64.powi(self.clamped_precision() as i32);
+        let base = (next.bytes() / storage.unit().bytes()) as f64;
+        if (storage.to_float() * scale).round() >= base * scale {
+            storage.to_unit(*next)
+        } else {
+            storage
+        }
+    }
+
+    fn number(&self, value: f64) -> String {
+        let options = &self.options;
+        let text = format!("{value:.*}", self.clamped_precision() as usize);
+        let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+        let fraction = if options.trim_zeros {
+            fraction.trim_end_matches('0')
+        } else {
+            fraction
+        };
+
+        let mut number = match &options.thousands_separator {
+            Some(separator) => group_thousands(whole, separator),
+            None => whole.to_string(),
+        };
+        if !fraction.is_empty() {
+            number.push_str(&options.decimal_separator);
+            number.push_str(fraction);
+        }
+        number
+    }
+}
+
+fn group_thousands(digits: &str, separator: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * separator.len());
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+fn compact_symbol(unit: StorageUnit) -> &'static str {
+    match unit {
+        StorageUnit::B => "B",
+        StorageUnit::KiB => "K",
+        StorageUnit::KB => "k",
+        StorageUnit::MiB | StorageUnit::MB => "M",
+        StorageUnit::GiB | StorageUnit::GB => "G",
+        StorageUnit::TiB | StorageUnit::TB => "T",
+        StorageUnit::PiB | StorageUnit::PB => "P",
+    }
+}
+
+fn long_name(unit: StorageUnit) -> &'static str {
+    match unit {
+        StorageUnit::B => "byte",
+        StorageUnit::KiB => "kibibyte",
+        StorageUnit::MiB => "mebibyte",
+        StorageUnit::GiB => "gibibyte",
+        StorageUnit::TiB => "tebibyte",
+        StorageUnit::PiB => "pebibyte",
+        StorageUnit::KB => "kilobyte",
+        StorageUnit::MB => "megabyte",
+        StorageUnit::GB => "gigabyte",
+        StorageUnit::TB => "terabyte",
+        StorageUnit::PB => "petabyte",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    fn iec() -> StorageFormatter {
+        StorageFormatter::new().system(UnitSystem::Iec)
+    }
+
+    #[test]
+    fn test_default_matches_display() {
+        let storage = Storage::from_bytes(64 * 1024 * 1024 + 12345);
+        assert_eq!(iec().format(&storage), storage.to_string());
+    }
+
+    #[test]
+    fn test_precision_and_trimming() {
+        let storage = Storage::from_bytes(GIB + GIB / 2);
+        assert_eq!(iec().precision(0).format(&storage), "2 GiB");
+        assert_eq!(iec().precision(3).format(&storage), "1.500 GiB");
+        assert_eq!(
+            iec().precision(3).trim_zeros(true).format(&storage),
+            "1.5 GiB"
+        );
+        assert_eq!(
+            iec().trim_zeros(true).format(&Storage::from_bytes(GIB)),
+            "1 GiB"
+        );
+    }
+
+    #[test]
+    fn test_rounding_moves_to_next_unit() {
+        let almost = Storage::from_bytes(1024 * 1024 - 1);
+        assert_eq!(iec().format(&almost), "1.00 MiB");
+        assert_eq!(iec().precision(4).format(&almost), "1023.9990 KiB");
+        assert_eq!(
+            StorageFormatter::new()
+                .system(UnitSystem::Si)
+                .precision(0)
+                .format(&Storage::from_bytes(999_600)),
+            "1 MB"
+        );
+        assert_eq!(iec().unit(StorageUnit::KiB).format(&almost), "1024.00 KiB");
+    }
+
+    #[test]
+    fn test_precision_is_clamped() {
+        let formatter = iec().precision(u32::MAX).trim_zeros(true);
+        assert_eq!(formatter.format(&Storage::from_bytes(1536)), "1.5 KiB");
+        let text = iec().precision(u32::MAX).format(&Storage::from_bytes(1536));
+        assert_eq!(
+            text.len(),
+            "1.".len() + MAX_PRECISION as usize + " KiB".len()
+        );
+    }
+
+    #[test]
+    fn test_forced_unit_and_grouping() {
+        let storage = Storage::from_bytes(3 * GIB);
+        let formatter = iec().unit(StorageUnit::KiB).precision(0);
+        assert_eq!(formatter.format(&storage), "3145728 KiB");
+        assert_eq!(
+            formatter.thousands_separator(Some(",")).format(&storage),
+            "3,145,728 KiB"
+        );
+        assert_eq!(
+            StorageFormatter::new()
+                .unit(StorageUnit::MB)
+                .format(&Storage::from_bytes(1_234_567)),
+            "1.23 MB"
+        );
+    }
+
+    #[test]
+    fn test_styles() {
+        let storage = Storage::from_bytes(GIB + GIB / 2);
+        let formatter = iec().precision(1);
+        assert_eq!(formatter.clone().compact().format(&storage), "1.5G");
+        assert_eq!(formatter.clone().long().format(&storage), "1.5 gibibytes");
+        assert_eq!(
+            formatter
+                .clone()
+                .system(UnitSystem::Si)
+                .long()
+                .format(&storage),
+            "1.6 gigabytes"
+        );
+        assert_eq!(
+            formatter
+                .clone()
+                .system(UnitSystem::Si)
+                .compact()
+                .format(&Storage::from_bytes(1500)),
+            "1.5k"
+        );
+        assert_eq!(
+            formatter
+                .precision(0)
+                .long()
+                .format(&Storage::from_bytes(1)),
+            "1 byte"
+        );
+    }
+
+    #[test]
+    fn test_locale() {
+        let storage = Storage::from_bytes(1536);
+        let formatter = StorageFormatter::locale("de-DE").system(UnitSystem::Iec);
+        assert_eq!(formatter.format(&storage), "1,50 KiB");
+        assert_eq!(
+            formatter
+                .unit(StorageUnit::B)
+                .format(&Storage::from_bytes(1_234_567)),
+            "1.234.567,00 B"
+        );
+
+        let options = StorageFormatOptions::for_locale("de_CH.UTF-8");
+        assert_eq!(options.decimal_separator, ".");
+        assert_eq!(options.thousands_separator.as_deref(), Some("\u{2019}"));
+        assert_eq!(
+            StorageFormatOptions::for_locale("fr").decimal_separator,
+            ","
+        );
+        assert_eq!(
+            StorageFormatOptions::for_locale("xx"),
+            StorageFormatOptions::for_locale("en-US")
+        );
+    }
+
+    #[test]
+    fn test_rate() {
+        let rate = StorageRate::new(-1.5 * GIB as f64);
+        assert_eq!(iec().precision(1).format_rate(&rate), "-1.5 GiB/s");
+        assert_eq!(iec().precision(1).compact().format_rate(&rate), "-1.5G/s");
+        assert_eq!(
+            iec().precision(1).long().format_rate(&rate),
+            "-1.5 gibibytes per second"
+        );
+    }
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -646841934;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -719145359;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__memory__get_first_process_memory_usage_impl(
+fn wire__crate__api__memory__add_alert_rule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__memory__get_first_process_memory_usage_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_first_process_memory_usage",
+            debug_name: "add_alert_rule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,20 +67,21 @@ fn wire__crate__api__memory__get_first_process_memory_usage_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_n = <usize>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_condition = <crate::alerts::AlertCondition>::sse_decode(&mut deserializer);
+            let api_for_ms = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::memory::get_first_process_memory_usage(api_n)?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok =
+                        crate::api::memory::add_alert_rule(api_name, api_condition, api_for_ms)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__memory__get_memory_info_impl(
+fn wire__crate__api__memory__alert_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -88,7 +89,7 @@ fn wire__crate__api__memory__get_memory_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_memory_info",
+            debug_name: "alert_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -102,19 +103,53 @@ fn wire__crate__api__memory__get_memory_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::alerts::AlertEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::memory::get_memory_info()?;
-                        Ok(output_ok)
-                    })(),
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::alert_stream(api_sink)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__clear_memory_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_memory_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::clear_memory_history()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__memory__init_app_impl(
+fn wire__crate__api__memory__close_history_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -122,7 +157,7 @@ fn wire__crate__api__memory__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "close_history_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -138,24 +173,54 @@ fn wire__crate__api__memory__init_app_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::memory::init_app()?;
-                        Ok(output_ok)
-                    })(),
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::close_history_store()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__compact_history_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "compact_history_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::compact_history_store()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__memory__storage_to_float_impl(
+fn wire__crate__api__memory__convert_storage_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "storage_to_float",
+            debug_name: "convert_storage",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -170,24 +235,23 @@ fn wire__crate__api__memory__storage_to_float_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_storage = <crate::utils::Storage>::sse_decode(&mut deserializer);
+            let api_unit = <crate::utils::StorageUnit>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::memory::storage_to_float(&api_storage)?;
-                    Ok(output_ok)
-                })(),
-            )
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::convert_storage(&api_storage, api_unit)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__memory__unit_to_string_impl(
+fn wire__crate__api__memory__default_history_store_options_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "unit_to_string",
+            debug_name: "default_history_store_options",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -201,469 +265,4621 @@ fn wire__crate__api__memory__unit_to_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_unit = <crate::utils::Unit>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::memory::unit_to_string(api_unit)?;
-                    Ok(output_ok)
-                })(),
-            )
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::default_history_store_options()?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
+fn wire__crate__api__memory__default_leak_detector_options_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_leak_detector_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::default_leak_detector_options()?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for std::collections::HashMap<u32, crate::memory::ProcessMemoryInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(u32, crate::memory::ProcessMemoryInfo)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
+fn wire__crate__api__memory__default_storage_format_options_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_storage_format_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::default_storage_format_options()?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__memory__detect_memory_leaks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_memory_leaks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_options = <crate::leak::LeakDetectorOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::detect_memory_leaks(api_options)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__memory__diff_memory_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "diff_memory_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_before_ms = <u64>::sse_decode(&mut deserializer);
+            let api_after_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok =
+                        crate::api::memory::diff_memory_history(api_before_ms, api_after_ms)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for f64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__memory__diff_memory_snapshots_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "diff_memory_snapshots",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_before = <crate::memory::Memory>::sse_decode(&mut deserializer);
+            let api_after = <crate::memory::Memory>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok =
+                        crate::api::memory::diff_memory_snapshots(api_before, api_after)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__memory__format_storage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "format_storage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_storage = <crate::utils::Storage>::sse_decode(&mut deserializer);
+            let api_options = <crate::format::StorageFormatOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::format_storage(&api_storage, api_options)?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
+fn wire__crate__api__memory__get_cgroup_tree_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cgroup_tree",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::get_cgroup_tree()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__get_first_process_memory_usage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_first_process_memory_usage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_n = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::get_first_process_memory_usage(api_n)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__get_memory_breakdown_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_memory_breakdown",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::get_memory_breakdown()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__get_memory_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_memory_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::get_memory_info()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__get_memory_metric_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_memory_metric",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::get_memory_metric()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__get_process_groups_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_process_groups",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_by = <crate::group::GroupBy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::get_process_groups(api_by)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__get_process_memory_maps_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_process_memory_maps",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::get_process_memory_maps(api_pid)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__get_process_tree_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_process_tree",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::get_process_tree()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__get_unit_system_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_unit_system",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::get_unit_system()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::init_app()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__kill_process_tree_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "kill_process_tree",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_grace_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::kill_process_tree(api_pid, api_grace_ms)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__list_alert_rules_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_alert_rules",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::list_alert_rules()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__memory_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "memory_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::memory::Memory,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_interval_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::memory_stream(api_sink, api_interval_ms)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__open_history_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_history_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::store::HistoryStoreOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::open_history_store(api_path, api_options)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__parse_storage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_storage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::parse_storage(api_input)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__pause_memory_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_memory_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::pause_memory_stream()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__query_memory_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_memory_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_series = <crate::history::HistorySeries>::sse_decode(&mut deserializer);
+            let api_start_ms = <u64>::sse_decode(&mut deserializer);
+            let api_end_ms = <u64>::sse_decode(&mut deserializer);
+            let api_resolution_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::query_memory_history(
+                        api_series,
+                        api_start_ms,
+                        api_end_ms,
+                        api_resolution_ms,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__query_processes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_processes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::query::ProcessQuery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::query_processes(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__query_stored_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_stored_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_series = <crate::history::HistorySeries>::sse_decode(&mut deserializer);
+            let api_start_ms = <u64>::sse_decode(&mut deserializer);
+            let api_end_ms = <u64>::sse_decode(&mut deserializer);
+            let api_resolution_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::query_stored_history(
+                        api_series,
+                        api_start_ms,
+                        api_end_ms,
+                        api_resolution_ms,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__remove_alert_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_alert_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::remove_alert_rule(api_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__resume_memory_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_memory_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::resume_memory_stream()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__send_signal_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_signal",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_start_time = <u64>::sse_decode(&mut deserializer);
+            let api_signal = <crate::control::Signal>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok =
+                        crate::api::memory::send_signal(api_pid, api_start_time, api_signal)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__set_memory_history_capacity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_memory_history_capacity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_capacity = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::set_memory_history_capacity(api_capacity)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__set_memory_metric_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_memory_metric",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_metric = <crate::memory::MemoryMetric>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::set_memory_metric(api_metric)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__set_memory_stream_interval_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_memory_stream_interval",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_interval_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::set_memory_stream_interval(api_interval_ms)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__set_unit_system_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_unit_system",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_system = <crate::utils::UnitSystem>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::set_unit_system(api_system)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__start_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::start_recording(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__start_replay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_replay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_speed = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::start_replay(api_path, api_speed)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__stop_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::stop_recording()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__stop_replay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_replay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::stop_replay()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__memory__storage_format_options_for_locale_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "storage_format_options_for_locale",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_locale = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::storage_format_options_for_locale(api_locale)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__storage_to_float_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "storage_to_float",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_storage = <crate::utils::Storage>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::storage_to_float(&api_storage)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__storage_to_string_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "storage_to_string",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_storage = <crate::utils::Storage>::sse_decode(&mut deserializer);
+            let api_system = <Option<crate::utils::UnitSystem>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::storage_to_string(&api_storage, api_system)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__unit_to_string_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unit_to_string",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_unit = <crate::utils::StorageUnit>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::MemoryError>((move || {
+                let output_ok = crate::api::memory::unit_to_string(api_unit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__memory__update_alert_rule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_alert_rule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rule = <crate::alerts::AlertRule>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::MemoryError>((move || {
+                    let output_ok = crate::api::memory::update_alert_rule(api_rule)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for std::collections::HashMap<u32, crate::memory::ProcessMemoryInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(u32, crate::memory::ProcessMemoryInfo)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::alerts::AlertEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for StreamSink<crate::memory::Memory, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::alerts::AlertCondition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_percent = <f64>::sse_decode(deserializer);
+                return crate::alerts::AlertCondition::UsedMemoryPercentAbove {
+                    percent: var_percent,
+                };
+            }
+            1 => {
+                let mut var_bytes = <u64>::sse_decode(deserializer);
+                return crate::alerts::AlertCondition::UsedSwapAbove { bytes: var_bytes };
+            }
+            2 => {
+                let mut var_name = <String>::sse_decode(deserializer);
+                let mut var_bytes = <u64>::sse_decode(deserializer);
+                return crate::alerts::AlertCondition::ProcessTotalMemoryAbove {
+                    name: var_name,
+                    bytes: var_bytes,
+                };
+            }
+            3 => {
+                let mut var_bytesPerMinute = <u64>::sse_decode(deserializer);
+                return crate::alerts::AlertCondition::ProcessGrowthAbove {
+                    bytes_per_minute: var_bytesPerMinute,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::alerts::AlertEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ruleId = <u32>::sse_decode(deserializer);
+        let mut var_ruleName = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::alerts::AlertEventKind>::sse_decode(deserializer);
+        let mut var_pid = <Option<u32>>::sse_decode(deserializer);
+        let mut var_processName = <Option<String>>::sse_decode(deserializer);
+        let mut var_value = <f64>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <u64>::sse_decode(deserializer);
+        return crate::alerts::AlertEvent {
+            rule_id: var_ruleId,
+            rule_name: var_ruleName,
+            kind: var_kind,
+            pid: var_pid,
+            process_name: var_processName,
+            value: var_value,
+            message: var_message,
+            timestamp: var_timestamp,
+        };
+    }
+}
+
+impl SseDecode for crate::alerts::AlertEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::alerts::AlertEventKind::Fired,
+            1 => crate::alerts::AlertEventKind::Resolved,
+            _ => unreachable!("Invalid variant for AlertEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::alerts::AlertRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_condition = <crate::alerts::AlertCondition>::sse_decode(deserializer);
+        let mut var_forMs = <u64>::sse_decode(deserializer);
+        return crate::alerts::AlertRule {
+            id: var_id,
+            name: var_name,
+            condition: var_condition,
+            for_ms: var_forMs,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::cgroup::CgroupEvents {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_low = <u64>::sse_decode(deserializer);
+        let mut var_high = <u64>::sse_decode(deserializer);
+        let mut var_max = <u64>::sse_decode(deserializer);
+        let mut var_oom = <u64>::sse_decode(deserializer);
+        let mut var_oomKill = <u64>::sse_decode(deserializer);
+        return crate::cgroup::CgroupEvents {
+            low: var_low,
+            high: var_high,
+            max: var_max,
+            oom: var_oom,
+            oom_kill: var_oomKill,
+        };
+    }
+}
+
+impl SseDecode for crate::cgroup::CgroupMemory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_current = <Option<crate::utils::Storage>>::sse_decode(deserializer);
+        let mut var_max = <Option<crate::utils::Storage>>::sse_decode(deserializer);
+        let mut var_high = <Option<crate::utils::Storage>>::sse_decode(deserializer);
+        let mut var_stat = <Vec<(String, u64)>>::sse_decode(deserializer);
+        let mut var_events = <crate::cgroup::CgroupEvents>::sse_decode(deserializer);
+        return crate::cgroup::CgroupMemory {
+            current: var_current,
+            max: var_max,
+            high: var_high,
+            stat: var_stat,
+            events: var_events,
+        };
+    }
+}
+
+impl SseDecode for crate::cgroup::CgroupNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_containerId = <Option<String>>::sse_decode(deserializer);
+        let mut var_memory = <crate::cgroup::CgroupMemory>::sse_decode(deserializer);
+        let mut var_processes = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_children = <Vec<crate::cgroup::CgroupNode>>::sse_decode(deserializer);
+        return crate::cgroup::CgroupNode {
+            path: var_path,
+            container_id: var_containerId,
+            memory: var_memory,
+            processes: var_processes,
+            children: var_children,
+        };
+    }
+}
+
+impl SseDecode for crate::store::CompactionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kept = <u64>::sse_decode(deserializer);
+        let mut var_removed = <u64>::sse_decode(deserializer);
+        return crate::store::CompactionStats {
+            kept: var_kept,
+            removed: var_removed,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::group::GroupBy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::group::GroupBy::Name;
+            }
+            1 => {
+                return crate::group::GroupBy::Exe;
+            }
+            2 => {
+                return crate::group::GroupBy::User;
+            }
+            3 => {
+                let mut var_pattern = <String>::sse_decode(deserializer);
+                return crate::group::GroupBy::Pattern {
+                    pattern: var_pattern,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::history::HistoryBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u64>::sse_decode(deserializer);
+        let mut var_end = <u64>::sse_decode(deserializer);
+        let mut var_min = <u64>::sse_decode(deserializer);
+        let mut var_max = <u64>::sse_decode(deserializer);
+        let mut var_avg = <u64>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        return crate::history::HistoryBucket {
+            start: var_start,
+            end: var_end,
+            min: var_min,
+            max: var_max,
+            avg: var_avg,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::history::HistorySeries {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::history::HistorySeries::UsedMemory;
+            }
+            1 => {
+                return crate::history::HistorySeries::UsedSwap;
+            }
+            2 => {
+                let mut var_field0 = <u32>::sse_decode(deserializer);
+                return crate::history::HistorySeries::ProcessMemory(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <u32>::sse_decode(deserializer);
+                return crate::history::HistorySeries::ProcessTotalMemory(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::store::HistoryStoreOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_recordIntervalMs = <u64>::sse_decode(deserializer);
+        let mut var_topProcesses = <u32>::sse_decode(deserializer);
+        let mut var_compactionIntervalMs = <u64>::sse_decode(deserializer);
+        let mut var_retention = <crate::store::RetentionPolicy>::sse_decode(deserializer);
+        return crate::store::HistoryStoreOptions {
+            record_interval_ms: var_recordIntervalMs,
+            top_processes: var_topProcesses,
+            compaction_interval_ms: var_compactionIntervalMs,
+            retention: var_retention,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::leak::LeakDetectorOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_windowMs = <u64>::sse_decode(deserializer);
+        let mut var_minSamples = <u32>::sse_decode(deserializer);
+        let mut var_minSlopeBytesPerMinute = <u64>::sse_decode(deserializer);
+        let mut var_minRSquared = <f64>::sse_decode(deserializer);
+        let mut var_minMonotonicRatio = <f64>::sse_decode(deserializer);
+        return crate::leak::LeakDetectorOptions {
+            window_ms: var_windowMs,
+            min_samples: var_minSamples,
+            min_slope_bytes_per_minute: var_minSlopeBytesPerMinute,
+            min_r_squared: var_minRSquared,
+            min_monotonic_ratio: var_minMonotonicRatio,
+        };
+    }
+}
+
+impl SseDecode for crate::leak::LeakReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_samples = <u32>::sse_decode(deserializer);
+        let mut var_slopeBytesPerMinute = <f64>::sse_decode(deserializer);
+        let mut var_rSquared = <f64>::sse_decode(deserializer);
+        let mut var_monotonicRatio = <f64>::sse_decode(deserializer);
+        let mut var_confidence = <f64>::sse_decode(deserializer);
+        let mut var_current = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_growth = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_timeToOomMs = <Option<u64>>::sse_decode(deserializer);
+        return crate::leak::LeakReport {
+            pid: var_pid,
+            name: var_name,
+            samples: var_samples,
+            slope_bytes_per_minute: var_slopeBytesPerMinute,
+            r_squared: var_rSquared,
+            monotonic_ratio: var_monotonicRatio,
+            confidence: var_confidence,
+            current: var_current,
+            growth: var_growth,
+            time_to_oom_ms: var_timeToOomMs,
+        };
+    }
+}
+
+impl SseDecode for Vec<crate::alerts::AlertRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::alerts::AlertRule>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::cgroup::CgroupNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::cgroup::CgroupNode>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::history::HistoryBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::history::HistoryBucket>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::leak::LeakReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::leak::LeakReport>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::smaps::MappingGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::smaps::MappingGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::smaps::MemoryMapping> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::smaps::MemoryMapping>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::diff::ProcessDiff> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::diff::ProcessDiff>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::group::ProcessGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::group::ProcessGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::memory::ProcessTreeNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::memory::ProcessTreeNode>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, u64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, u64)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(u32, crate::memory::ProcessMemoryInfo)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(u32, crate::memory::ProcessMemoryInfo)>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::control::SignalResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::control::SignalResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::smaps::MappingGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::smaps::MappingKind>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        let mut var_size = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_rss = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_pss = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_dirty = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_swap = <crate::utils::Storage>::sse_decode(deserializer);
+        return crate::smaps::MappingGroup {
+            name: var_name,
+            kind: var_kind,
+            count: var_count,
+            size: var_size,
+            rss: var_rss,
+            pss: var_pss,
+            dirty: var_dirty,
+            swap: var_swap,
+        };
+    }
+}
+
+impl SseDecode for crate::smaps::MappingKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::smaps::MappingKind::Heap,
+            1 => crate::smaps::MappingKind::Stack,
+            2 => crate::smaps::MappingKind::Anonymous,
+            3 => crate::smaps::MappingKind::File,
+            4 => crate::smaps::MappingKind::Special,
+            _ => unreachable!("Invalid variant for MappingKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::memory::Memory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalMemory = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_usedMemory = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_totalSwap = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_usedSwap = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_processes =
+            <std::collections::HashMap<u32, crate::memory::ProcessMemoryInfo>>::sse_decode(
+                deserializer,
+            );
+        return crate::memory::Memory {
+            total_memory: var_totalMemory,
+            used_memory: var_usedMemory,
+            total_swap: var_totalSwap,
+            used_swap: var_usedSwap,
+            processes: var_processes,
+        };
+    }
+}
+
+impl SseDecode for crate::meminfo::MemoryBreakdown {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_total = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_free = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_available = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_buffers = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_cached = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_swapCached = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_shmem = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_slabReclaimable = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_slabUnreclaimable = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_active = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_inactive = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_dirty = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_writeback = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_hugepagesTotal = <u64>::sse_decode(deserializer);
+        let mut var_hugepagesFree = <u64>::sse_decode(deserializer);
+        let mut var_hugepageSize = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_committedAs = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_commitLimit = <crate::utils::Storage>::sse_decode(deserializer);
+        return crate::meminfo::MemoryBreakdown {
+            total: var_total,
+            free: var_free,
+            available: var_available,
+            buffers: var_buffers,
+            cached: var_cached,
+            swap_cached: var_swapCached,
+            shmem: var_shmem,
+            slab_reclaimable: var_slabReclaimable,
+            slab_unreclaimable: var_slabUnreclaimable,
+            active: var_active,
+            inactive: var_inactive,
+            dirty: var_dirty,
+            writeback: var_writeback,
+            hugepages_total: var_hugepagesTotal,
+            hugepages_free: var_hugepagesFree,
+            hugepage_size: var_hugepageSize,
+            committed_as: var_committedAs,
+            commit_limit: var_commitLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::diff::MemoryDiff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_beforeTimestamp = <u64>::sse_decode(deserializer);
+        let mut var_afterTimestamp = <u64>::sse_decode(deserializer);
+        let mut var_totalMemoryDelta = <i64>::sse_decode(deserializer);
+        let mut var_usedMemoryDelta = <i64>::sse_decode(deserializer);
+        let mut var_totalSwapDelta = <i64>::sse_decode(deserializer);
+        let mut var_usedSwapDelta = <i64>::sse_decode(deserializer);
+        let mut var_processes = <Vec<crate::diff::ProcessDiff>>::sse_decode(deserializer);
+        return crate::diff::MemoryDiff {
+            before_timestamp: var_beforeTimestamp,
+            after_timestamp: var_afterTimestamp,
+            total_memory_delta: var_totalMemoryDelta,
+            used_memory_delta: var_usedMemoryDelta,
+            total_swap_delta: var_totalSwapDelta,
+            used_swap_delta: var_usedSwapDelta,
+            processes: var_processes,
+        };
+    }
+}
+
+impl SseDecode for crate::error::MemoryError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::MemoryError::PermissionDenied {
+                    message: var_message,
+                };
+            }
+            1 => {
+                let mut var_pid = <u32>::sse_decode(deserializer);
+                return crate::error::MemoryError::ProcessGone { pid: var_pid };
+            }
+            2 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::MemoryError::NotFound {
+                    message: var_message,
+                };
+            }
+            3 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::MemoryError::TreeInconsistent {
+                    message: var_message,
+                };
+            }
+            4 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::MemoryError::Unsupported {
+                    message: var_message,
+                };
+            }
+            5 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::MemoryError::InvalidInput {
+                    message: var_message,
+                };
+            }
+            6 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::error::MemoryError::Io {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::smaps::MemoryMapping {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u64>::sse_decode(deserializer);
+        let mut var_end = <u64>::sse_decode(deserializer);
+        let mut var_permissions = <String>::sse_decode(deserializer);
+        let mut var_offset = <u64>::sse_decode(deserializer);
+        let mut var_kind = <crate::smaps::MappingKind>::sse_decode(deserializer);
+        let mut var_path = <Option<String>>::sse_decode(deserializer);
+        let mut var_size = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_rss = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_pss = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_dirty = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_swap = <crate::utils::Storage>::sse_decode(deserializer);
+        return crate::smaps::MemoryMapping {
+            start: var_start,
+            end: var_end,
+            permissions: var_permissions,
+            offset: var_offset,
+            kind: var_kind,
+            path: var_path,
+            size: var_size,
+            rss: var_rss,
+            pss: var_pss,
+            dirty: var_dirty,
+            swap: var_swap,
+        };
+    }
+}
+
+impl SseDecode for crate::memory::MemoryMetric {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::memory::MemoryMetric::Rss,
+            1 => crate::memory::MemoryMetric::Pss,
+            2 => crate::memory::MemoryMetric::Uss,
+            _ => unreachable!("Invalid variant for MemoryMetric: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::utils::Storage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::utils::Storage>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::utils::StorageRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::utils::StorageRate>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::utils::StorageUnit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::utils::StorageUnit>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::utils::UnitSystem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::utils::UnitSystem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u32>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::diff::ProcessChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::diff::ProcessChange::Started,
+            1 => crate::diff::ProcessChange::Exited,
+            2 => crate::diff::ProcessChange::Changed,
+            _ => unreachable!("Invalid variant for ProcessChange: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::diff::ProcessDiff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_change = <crate::diff::ProcessChange>::sse_decode(deserializer);
+        let mut var_memoryBefore = <u64>::sse_decode(deserializer);
+        let mut var_memoryAfter = <u64>::sse_decode(deserializer);
+        let mut var_memoryDelta = <i64>::sse_decode(deserializer);
+        let mut var_totalMemoryBefore = <u64>::sse_decode(deserializer);
+        let mut var_totalMemoryAfter = <u64>::sse_decode(deserializer);
+        let mut var_totalMemoryDelta = <i64>::sse_decode(deserializer);
+        return crate::diff::ProcessDiff {
+            pid: var_pid,
+            name: var_name,
+            change: var_change,
+            memory_before: var_memoryBefore,
+            memory_after: var_memoryAfter,
+            memory_delta: var_memoryDelta,
+            total_memory_before: var_totalMemoryBefore,
+            total_memory_after: var_totalMemoryAfter,
+            total_memory_delta: var_totalMemoryDelta,
+        };
+    }
+}
+
+impl SseDecode for crate::query::ProcessFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <Option<String>>::sse_decode(deserializer);
+        let mut var_pattern = <Option<String>>::sse_decode(deserializer);
+        let mut var_minMemory = <Option<u64>>::sse_decode(deserializer);
+        let mut var_rootsOnly = <bool>::sse_decode(deserializer);
+        let mut var_pids = <Option<Vec<u32>>>::sse_decode(deserializer);
+        let mut var_user = <Option<String>>::sse_decode(deserializer);
+        return crate::query::ProcessFilter {
+            text: var_text,
+            pattern: var_pattern,
+            min_memory: var_minMemory,
+            roots_only: var_rootsOnly,
+            pids: var_pids,
+            user: var_user,
+        };
+    }
+}
+
+impl SseDecode for crate::group::ProcessGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <Option<String>>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        let mut var_pids = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_memory = <crate::utils::Storage>::sse_decode(deserializer);
+        return crate::group::ProcessGroup {
+            key: var_key,
+            count: var_count,
+            pids: var_pids,
+            memory: var_memory,
+        };
+    }
+}
+
+impl SseDecode for crate::memory::ProcessMemoryInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_memory = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_rawMemory = <u64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_exe = <Option<String>>::sse_decode(deserializer);
+        let mut var_parent = <Option<u32>>::sse_decode(deserializer);
+        let mut var_root = <Option<String>>::sse_decode(deserializer);
+        let mut var_totalMemory = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_pss = <Option<crate::utils::Storage>>::sse_decode(deserializer);
+        let mut var_uss = <Option<crate::utils::Storage>>::sse_decode(deserializer);
+        let mut var_swap = <Option<crate::utils::Storage>>::sse_decode(deserializer);
+        let mut var_user = <Option<String>>::sse_decode(deserializer);
+        let mut var_startTime = <u64>::sse_decode(deserializer);
+        let mut var_cgroup = <Option<String>>::sse_decode(deserializer);
+        let mut var_growthRate = <Option<crate::utils::StorageRate>>::sse_decode(deserializer);
+        return crate::memory::ProcessMemoryInfo {
+            memory: var_memory,
+            raw_memory: var_rawMemory,
+            name: var_name,
+            exe: var_exe,
+            parent: var_parent,
+            root: var_root,
+            total_memory: var_totalMemory,
+            pss: var_pss,
+            uss: var_uss,
+            swap: var_swap,
+            user: var_user,
+            start_time: var_startTime,
+            cgroup: var_cgroup,
+            growth_rate: var_growthRate,
+        };
+    }
+}
+
+impl SseDecode for crate::smaps::ProcessMemoryMaps {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_mappings = <Vec<crate::smaps::MemoryMapping>>::sse_decode(deserializer);
+        let mut var_groups = <Vec<crate::smaps::MappingGroup>>::sse_decode(deserializer);
+        return crate::smaps::ProcessMemoryMaps {
+            pid: var_pid,
+            mappings: var_mappings,
+            groups: var_groups,
+        };
+    }
+}
+
+impl SseDecode for crate::query::ProcessPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_items =
+            <Vec<(u32, crate::memory::ProcessMemoryInfo)>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::query::ProcessPage {
+            items: var_items,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::query::ProcessQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_filter = <crate::query::ProcessFilter>::sse_decode(deserializer);
+        let mut var_sortKey = <crate::query::ProcessSortKey>::sse_decode(deserializer);
+        let mut var_direction = <crate::query::SortDirection>::sse_decode(deserializer);
+        let mut var_offset = <u32>::sse_decode(deserializer);
+        let mut var_limit = <u32>::sse_decode(deserializer);
+        return crate::query::ProcessQuery {
+            filter: var_filter,
+            sort_key: var_sortKey,
+            direction: var_direction,
+            offset: var_offset,
+            limit: var_limit,
+        };
+    }
+}
+
+impl SseDecode for crate::query::ProcessSortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::query::ProcessSortKey::Memory,
+            1 => crate::query::ProcessSortKey::TotalMemory,
+            2 => crate::query::ProcessSortKey::Name,
+            3 => crate::query::ProcessSortKey::Pid,
+            4 => crate::query::ProcessSortKey::StartTime,
+            _ => unreachable!("Invalid variant for ProcessSortKey: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::memory::ProcessTreeNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_depth = <u32>::sse_decode(deserializer);
+        let mut var_memory = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_totalMemory = <crate::utils::Storage>::sse_decode(deserializer);
+        let mut var_children = <Vec<crate::memory::ProcessTreeNode>>::sse_decode(deserializer);
+        return crate::memory::ProcessTreeNode {
+            pid: var_pid,
+            name: var_name,
+            depth: var_depth,
+            memory: var_memory,
+            total_memory: var_totalMemory,
+            children: var_children,
+        };
+    }
+}
+
+impl SseDecode for (String, u64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <u64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (u32, crate::memory::ProcessMemoryInfo) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <u32>::sse_decode(deserializer);
+        let mut var_field1 = <crate::memory::ProcessMemoryInfo>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::store::RetentionPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxAgeMs = <u64>::sse_decode(deserializer);
+        let mut var_fullResolutionMs = <u64>::sse_decode(deserializer);
+        let mut var_compactedIntervalMs = <u64>::sse_decode(deserializer);
+        return crate::store::RetentionPolicy {
+            max_age_ms: var_maxAgeMs,
+            full_resolution_ms: var_fullResolutionMs,
+            compacted_interval_ms: var_compactedIntervalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::control::Signal {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::control::Signal::Term,
+            1 => crate::control::Signal::Kill,
+            2 => crate::control::Signal::Stop,
+            3 => crate::control::Signal::Cont,
+            _ => unreachable!("Invalid variant for Signal: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::control::SignalResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_signal = <crate::control::Signal>::sse_decode(deserializer);
+        let mut var_status = <crate::control::SignalStatus>::sse_decode(deserializer);
+        return crate::control::SignalResult {
+            pid: var_pid,
+            signal: var_signal,
+            status: var_status,
+        };
+    }
+}
+
+impl SseDecode for crate::control::SignalStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::control::SignalStatus::Sent;
+            }
+            1 => {
+                return crate::control::SignalStatus::PermissionDenied;
+            }
+            2 => {
+                return crate::control::SignalStatus::NotFound;
+            }
+            3 => {
+                return crate::control::SignalStatus::InvalidPid;
+            }
+            4 => {
+                return crate::control::SignalStatus::Unsupported;
+            }
+            5 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::control::SignalStatus::Failed {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::query::SortDirection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::query::SortDirection::Ascending,
+            1 => crate::query::SortDirection::Descending,
+            _ => unreachable!("Invalid variant for SortDirection: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::utils::Storage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_quotient = <u64>::sse_decode(deserializer);
+        let mut var_remainder = <u64>::sse_decode(deserializer);
+        let mut var_unit = <crate::utils::StorageUnit>::sse_decode(deserializer);
+        return crate::utils::Storage {
+            quotient: var_quotient,
+            remainder: var_remainder,
+            unit: var_unit,
+        };
+    }
+}
+
+impl SseDecode for crate::format::StorageFormatOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_precision = <u32>::sse_decode(deserializer);
+        let mut var_unit = <Option<crate::utils::StorageUnit>>::sse_decode(deserializer);
+        let mut var_system = <Option<crate::utils::UnitSystem>>::sse_decode(deserializer);
+        let mut var_trimZeros = <bool>::sse_decode(deserializer);
+        let mut var_style = <crate::format::StorageStyle>::sse_decode(deserializer);
+        let mut var_decimalSeparator = <String>::sse_decode(deserializer);
+        let mut var_thousandsSeparator = <Option<String>>::sse_decode(deserializer);
+        return crate::format::StorageFormatOptions {
+            precision: var_precision,
+            unit: var_unit,
+            system: var_system,
+            trim_zeros: var_trimZeros,
+            style: var_style,
+            decimal_separator: var_decimalSeparator,
+            thousands_separator: var_thousandsSeparator,
+        };
+    }
+}
+
+impl SseDecode for crate::utils::StorageRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bytesPerSecond = <f64>::sse_decode(deserializer);
+        return crate::utils::StorageRate {
+            bytes_per_second: var_bytesPerSecond,
+        };
+    }
+}
+
+impl SseDecode for crate::format::StorageStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::format::StorageStyle::Short,
+            1 => crate::format::StorageStyle::Compact,
+            2 => crate::format::StorageStyle::Long,
+            _ => unreachable!("Invalid variant for StorageStyle: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::utils::StorageUnit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::utils::StorageUnit::B,
+            1 => crate::utils::StorageUnit::KiB,
+            2 => crate::utils::StorageUnit::MiB,
+            3 => crate::utils::StorageUnit::GiB,
+            4 => crate::utils::StorageUnit::TiB,
+            5 => crate::utils::StorageUnit::PiB,
+            6 => crate::utils::StorageUnit::KB,
+            7 => crate::utils::StorageUnit::MB,
+            8 => crate::utils::StorageUnit::GB,
+            9 => crate::utils::StorageUnit::TB,
+            10 => crate::utils::StorageUnit::PB,
+            _ => unreachable!("Invalid variant for StorageUnit: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::utils::UnitSystem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::utils::UnitSystem::Iec,
+            1 => crate::utils::UnitSystem::Si,
+            _ => unreachable!("Invalid variant for UnitSystem: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__memory__add_alert_rule_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__memory__alert_stream_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__memory__clear_memory_history_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__memory__close_history_store_impl(port, ptr, rust_vec_len, data_len),
+        5 => {
+            wire__crate__api__memory__compact_history_store_impl(port, ptr, rust_vec_len, data_len)
+        }
+        10 => wire__crate__api__memory__detect_memory_leaks_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__memory__diff_memory_history_impl(port, ptr, rust_vec_len, data_len),
+        12 => {
+            wire__crate__api__memory__diff_memory_snapshots_impl(port, ptr, rust_vec_len, data_len)
+        }
+        14 => wire__crate__api__memory__get_cgroup_tree_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__memory__get_first_process_memory_usage_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => {
+            wire__crate__api__memory__get_memory_breakdown_impl(port, ptr, rust_vec_len, data_len)
+        }
+        17 => wire__crate__api__memory__get_memory_info_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__memory__get_memory_metric_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__memory__get_process_groups_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__memory__get_process_memory_maps_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__memory__get_process_tree_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__memory__init_app_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__memory__kill_process_tree_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__memory__list_alert_rules_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__memory__memory_stream_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__memory__open_history_store_impl(port, ptr, rust_vec_len, data_len),
+        30 => {
+            wire__crate__api__memory__query_memory_history_impl(port, ptr, rust_vec_len, data_len)
+        }
+        31 => wire__crate__api__memory__query_processes_impl(port, ptr, rust_vec_len, data_len),
+        32 => {
+            wire__crate__api__memory__query_stored_history_impl(port, ptr, rust_vec_len, data_len)
+        }
+        33 => wire__crate__api__memory__remove_alert_rule_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__memory__send_signal_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__memory__set_memory_history_capacity_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__memory__set_memory_metric_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__memory__start_recording_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__memory__start_replay_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__memory__stop_recording_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__memory__stop_replay_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__memory__update_alert_rule_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        6 => wire__crate__api__memory__convert_storage_impl(ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__memory__default_history_store_options_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__memory__default_leak_detector_options_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__memory__default_storage_format_options_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__memory__format_storage_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__memory__get_unit_system_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__memory__parse_storage_impl(ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__memory__pause_memory_stream_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__memory__resume_memory_stream_impl(ptr, rust_vec_len, data_len),
+        38 => {
+            wire__crate__api__memory__set_memory_stream_interval_impl(ptr, rust_vec_len, data_len)
+        }
+        39 => wire__crate__api__memory__set_unit_system_impl(ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__memory__storage_format_options_for_locale_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__memory__storage_to_float_impl(ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__memory__storage_to_string_impl(ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__memory__unit_to_string_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::alerts::AlertCondition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::alerts::AlertCondition::UsedMemoryPercentAbove { percent } => {
+                [0.into_dart(), percent.into_into_dart().into_dart()].into_dart()
+            }
+            crate::alerts::AlertCondition::UsedSwapAbove { bytes } => {
+                [1.into_dart(), bytes.into_into_dart().into_dart()].into_dart()
+            }
+            crate::alerts::AlertCondition::ProcessTotalMemoryAbove { name, bytes } => [
+                2.into_dart(),
+                name.into_into_dart().into_dart(),
+                bytes.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::alerts::AlertCondition::ProcessGrowthAbove { bytes_per_minute } => {
+                [3.into_dart(), bytes_per_minute.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::alerts::AlertCondition {}
+impl flutter_rust_bridge::IntoIntoDart<crate::alerts::AlertCondition>
+    for crate::alerts::AlertCondition
+{
+    fn into_into_dart(self) -> crate::alerts::AlertCondition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::alerts::AlertEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rule_id.into_into_dart().into_dart(),
+            self.rule_name.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.pid.into_into_dart().into_dart(),
+            self.process_name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::alerts::AlertEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::alerts::AlertEvent> for crate::alerts::AlertEvent {
+    fn into_into_dart(self) -> crate::alerts::AlertEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::alerts::AlertEventKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fired => 0.into_dart(),
+            Self::Resolved => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::alerts::AlertEventKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::alerts::AlertEventKind>
+    for crate::alerts::AlertEventKind
+{
+    fn into_into_dart(self) -> crate::alerts::AlertEventKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::alerts::AlertRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.condition.into_into_dart().into_dart(),
+            self.for_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::alerts::AlertRule {}
+impl flutter_rust_bridge::IntoIntoDart<crate::alerts::AlertRule> for crate::alerts::AlertRule {
+    fn into_into_dart(self) -> crate::alerts::AlertRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::cgroup::CgroupEvents {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.low.into_into_dart().into_dart(),
+            self.high.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+            self.oom.into_into_dart().into_dart(),
+            self.oom_kill.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::cgroup::CgroupEvents {}
+impl flutter_rust_bridge::IntoIntoDart<crate::cgroup::CgroupEvents>
+    for crate::cgroup::CgroupEvents
+{
+    fn into_into_dart(self) -> crate::cgroup::CgroupEvents {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::cgroup::CgroupMemory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.current.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+            self.high.into_into_dart().into_dart(),
+            self.stat.into_into_dart().into_dart(),
+            self.events.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::cgroup::CgroupMemory {}
+impl flutter_rust_bridge::IntoIntoDart<crate::cgroup::CgroupMemory>
+    for crate::cgroup::CgroupMemory
+{
+    fn into_into_dart(self) -> crate::cgroup::CgroupMemory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::cgroup::CgroupNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.container_id.into_into_dart().into_dart(),
+            self.memory.into_into_dart().into_dart(),
+            self.processes.into_into_dart().into_dart(),
+            self.children.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::cgroup::CgroupNode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::cgroup::CgroupNode> for crate::cgroup::CgroupNode {
+    fn into_into_dart(self) -> crate::cgroup::CgroupNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::store::CompactionStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kept.into_into_dart().into_dart(),
+            self.removed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::store::CompactionStats {}
+impl flutter_rust_bridge::IntoIntoDart<crate::store::CompactionStats>
+    for crate::store::CompactionStats
+{
+    fn into_into_dart(self) -> crate::store::CompactionStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::group::GroupBy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::group::GroupBy::Name => [0.into_dart()].into_dart(),
+            crate::group::GroupBy::Exe => [1.into_dart()].into_dart(),
+            crate::group::GroupBy::User => [2.into_dart()].into_dart(),
+            crate::group::GroupBy::Pattern { pattern } => {
+                [3.into_dart(), pattern.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::group::GroupBy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::group::GroupBy> for crate::group::GroupBy {
+    fn into_into_dart(self) -> crate::group::GroupBy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::history::HistoryBucket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.min.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+            self.avg.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::history::HistoryBucket {}
+impl flutter_rust_bridge::IntoIntoDart<crate::history::HistoryBucket>
+    for crate::history::HistoryBucket
+{
+    fn into_into_dart(self) -> crate::history::HistoryBucket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::history::HistorySeries {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::history::HistorySeries::UsedMemory => [0.into_dart()].into_dart(),
+            crate::history::HistorySeries::UsedSwap => [1.into_dart()].into_dart(),
+            crate::history::HistorySeries::ProcessMemory(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::history::HistorySeries::ProcessTotalMemory(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::history::HistorySeries {}
+impl flutter_rust_bridge::IntoIntoDart<crate::history::HistorySeries>
+    for crate::history::HistorySeries
+{
+    fn into_into_dart(self) -> crate::history::HistorySeries {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::store::HistoryStoreOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.record_interval_ms.into_into_dart().into_dart(),
+            self.top_processes.into_into_dart().into_dart(),
+            self.compaction_interval_ms.into_into_dart().into_dart(),
+            self.retention.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::store::HistoryStoreOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::store::HistoryStoreOptions>
+    for crate::store::HistoryStoreOptions
+{
+    fn into_into_dart(self) -> crate::store::HistoryStoreOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::leak::LeakDetectorOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.window_ms.into_into_dart().into_dart(),
+            self.min_samples.into_into_dart().into_dart(),
+            self.min_slope_bytes_per_minute.into_into_dart().into_dart(),
+            self.min_r_squared.into_into_dart().into_dart(),
+            self.min_monotonic_ratio.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::leak::LeakDetectorOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::leak::LeakDetectorOptions>
+    for crate::leak::LeakDetectorOptions
+{
+    fn into_into_dart(self) -> crate::leak::LeakDetectorOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::leak::LeakReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.samples.into_into_dart().into_dart(),
+            self.slope_bytes_per_minute.into_into_dart().into_dart(),
+            self.r_squared.into_into_dart().into_dart(),
+            self.monotonic_ratio.into_into_dart().into_dart(),
+            self.confidence.into_into_dart().into_dart(),
+            self.current.into_into_dart().into_dart(),
+            self.growth.into_into_dart().into_dart(),
+            self.time_to_oom_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::leak::LeakReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::leak::LeakReport> for crate::leak::LeakReport {
+    fn into_into_dart(self) -> crate::leak::LeakReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::smaps::MappingGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.rss.into_into_dart().into_dart(),
+            self.pss.into_into_dart().into_dart(),
+            self.dirty.into_into_dart().into_dart(),
+            self.swap.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::smaps::MappingGroup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::smaps::MappingGroup> for crate::smaps::MappingGroup {
+    fn into_into_dart(self) -> crate::smaps::MappingGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::smaps::MappingKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Heap => 0.into_dart(),
+            Self::Stack => 1.into_dart(),
+            Self::Anonymous => 2.into_dart(),
+            Self::File => 3.into_dart(),
+            Self::Special => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::smaps::MappingKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::smaps::MappingKind> for crate::smaps::MappingKind {
+    fn into_into_dart(self) -> crate::smaps::MappingKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::memory::Memory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_memory.into_into_dart().into_dart(),
+            self.used_memory.into_into_dart().into_dart(),
+            self.total_swap.into_into_dart().into_dart(),
+            self.used_swap.into_into_dart().into_dart(),
+            self.processes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::memory::Memory {}
+impl flutter_rust_bridge::IntoIntoDart<crate::memory::Memory> for crate::memory::Memory {
+    fn into_into_dart(self) -> crate::memory::Memory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::meminfo::MemoryBreakdown {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total.into_into_dart().into_dart(),
+            self.free.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+            self.buffers.into_into_dart().into_dart(),
+            self.cached.into_into_dart().into_dart(),
+            self.swap_cached.into_into_dart().into_dart(),
+            self.shmem.into_into_dart().into_dart(),
+            self.slab_reclaimable.into_into_dart().into_dart(),
+            self.slab_unreclaimable.into_into_dart().into_dart(),
+            self.active.into_into_dart().into_dart(),
+            self.inactive.into_into_dart().into_dart(),
+            self.dirty.into_into_dart().into_dart(),
+            self.writeback.into_into_dart().into_dart(),
+            self.hugepages_total.into_into_dart().into_dart(),
+            self.hugepages_free.into_into_dart().into_dart(),
+            self.hugepage_size.into_into_dart().into_dart(),
+            self.committed_as.into_into_dart().into_dart(),
+            self.commit_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::meminfo::MemoryBreakdown
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::meminfo::MemoryBreakdown>
+    for crate::meminfo::MemoryBreakdown
+{
+    fn into_into_dart(self) -> crate::meminfo::MemoryBreakdown {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::diff::MemoryDiff {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.before_timestamp.into_into_dart().into_dart(),
+            self.after_timestamp.into_into_dart().into_dart(),
+            self.total_memory_delta.into_into_dart().into_dart(),
+            self.used_memory_delta.into_into_dart().into_dart(),
+            self.total_swap_delta.into_into_dart().into_dart(),
+            self.used_swap_delta.into_into_dart().into_dart(),
+            self.processes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::diff::MemoryDiff {}
+impl flutter_rust_bridge::IntoIntoDart<crate::diff::MemoryDiff> for crate::diff::MemoryDiff {
+    fn into_into_dart(self) -> crate::diff::MemoryDiff {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::error::MemoryError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::error::MemoryError::PermissionDenied { message } => {
+                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::MemoryError::ProcessGone { pid } => {
+                [1.into_dart(), pid.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::MemoryError::NotFound { message } => {
+                [2.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::MemoryError::TreeInconsistent { message } => {
+                [3.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::MemoryError::Unsupported { message } => {
+                [4.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::MemoryError::InvalidInput { message } => {
+                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::MemoryError::Io { message } => {
+                [6.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::MemoryError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::MemoryError> for crate::error::MemoryError {
+    fn into_into_dart(self) -> crate::error::MemoryError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::smaps::MemoryMapping {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.permissions.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.rss.into_into_dart().into_dart(),
+            self.pss.into_into_dart().into_dart(),
+            self.dirty.into_into_dart().into_dart(),
+            self.swap.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::smaps::MemoryMapping {}
+impl flutter_rust_bridge::IntoIntoDart<crate::smaps::MemoryMapping>
+    for crate::smaps::MemoryMapping
+{
+    fn into_into_dart(self) -> crate::smaps::MemoryMapping {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::memory::MemoryMetric {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Rss => 0.into_dart(),
+            Self::Pss => 1.into_dart(),
+            Self::Uss => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::memory::MemoryMetric {}
+impl flutter_rust_bridge::IntoIntoDart<crate::memory::MemoryMetric>
+    for crate::memory::MemoryMetric
+{
+    fn into_into_dart(self) -> crate::memory::MemoryMetric {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::diff::ProcessChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Started => 0.into_dart(),
+            Self::Exited => 1.into_dart(),
+            Self::Changed => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::diff::ProcessChange {}
+impl flutter_rust_bridge::IntoIntoDart<crate::diff::ProcessChange> for crate::diff::ProcessChange {
+    fn into_into_dart(self) -> crate::diff::ProcessChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::diff::ProcessDiff {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.change.into_into_dart().into_dart(),
+            self.memory_before.into_into_dart().into_dart(),
+            self.memory_after.into_into_dart().into_dart(),
+            self.memory_delta.into_into_dart().into_dart(),
+            self.total_memory_before.into_into_dart().into_dart(),
+            self.total_memory_after.into_into_dart().into_dart(),
+            self.total_memory_delta.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::diff::ProcessDiff {}
+impl flutter_rust_bridge::IntoIntoDart<crate::diff::ProcessDiff> for crate::diff::ProcessDiff {
+    fn into_into_dart(self) -> crate::diff::ProcessDiff {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::query::ProcessFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.pattern.into_into_dart().into_dart(),
+            self.min_memory.into_into_dart().into_dart(),
+            self.roots_only.into_into_dart().into_dart(),
+            self.pids.into_into_dart().into_dart(),
+            self.user.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::query::ProcessFilter {}
+impl flutter_rust_bridge::IntoIntoDart<crate::query::ProcessFilter>
+    for crate::query::ProcessFilter
+{
+    fn into_into_dart(self) -> crate::query::ProcessFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::group::ProcessGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.pids.into_into_dart().into_dart(),
+            self.memory.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::group::ProcessGroup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::group::ProcessGroup> for crate::group::ProcessGroup {
+    fn into_into_dart(self) -> crate::group::ProcessGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::memory::ProcessMemoryInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.memory.into_into_dart().into_dart(),
+            self.raw_memory.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.exe.into_into_dart().into_dart(),
+            self.parent.into_into_dart().into_dart(),
+            self.root.into_into_dart().into_dart(),
+            self.total_memory.into_into_dart().into_dart(),
+            self.pss.into_into_dart().into_dart(),
+            self.uss.into_into_dart().into_dart(),
+            self.swap.into_into_dart().into_dart(),
+            self.user.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.cgroup.into_into_dart().into_dart(),
+            self.growth_rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::memory::ProcessMemoryInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::memory::ProcessMemoryInfo>
+    for crate::memory::ProcessMemoryInfo
+{
+    fn into_into_dart(self) -> crate::memory::ProcessMemoryInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::smaps::ProcessMemoryMaps {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.mappings.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::smaps::ProcessMemoryMaps
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::smaps::ProcessMemoryMaps>
+    for crate::smaps::ProcessMemoryMaps
+{
+    fn into_into_dart(self) -> crate::smaps::ProcessMemoryMaps {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::query::ProcessPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.items.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::query::ProcessPage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::query::ProcessPage> for crate::query::ProcessPage {
+    fn into_into_dart(self) -> crate::query::ProcessPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::query::ProcessQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.filter.into_into_dart().into_dart(),
+            self.sort_key.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::query::ProcessQuery {}
+impl flutter_rust_bridge::IntoIntoDart<crate::query::ProcessQuery> for crate::query::ProcessQuery {
+    fn into_into_dart(self) -> crate::query::ProcessQuery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::query::ProcessSortKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Memory => 0.into_dart(),
+            Self::TotalMemory => 1.into_dart(),
+            Self::Name => 2.into_dart(),
+            Self::Pid => 3.into_dart(),
+            Self::StartTime => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::query::ProcessSortKey {}
+impl flutter_rust_bridge::IntoIntoDart<crate::query::ProcessSortKey>
+    for crate::query::ProcessSortKey
+{
+    fn into_into_dart(self) -> crate::query::ProcessSortKey {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::memory::ProcessTreeNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.depth.into_into_dart().into_dart(),
+            self.memory.into_into_dart().into_dart(),
+            self.total_memory.into_into_dart().into_dart(),
+            self.children.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::memory::ProcessTreeNode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::memory::ProcessTreeNode>
+    for crate::memory::ProcessTreeNode
+{
+    fn into_into_dart(self) -> crate::memory::ProcessTreeNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::store::RetentionPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_age_ms.into_into_dart().into_dart(),
+            self.full_resolution_ms.into_into_dart().into_dart(),
+            self.compacted_interval_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::store::RetentionPolicy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::store::RetentionPolicy>
+    for crate::store::RetentionPolicy
+{
+    fn into_into_dart(self) -> crate::store::RetentionPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::control::Signal {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Term => 0.into_dart(),
+            Self::Kill => 1.into_dart(),
+            Self::Stop => 2.into_dart(),
+            Self::Cont => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::control::Signal {}
+impl flutter_rust_bridge::IntoIntoDart<crate::control::Signal> for crate::control::Signal {
+    fn into_into_dart(self) -> crate::control::Signal {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::control::SignalResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.signal.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::control::SignalResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::control::SignalResult>
+    for crate::control::SignalResult
+{
+    fn into_into_dart(self) -> crate::control::SignalResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::control::SignalStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::control::SignalStatus::Sent => [0.into_dart()].into_dart(),
+            crate::control::SignalStatus::PermissionDenied => [1.into_dart()].into_dart(),
+            crate::control::SignalStatus::NotFound => [2.into_dart()].into_dart(),
+            crate::control::SignalStatus::InvalidPid => [3.into_dart()].into_dart(),
+            crate::control::SignalStatus::Unsupported => [4.into_dart()].into_dart(),
+            crate::control::SignalStatus::Failed { message } => {
+                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::control::SignalStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::control::SignalStatus>
+    for crate::control::SignalStatus
+{
+    fn into_into_dart(self) -> crate::control::SignalStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::query::SortDirection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Ascending => 0.into_dart(),
+            Self::Descending => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::query::SortDirection {}
+impl flutter_rust_bridge::IntoIntoDart<crate::query::SortDirection>
+    for crate::query::SortDirection
+{
+    fn into_into_dart(self) -> crate::query::SortDirection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::utils::Storage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.quotient.into_into_dart().into_dart(),
+            self.remainder.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::utils::Storage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::utils::Storage> for crate::utils::Storage {
+    fn into_into_dart(self) -> crate::utils::Storage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::format::StorageFormatOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.precision.into_into_dart().into_dart(),
+            self.unit.into_into_dart().into_dart(),
+            self.system.into_into_dart().into_dart(),
+            self.trim_zeros.into_into_dart().into_dart(),
+            self.style.into_into_dart().into_dart(),
+            self.decimal_separator.into_into_dart().into_dart(),
+            self.thousands_separator.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::format::StorageFormatOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::format::StorageFormatOptions>
+    for crate::format::StorageFormatOptions
+{
+    fn into_into_dart(self) -> crate::format::StorageFormatOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::utils::StorageRate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.bytes_per_second.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::utils::StorageRate {}
+impl flutter_rust_bridge::IntoIntoDart<crate::utils::StorageRate> for crate::utils::StorageRate {
+    fn into_into_dart(self) -> crate::utils::StorageRate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::format::StorageStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Short => 0.into_dart(),
+            Self::Compact => 1.into_dart(),
+            Self::Long => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::format::StorageStyle {}
+impl flutter_rust_bridge::IntoIntoDart<crate::format::StorageStyle>
+    for crate::format::StorageStyle
+{
+    fn into_into_dart(self) -> crate::format::StorageStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::utils::StorageUnit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::B => 0.into_dart(),
+            Self::KiB => 1.into_dart(),
+            Self::MiB => 2.into_dart(),
+            Self::GiB => 3.into_dart(),
+            Self::TiB => 4.into_dart(),
+            Self::PiB => 5.into_dart(),
+            Self::KB => 6.into_dart(),
+            Self::MB => 7.into_dart(),
+            Self::GB => 8.into_dart(),
+            Self::TB => 9.into_dart(),
+            Self::PB => 10.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::utils::StorageUnit {}
+impl flutter_rust_bridge::IntoIntoDart<crate::utils::StorageUnit> for crate::utils::StorageUnit {
+    fn into_into_dart(self) -> crate::utils::StorageUnit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::utils::UnitSystem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Iec => 0.into_dart(),
+            Self::Si => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::utils::UnitSystem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::utils::UnitSystem> for crate::utils::UnitSystem {
+    fn into_into_dart(self) -> crate::utils::UnitSystem {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for std::collections::HashMap<u32, crate::memory::ProcessMemoryInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(u32, crate::memory::ProcessMemoryInfo)>>::sse_encode(
+            self.into_iter().collect(),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::alerts::AlertEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for StreamSink<crate::memory::Memory, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::alerts::AlertCondition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::alerts::AlertCondition::UsedMemoryPercentAbove { percent } => {
+                <i32>::sse_encode(0, serializer);
+                <f64>::sse_encode(percent, serializer);
+            }
+            crate::alerts::AlertCondition::UsedSwapAbove { bytes } => {
+                <i32>::sse_encode(1, serializer);
+                <u64>::sse_encode(bytes, serializer);
+            }
+            crate::alerts::AlertCondition::ProcessTotalMemoryAbove { name, bytes } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(name, serializer);
+                <u64>::sse_encode(bytes, serializer);
+            }
+            crate::alerts::AlertCondition::ProcessGrowthAbove { bytes_per_minute } => {
+                <i32>::sse_encode(3, serializer);
+                <u64>::sse_encode(bytes_per_minute, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::alerts::AlertEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.rule_id, serializer);
+        <String>::sse_encode(self.rule_name, serializer);
+        <crate::alerts::AlertEventKind>::sse_encode(self.kind, serializer);
+        <Option<u32>>::sse_encode(self.pid, serializer);
+        <Option<String>>::sse_encode(self.process_name, serializer);
+        <f64>::sse_encode(self.value, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u64>::sse_encode(self.timestamp, serializer);
+    }
+}
+
+impl SseEncode for crate::alerts::AlertEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::alerts::AlertEventKind::Fired => 0,
+                crate::alerts::AlertEventKind::Resolved => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::alerts::AlertRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <crate::alerts::AlertCondition>::sse_encode(self.condition, serializer);
+        <u64>::sse_encode(self.for_ms, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::cgroup::CgroupEvents {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.low, serializer);
+        <u64>::sse_encode(self.high, serializer);
+        <u64>::sse_encode(self.max, serializer);
+        <u64>::sse_encode(self.oom, serializer);
+        <u64>::sse_encode(self.oom_kill, serializer);
+    }
+}
+
+impl SseEncode for crate::cgroup::CgroupMemory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::utils::Storage>>::sse_encode(self.current, serializer);
+        <Option<crate::utils::Storage>>::sse_encode(self.max, serializer);
+        <Option<crate::utils::Storage>>::sse_encode(self.high, serializer);
+        <Vec<(String, u64)>>::sse_encode(self.stat, serializer);
+        <crate::cgroup::CgroupEvents>::sse_encode(self.events, serializer);
+    }
+}
+
+impl SseEncode for crate::cgroup::CgroupNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <Option<String>>::sse_encode(self.container_id, serializer);
+        <crate::cgroup::CgroupMemory>::sse_encode(self.memory, serializer);
+        <Vec<u32>>::sse_encode(self.processes, serializer);
+        <Vec<crate::cgroup::CgroupNode>>::sse_encode(self.children, serializer);
+    }
+}
+
+impl SseEncode for crate::store::CompactionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.kept, serializer);
+        <u64>::sse_encode(self.removed, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::group::GroupBy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::group::GroupBy::Name => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::group::GroupBy::Exe => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::group::GroupBy::User => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::group::GroupBy::Pattern { pattern } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(pattern, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::history::HistoryBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.start, serializer);
+        <u64>::sse_encode(self.end, serializer);
+        <u64>::sse_encode(self.min, serializer);
+        <u64>::sse_encode(self.max, serializer);
+        <u64>::sse_encode(self.avg, serializer);
+        <u32>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for crate::history::HistorySeries {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::history::HistorySeries::UsedMemory => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::history::HistorySeries::UsedSwap => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::history::HistorySeries::ProcessMemory(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <u32>::sse_encode(field0, serializer);
+            }
+            crate::history::HistorySeries::ProcessTotalMemory(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <u32>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::store::HistoryStoreOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.record_interval_ms, serializer);
+        <u32>::sse_encode(self.top_processes, serializer);
+        <u64>::sse_encode(self.compaction_interval_ms, serializer);
+        <crate::store::RetentionPolicy>::sse_encode(self.retention, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::leak::LeakDetectorOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.window_ms, serializer);
+        <u32>::sse_encode(self.min_samples, serializer);
+        <u64>::sse_encode(self.min_slope_bytes_per_minute, serializer);
+        <f64>::sse_encode(self.min_r_squared, serializer);
+        <f64>::sse_encode(self.min_monotonic_ratio, serializer);
+    }
+}
+
+impl SseEncode for crate::leak::LeakReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u32>::sse_encode(self.samples, serializer);
+        <f64>::sse_encode(self.slope_bytes_per_minute, serializer);
+        <f64>::sse_encode(self.r_squared, serializer);
+        <f64>::sse_encode(self.monotonic_ratio, serializer);
+        <f64>::sse_encode(self.confidence, serializer);
+        <crate::utils::Storage>::sse_encode(self.current, serializer);
+        <crate::utils::Storage>::sse_encode(self.growth, serializer);
+        <Option<u64>>::sse_encode(self.time_to_oom_ms, serializer);
+    }
+}
+
+impl SseEncode for Vec<crate::alerts::AlertRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::alerts::AlertRule>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::cgroup::CgroupNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::cgroup::CgroupNode>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::history::HistoryBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::history::HistoryBucket>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::leak::LeakReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::leak::LeakReport>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::smaps::MappingGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::smaps::MappingGroup>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::smaps::MemoryMapping> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::smaps::MemoryMapping>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<(u32, crate::memory::ProcessMemoryInfo)> {
+impl SseEncode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(u32, crate::memory::ProcessMemoryInfo)>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::memory::Memory {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_totalMemory = <crate::utils::Storage>::sse_decode(deserializer);
-        let mut var_usedMemory = <crate::utils::Storage>::sse_decode(deserializer);
-        let mut var_totalSwap = <crate::utils::Storage>::sse_decode(deserializer);
-        let mut var_usedSwap = <crate::utils::Storage>::sse_decode(deserializer);
-        let mut var_processes =
-            <std::collections::HashMap<u32, crate::memory::ProcessMemoryInfo>>::sse_decode(
-                deserializer,
-            );
-        return crate::memory::Memory {
-            total_memory: var_totalMemory,
-            used_memory: var_usedMemory,
-            total_swap: var_totalSwap,
-            used_swap: var_usedSwap,
-            processes: var_processes,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::diff::ProcessDiff> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::diff::ProcessDiff>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<u32> {
+impl SseEncode for Vec<crate::group::ProcessGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::group::ProcessGroup>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for crate::memory::ProcessMemoryInfo {
+impl SseEncode for Vec<crate::memory::ProcessTreeNode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_memory = <crate::utils::Storage>::sse_decode(deserializer);
-        let mut var_rawMemory = <u64>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_exe = <Option<String>>::sse_decode(deserializer);
-        let mut var_parent = <Option<u32>>::sse_decode(deserializer);
-        let mut var_root = <Option<String>>::sse_decode(deserializer);
-        let mut var_totalMemory = <crate::utils::Storage>::sse_decode(deserializer);
-        return crate::memory::ProcessMemoryInfo {
-            memory: var_memory,
-            raw_memory: var_rawMemory,
-            name: var_name,
-            exe: var_exe,
-            parent: var_parent,
-            root: var_root,
-            total_memory: var_totalMemory,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::memory::ProcessTreeNode>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for (u32, crate::memory::ProcessMemoryInfo) {
+impl SseEncode for Vec<(String, u64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <u32>::sse_decode(deserializer);
-        let mut var_field1 = <crate::memory::ProcessMemoryInfo>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, u64)>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::utils::Storage {
+impl SseEncode for Vec<(u32, crate::memory::ProcessMemoryInfo)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_quotient = <u64>::sse_decode(deserializer);
-        let mut var_remainder = <u64>::sse_decode(deserializer);
-        let mut var_unit = <crate::utils::Unit>::sse_decode(deserializer);
-        return crate::utils::Storage {
-            quotient: var_quotient,
-            remainder: var_remainder,
-            unit: var_unit,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(u32, crate::memory::ProcessMemoryInfo)>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Vec<crate::control::SignalResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::control::SignalResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::smaps::MappingGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <crate::smaps::MappingKind>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.count, serializer);
+        <crate::utils::Storage>::sse_encode(self.size, serializer);
+        <crate::utils::Storage>::sse_encode(self.rss, serializer);
+        <crate::utils::Storage>::sse_encode(self.pss, serializer);
+        <crate::utils::Storage>::sse_encode(self.dirty, serializer);
+        <crate::utils::Storage>::sse_encode(self.swap, serializer);
+    }
+}
+
+impl SseEncode for crate::smaps::MappingKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::smaps::MappingKind::Heap => 0,
+                crate::smaps::MappingKind::Stack => 1,
+                crate::smaps::MappingKind::Anonymous => 2,
+                crate::smaps::MappingKind::File => 3,
+                crate::smaps::MappingKind::Special => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::memory::Memory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::utils::Storage>::sse_encode(self.total_memory, serializer);
+        <crate::utils::Storage>::sse_encode(self.used_memory, serializer);
+        <crate::utils::Storage>::sse_encode(self.total_swap, serializer);
+        <crate::utils::Storage>::sse_encode(self.used_swap, serializer);
+        <std::collections::HashMap<u32, crate::memory::ProcessMemoryInfo>>::sse_encode(
+            self.processes,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::meminfo::MemoryBreakdown {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::utils::Storage>::sse_encode(self.total, serializer);
+        <crate::utils::Storage>::sse_encode(self.free, serializer);
+        <crate::utils::Storage>::sse_encode(self.available, serializer);
+        <crate::utils::Storage>::sse_encode(self.buffers, serializer);
+        <crate::utils::Storage>::sse_encode(self.cached, serializer);
+        <crate::utils::Storage>::sse_encode(self.swap_cached, serializer);
+        <crate::utils::Storage>::sse_encode(self.shmem, serializer);
+        <crate::utils::Storage>::sse_encode(self.slab_reclaimable, serializer);
+        <crate::utils::Storage>::sse_encode(self.slab_unreclaimable, serializer);
+        <crate::utils::Storage>::sse_encode(self.active, serializer);
+        <crate::utils::Storage>::sse_encode(self.inactive, serializer);
+        <crate::utils::Storage>::sse_encode(self.dirty, serializer);
+        <crate::utils::Storage>::sse_encode(self.writeback, serializer);
+        <u64>::sse_encode(self.hugepages_total, serializer);
+        <u64>::sse_encode(self.hugepages_free, serializer);
+        <crate::utils::Storage>::sse_encode(self.hugepage_size, serializer);
+        <crate::utils::Storage>::sse_encode(self.committed_as, serializer);
+        <crate::utils::Storage>::sse_encode(self.commit_limit, serializer);
+    }
+}
+
+impl SseEncode for crate::diff::MemoryDiff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.before_timestamp, serializer);
+        <u64>::sse_encode(self.after_timestamp, serializer);
+        <i64>::sse_encode(self.total_memory_delta, serializer);
+        <i64>::sse_encode(self.used_memory_delta, serializer);
+        <i64>::sse_encode(self.total_swap_delta, serializer);
+        <i64>::sse_encode(self.used_swap_delta, serializer);
+        <Vec<crate::diff::ProcessDiff>>::sse_encode(self.processes, serializer);
+    }
+}
+
+impl SseEncode for crate::error::MemoryError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::error::MemoryError::PermissionDenied { message } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::MemoryError::ProcessGone { pid } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(pid, serializer);
+            }
+            crate::error::MemoryError::NotFound { message } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::MemoryError::TreeInconsistent { message } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::MemoryError::Unsupported { message } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::MemoryError::InvalidInput { message } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::error::MemoryError::Io { message } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::smaps::MemoryMapping {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.start, serializer);
+        <u64>::sse_encode(self.end, serializer);
+        <String>::sse_encode(self.permissions, serializer);
+        <u64>::sse_encode(self.offset, serializer);
+        <crate::smaps::MappingKind>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.path, serializer);
+        <crate::utils::Storage>::sse_encode(self.size, serializer);
+        <crate::utils::Storage>::sse_encode(self.rss, serializer);
+        <crate::utils::Storage>::sse_encode(self.pss, serializer);
+        <crate::utils::Storage>::sse_encode(self.dirty, serializer);
+        <crate::utils::Storage>::sse_encode(self.swap, serializer);
+    }
+}
+
+impl SseEncode for crate::memory::MemoryMetric {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::memory::MemoryMetric::Rss => 0,
+                crate::memory::MemoryMetric::Pss => 1,
+                crate::memory::MemoryMetric::Uss => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::utils::Storage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::utils::Storage>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::utils::StorageRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::utils::StorageRate>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::utils::StorageUnit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::utils::StorageUnit>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::utils::UnitSystem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::utils::UnitSystem>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Option<Vec<u32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u32>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::diff::ProcessChange {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::diff::ProcessChange::Started => 0,
+                crate::diff::ProcessChange::Exited => 1,
+                crate::diff::ProcessChange::Changed => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::utils::Unit {
+impl SseEncode for crate::diff::ProcessDiff {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::utils::Unit::B,
-            1 => crate::utils::Unit::KB,
-            2 => crate::utils::Unit::MB,
-            3 => crate::utils::Unit::GB,
-            4 => crate::utils::Unit::TB,
-            5 => crate::utils::Unit::PB,
-            _ => unreachable!("Invalid variant for Unit: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <crate::diff::ProcessChange>::sse_encode(self.change, serializer);
+        <u64>::sse_encode(self.memory_before, serializer);
+        <u64>::sse_encode(self.memory_after, serializer);
+        <i64>::sse_encode(self.memory_delta, serializer);
+        <u64>::sse_encode(self.total_memory_before, serializer);
+        <u64>::sse_encode(self.total_memory_after, serializer);
+        <i64>::sse_encode(self.total_memory_delta, serializer);
     }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::query::ProcessFilter {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.text, serializer);
+        <Option<String>>::sse_encode(self.pattern, serializer);
+        <Option<u64>>::sse_encode(self.min_memory, serializer);
+        <bool>::sse_encode(self.roots_only, serializer);
+        <Option<Vec<u32>>>::sse_encode(self.pids, serializer);
+        <Option<String>>::sse_encode(self.user, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__memory__get_first_process_memory_usage_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__memory__get_memory_info_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__memory__init_app_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::group::ProcessGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.key, serializer);
+        <u32>::sse_encode(self.count, serializer);
+        <Vec<u32>>::sse_encode(self.pids, serializer);
+        <crate::utils::Storage>::sse_encode(self.memory, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        4 => wire__crate__api__memory__storage_to_float_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__memory__unit_to_string_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::memory::ProcessMemoryInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::utils::Storage>::sse_encode(self.memory, serializer);
+        <u64>::sse_encode(self.raw_memory, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.exe, serializer);
+        <Option<u32>>::sse_encode(self.parent, serializer);
+        <Option<String>>::sse_encode(self.root, serializer);
+        <crate::utils::Storage>::sse_encode(self.total_memory, serializer);
+        <Option<crate::utils::Storage>>::sse_encode(self.pss, serializer);
+        <Option<crate::utils::Storage>>::sse_encode(self.uss, serializer);
+        <Option<crate::utils::Storage>>::sse_encode(self.swap, serializer);
+        <Option<String>>::sse_encode(self.user, serializer);
+        <u64>::sse_encode(self.start_time, serializer);
+        <Option<String>>::sse_encode(self.cgroup, serializer);
+        <Option<crate::utils::StorageRate>>::sse_encode(self.growth_rate, serializer);
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::memory::Memory {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.total_memory.into_into_dart().into_dart(),
-            self.used_memory.into_into_dart().into_dart(),
-            self.total_swap.into_into_dart().into_dart(),
-            self.used_swap.into_into_dart().into_dart(),
-            self.processes.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::memory::Memory {}
-impl flutter_rust_bridge::IntoIntoDart<crate::memory::Memory> for crate::memory::Memory {
-    fn into_into_dart(self) -> crate::memory::Memory {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::memory::ProcessMemoryInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.memory.into_into_dart().into_dart(),
-            self.raw_memory.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.exe.into_into_dart().into_dart(),
-            self.parent.into_into_dart().into_dart(),
-            self.root.into_into_dart().into_dart(),
-            self.total_memory.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::memory::ProcessMemoryInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::memory::ProcessMemoryInfo>
-    for crate::memory::ProcessMemoryInfo
-{
-    fn into_into_dart(self) -> crate::memory::ProcessMemoryInfo {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::utils::Storage {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.quotient.into_into_dart().into_dart(),
-            self.remainder.into_into_dart().into_dart(),
-            self.unit.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::utils::Storage {}
-impl flutter_rust_bridge::IntoIntoDart<crate::utils::Storage> for crate::utils::Storage {
-    fn into_into_dart(self) -> crate::utils::Storage {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::utils::Unit {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::B => 0.into_dart(),
-            Self::KB => 1.into_dart(),
-            Self::MB => 2.into_dart(),
-            Self::GB => 3.into_dart(),
-            Self::TB => 4.into_dart(),
-            Self::PB => 5.into_dart(),
-            _ => unreachable!(),
-        }
+impl SseEncode for crate::smaps::ProcessMemoryMaps {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <Vec<crate::smaps::MemoryMapping>>::sse_encode(self.mappings, serializer);
+        <Vec<crate::smaps::MappingGroup>>::sse_encode(self.groups, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::utils::Unit {}
-impl flutter_rust_bridge::IntoIntoDart<crate::utils::Unit> for crate::utils::Unit {
-    fn into_into_dart(self) -> crate::utils::Unit {
-        self
+
+impl SseEncode for crate::query::ProcessPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(u32, crate::memory::ProcessMemoryInfo)>>::sse_encode(self.items, serializer);
+        <u32>::sse_encode(self.total, serializer);
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::query::ProcessQuery {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <crate::query::ProcessFilter>::sse_encode(self.filter, serializer);
+        <crate::query::ProcessSortKey>::sse_encode(self.sort_key, serializer);
+        <crate::query::SortDirection>::sse_encode(self.direction, serializer);
+        <u32>::sse_encode(self.offset, serializer);
+        <u32>::sse_encode(self.limit, serializer);
     }
 }
 
-impl SseEncode for std::collections::HashMap<u32, crate::memory::ProcessMemoryInfo> {
+impl SseEncode for crate::query::ProcessSortKey {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(u32, crate::memory::ProcessMemoryInfo)>>::sse_encode(
-            self.into_iter().collect(),
+        <i32>::sse_encode(
+            match self {
+                crate::query::ProcessSortKey::Memory => 0,
+                crate::query::ProcessSortKey::TotalMemory => 1,
+                crate::query::ProcessSortKey::Name => 2,
+                crate::query::ProcessSortKey::Pid => 3,
+                crate::query::ProcessSortKey::StartTime => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
             serializer,
         );
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::memory::ProcessTreeNode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u32>::sse_encode(self.depth, serializer);
+        <crate::utils::Storage>::sse_encode(self.memory, serializer);
+        <crate::utils::Storage>::sse_encode(self.total_memory, serializer);
+        <Vec<crate::memory::ProcessTreeNode>>::sse_encode(self.children, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for (String, u64) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.0, serializer);
+        <u64>::sse_encode(self.1, serializer);
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for (u32, crate::memory::ProcessMemoryInfo) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <u32>::sse_encode(self.0, serializer);
+        <crate::memory::ProcessMemoryInfo>::sse_encode(self.1, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::store::RetentionPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <u64>::sse_encode(self.max_age_ms, serializer);
+        <u64>::sse_encode(self.full_resolution_ms, serializer);
+        <u64>::sse_encode(self.compacted_interval_ms, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::control::Signal {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::control::Signal::Term => 0,
+                crate::control::Signal::Kill => 1,
+                crate::control::Signal::Stop => 2,
+                crate::control::Signal::Cont => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<(u32, crate::memory::ProcessMemoryInfo)> {
+impl SseEncode for crate::control::SignalResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(u32, crate::memory::ProcessMemoryInfo)>::sse_encode(item, serializer);
+        <u32>::sse_encode(self.pid, serializer);
+        <crate::control::Signal>::sse_encode(self.signal, serializer);
+        <crate::control::SignalStatus>::sse_encode(self.status, serializer);
+    }
+}
+
+impl SseEncode for crate::control::SignalStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::control::SignalStatus::Sent => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::control::SignalStatus::PermissionDenied => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::control::SignalStatus::NotFound => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::control::SignalStatus::InvalidPid => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::control::SignalStatus::Unsupported => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::control::SignalStatus::Failed { message } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for crate::memory::Memory {
+impl SseEncode for crate::query::SortDirection {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::utils::Storage>::sse_encode(self.total_memory, serializer);
-        <crate::utils::Storage>::sse_encode(self.used_memory, serializer);
-        <crate::utils::Storage>::sse_encode(self.total_swap, serializer);
-        <crate::utils::Storage>::sse_encode(self.used_swap, serializer);
-        <std::collections::HashMap<u32, crate::memory::ProcessMemoryInfo>>::sse_encode(
-            self.processes,
+        <i32>::sse_encode(
+            match self {
+                crate::query::SortDirection::Ascending => 0,
+                crate::query::SortDirection::Descending => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
             serializer,
         );
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::utils::Storage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <u64>::sse_encode(self.quotient, serializer);
+        <u64>::sse_encode(self.remainder, serializer);
+        <crate::utils::StorageUnit>::sse_encode(self.unit, serializer);
     }
 }
 
-impl SseEncode for Option<u32> {
+impl SseEncode for crate::format::StorageFormatOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+        <u32>::sse_encode(self.precision, serializer);
+        <Option<crate::utils::StorageUnit>>::sse_encode(self.unit, serializer);
+        <Option<crate::utils::UnitSystem>>::sse_encode(self.system, serializer);
+        <bool>::sse_encode(self.trim_zeros, serializer);
+        <crate::format::StorageStyle>::sse_encode(self.style, serializer);
+        <String>::sse_encode(self.decimal_separator, serializer);
+        <Option<String>>::sse_encode(self.thousands_separator, serializer);
     }
 }
 
-impl SseEncode for crate::memory::ProcessMemoryInfo {
+impl SseEncode for crate::utils::StorageRate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::utils::Storage>::sse_encode(self.memory, serializer);
-        <u64>::sse_encode(self.raw_memory, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <Option<String>>::sse_encode(self.exe, serializer);
-        <Option<u32>>::sse_encode(self.parent, serializer);
-        <Option<String>>::sse_encode(self.root, serializer);
-        <crate::utils::Storage>::sse_encode(self.total_memory, serializer);
+        <f64>::sse_encode(self.bytes_per_second, serializer);
     }
 }
 
-impl SseEncode for (u32, crate::memory::ProcessMemoryInfo) {
+impl SseEncode for crate::format::StorageStyle {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.0, serializer);
-        <crate::memory::ProcessMemoryInfo>::sse_encode(self.1, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::format::StorageStyle::Short => 0,
+                crate::format::StorageStyle::Compact => 1,
+                crate::format::StorageStyle::Long => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::utils::Storage {
+impl SseEncode for crate::utils::StorageUnit {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.quotient, serializer);
-        <u64>::sse_encode(self.remainder, serializer);
-        <crate::utils::Unit>::sse_encode(self.unit, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::utils::StorageUnit::B => 0,
+                crate::utils::StorageUnit::KiB => 1,
+                crate::utils::StorageUnit::MiB => 2,
+                crate::utils::StorageUnit::GiB => 3,
+                crate::utils::StorageUnit::TiB => 4,
+                crate::utils::StorageUnit::PiB => 5,
+                crate::utils::StorageUnit::KB => 6,
+                crate::utils::StorageUnit::MB => 7,
+                crate::utils::StorageUnit::GB => 8,
+                crate::utils::StorageUnit::TB => 9,
+                crate::utils::StorageUnit::PB => 10,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -688,17 +4904,18 @@ impl SseEncode for u8 {
     }
 }
 
-impl SseEncode for crate::utils::Unit {
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for crate::utils::UnitSystem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::utils::Unit::B => 0,
-                crate::utils::Unit::KB => 1,
-                crate::utils::Unit::MB => 2,
-                crate::utils::Unit::GB => 3,
-                crate::utils::Unit::TB => 4,
-                crate::utils::Unit::PB => 5,
+                crate::utils::UnitSystem::Iec => 0,
+                crate::utils::UnitSystem::Si => 1,
                 _ => {
                     unimplemented!("");
                 }
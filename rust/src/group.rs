@@ -0,0 +1,163 @@
+use crate::{
+    error::MemoryError,
+    memory::{Memory, MemoryMetric, ProcessMemoryInfo},
+    utils::Storage,
+};
+use itertools::Itertools;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// What [`group_processes`] rolls processes up by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupBy {
+    Name,
+    Exe,
+    User,
+    /// Matched against the process name. The first capture group, or the
+    /// whole match if there is none, is the group key. Processes that do not
+    /// match are left out.
+    Pattern {
+        pattern: String,
+    },
+}
+
+/// Processes sharing the same key.
+#[derive(Debug, Clone)]
+pub struct ProcessGroup {
+    /// `None` for processes whose executable or user could not be read.
+    pub key: Option<String>,
+    pub count: u32,
+    /// Sorted by PID.
+    pub pids: Vec<u32>,
+    /// Sum of the processes' own memory, by the requested metric.
+    pub memory: Storage,
+}
+
+/// Rolls up the processes of `memory` into groups, largest first.
+///
+/// Unlike [`Memory::first`], this ignores the parent/child hierarchy, so
+/// processes that were re-parented to init still end up together.
+pub fn group_processes(
+    memory: &Memory,
+    by: &GroupBy,
+    metric: MemoryMetric,
+) -> Result<Vec<ProcessGroup>, MemoryError> {
+    let pattern = match by {
+        GroupBy::Pattern { pattern } => Some(Regex::new(pattern)?),
+        _ => None,
+    };
+    let key = |process: &ProcessMemoryInfo| -> Option<Option<String>> {
+        match by {
+            GroupBy::Name => Some(Some(process.name.clone())),
+            GroupBy::Exe => Some(process.exe.clone()),
+            GroupBy::User => Some(process.user.clone()),
+            GroupBy::Pattern { .. } => {
+                let captures = pattern.as_ref()?.captures(&process.name)?;
+                let matched = captures.get(1).or_else(|| captures.get(0))?;
+                Some(Some(matched.as_str().to_string()))
+            }
+        }
+    };
+
+    let mut groups: HashMap<Option<String>, (Vec<u32>, u64)> = HashMap::new();
+    for (pid, process) in &memory.processes {
+        let Some(key) = key(process) else {
+            continue;
+        };
+        let (pids, bytes) = groups.entry(key).or_default();
+        pids.push(*pid);
+        *bytes += metric.bytes(process);
+    }
+
+    Ok(groups
+        .into_iter()
+        .sorted_by(|(a_key, (_, a)), (b_key, (_, b))| b.cmp(a).then_with(|| a_key.cmp(b_key)))
+        .map(|(key, (mut pids, bytes))| {
+            pids.sort_unstable();
+            ProcessGroup {
+                key,
+                count: pids.len() as u32,
+                pids,
+                memory: Storage::from_bytes(bytes),
+            }
+        })
+        .collect_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{source::RawProcess, test_support};
+
+    fn memory(processes: &[(u32, &str, Option<&str>, u64)]) -> Memory {
+        test_support::memory(
+            processes
+                .iter()
+                .map(|&(pid, name, user, bytes)| RawProcess {
+                    name: name.to_string(),
+                    exe: Some(format!("/usr/bin/{name}")),
+                    user: user.map(str::to_string),
+                    ..test_support::process(pid, Some(1), bytes)
+                }),
+        )
+    }
+
+    fn summary(groups: &[ProcessGroup]) -> Vec<(Option<&str>, u32, u64)> {
+        groups
+            .iter()
+            .map(|g| (g.key.as_deref(), g.count, g.memory.to_bytes()))
+            .collect_vec()
+    }
+
+    #[test]
+    fn test_group_by_name_and_user() {
+        let memory = memory(&[
+            (10, "chrome", Some("alice"), 100),
+            (11, "chrome", Some("alice"), 200),
+            (12, "chrome", Some("bob"), 50),
+            (20, "bash", None, 400),
+        ]);
+
+        let by_name = group_processes(&memory, &GroupBy::Name, MemoryMetric::Rss).unwrap();
+        assert_eq!(
+            summary(&by_name),
+            vec![(Some("bash"), 1, 400), (Some("chrome"), 3, 350)]
+        );
+        assert_eq!(by_name[1].pids, vec![10, 11, 12]);
+
+        let by_user = group_processes(&memory, &GroupBy::User, MemoryMetric::Rss).unwrap();
+        assert_eq!(
+            summary(&by_user),
+            vec![
+                (None, 1, 400),
+                (Some("alice"), 2, 300),
+                (Some("bob"), 1, 50)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_pattern() {
+        let memory = memory(&[
+            (10, "chrome", None, 100),
+            (11, "chrome_crashpad", None, 10),
+            (12, "code-helper", None, 30),
+            (13, "code", None, 20),
+            (20, "bash", None, 400),
+        ]);
+
+        let by = GroupBy::Pattern {
+            pattern: String::from("^(chrome|code)"),
+        };
+        let groups = group_processes(&memory, &by, MemoryMetric::Rss).unwrap();
+        assert_eq!(
+            summary(&groups),
+            vec![(Some("chrome"), 2, 110), (Some("code"), 2, 50)]
+        );
+
+        let invalid = GroupBy::Pattern {
+            pattern: String::from("("),
+        };
+        assert!(group_processes(&memory, &invalid, MemoryMetric::Rss).is_err());
+    }
+}
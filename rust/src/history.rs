@@ -0,0 +1,491 @@
+use crate::memory::Memory;
+use itertools::Itertools;
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// One hour of samples at the default one second interval.
+pub const DEFAULT_CAPACITY: usize = 3600;
+
+/// Processes kept per sample, largest `total_memory` first.
+pub const DEFAULT_PROCESS_LIMIT: usize = 50;
+
+/// Milliseconds since the Unix epoch.
+pub fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// Memory figures of a single process at one point in time, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProcessSample {
+    pub pid: u32,
+    /// Seconds since the Unix epoch, telling apart processes that reused
+    /// the same PID.
+    pub start_time: u64,
+    pub memory: u64,
+    pub total_memory: u64,
+}
+
+/// Compact form of a [`Memory`] snapshot, in bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    pub total_memory: u64,
+    pub used_memory: u64,
+    pub total_swap: u64,
+    pub used_swap: u64,
+    /// Sorted by PID.
+    pub processes: Vec<ProcessSample>,
+    /// Whether smaller processes were left out of `processes`.
+    pub truncated: bool,
+}
+
+impl Sample {
+    pub fn from_memory(memory: &Memory, timestamp: u64) -> Self {
+        let processes = memory
+            .processes
+            .iter()
+            .map(|(pid, process)| ProcessSample {
+                pid: *pid,
+                start_time: process.start_time,
+                memory: process.raw_memory,
+                total_memory: process.total_memory.to_bytes(),
+            })
+            .sorted_by_key(|process| process.pid)
+            .collect_vec();
+
+        Self {
+            timestamp,
+            total_memory: memory.total_memory.to_bytes(),
+            used_memory: memory.used_memory.to_bytes(),
+            total_swap: memory.total_swap.to_bytes(),
+            used_swap: memory.used_swap.to_bytes(),
+            processes,
+            truncated: false,
+        }
+    }
+
+    /// Keeps only the `limit` processes with the largest `total_memory`,
+    /// along with every process `keep` returns `true` for.
+    pub fn retain_largest(&mut self, limit: usize, keep: impl Fn(u32) -> bool) {
+        if self.processes.len() <= limit {
+            return;
+        }
+        self.processes
+            .sort_unstable_by(|a, b| b.total_memory.cmp(&a.total_memory).then(a.pid.cmp(&b.pid)));
+        let len = self.processes.len();
+        let mut index = 0;
+        self.processes.retain(|process| {
+            index += 1;
+            index <= limit || keep(process.pid)
+        });
+        self.processes.sort_unstable_by_key(|process| process.pid);
+        self.truncated = self.processes.len() < len;
+    }
+
+    pub fn process(&self, pid: u32) -> Option<&ProcessSample> {
+        self.processes
+            .binary_search_by_key(&pid, |process| process.pid)
+            .ok()
+            .map(|index| &self.processes[index])
+    }
+}
+
+/// A single value tracked by the history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistorySeries {
+    UsedMemory,
+    UsedSwap,
+    /// Resident memory of a single process.
+    ProcessMemory(u32),
+    /// Aggregated memory of a process and its descendants.
+    ProcessTotalMemory(u32),
+}
+
+impl HistorySeries {
+    fn value(&self, sample: &Sample) -> Option<u64> {
+        match self {
+            HistorySeries::UsedMemory => Some(sample.used_memory),
+            HistorySeries::UsedSwap => Some(sample.used_swap),
+            HistorySeries::ProcessMemory(pid) => sample.process(*pid).map(|p| p.memory),
+            HistorySeries::ProcessTotalMemory(pid) => sample.process(*pid).map(|p| p.total_memory),
+        }
+    }
+}
+
+/// Downsampled values of a series over `[start, end)`, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistoryBucket {
+    pub start: u64,
+    pub end: u64,
+    pub min: u64,
+    pub max: u64,
+    pub avg: u64,
+    pub count: u32,
+}
+
+/// Bounded, in-memory history of [`Sample`]s.
+///
+/// Once `capacity` samples are stored the oldest ones are dropped. Longer
+/// windows are served by downsampling into fixed-width buckets at query time
+/// rather than by keeping separate coarse tiers. Samples taken through
+/// [`History::record_memory`] keep only the largest processes.
+#[derive(Debug, Clone)]
+pub struct History {
+    samples: VecDeque<Sample>,
+    capacity: usize,
+    process_limit: usize,
+    names: HashMap<u32, String>,
+    /// Number of retained samples each PID appears in.
+    pids: HashMap<u32, usize>,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl History {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity.min(DEFAULT_CAPACITY)),
+            capacity: capacity.max(1),
+            process_limit: DEFAULT_PROCESS_LIMIT,
+            names: HashMap::new(),
+            pids: HashMap::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.evict();
+    }
+
+    pub fn process_limit(&self) -> usize {
+        self.process_limit
+    }
+
+    /// Applies to samples recorded from now on.
+    pub fn set_process_limit(&mut self, limit: usize) {
+        self.process_limit = limit;
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.names.clear();
+        self.pids.clear();
+    }
+
+    /// Appends a sample of the largest processes in `memory`, remembering
+    /// their names for later lookups through [`History::name`].
+    ///
+    /// Processes already in the history stay in it however small they get,
+    /// so their series have no gaps.
+    pub fn record_memory(&mut self, memory: &Memory, timestamp: u64) {
+        let mut sample = Sample::from_memory(memory, timestamp);
+        sample.retain_largest(self.process_limit, |pid| self.pids.contains_key(&pid));
+        for process in &sample.processes {
+            self.set_name(process.pid, &memory.processes[&process.pid].name);
+        }
+        self.record(sample);
+    }
+
+    /// Appends a sample. Samples older than the newest one already stored
+    /// are ignored, so the buffer always stays in timestamp order.
+    pub fn record(&mut self, sample: Sample) {
+        if self
+            .samples
+            .back()
+            .is_some_and(|last| last.timestamp > sample.timestamp)
+        {
+            return;
+        }
+        for process in &sample.processes {
+            *self.pids.entry(process.pid).or_default() += 1;
+        }
+        self.samples.push_back(sample);
+        self.evict();
+    }
+
+    /// Drops the oldest samples beyond the capacity, along with the names of
+    /// PIDs no retained sample mentions anymore.
+    fn evict(&mut self) {
+        if self.samples.len() <= self.capacity {
+            return;
+        }
+        while self.samples.len() > self.capacity {
+            let Some(sample) = self.samples.pop_front() else {
+                break;
+            };
+            for process in &sample.processes {
+                if let Some(count) = self.pids.get_mut(&process.pid) {
+                    *count -= 1;
+                    if *count == 0 {
+                        self.pids.remove(&process.pid);
+                    }
+                }
+            }
+        }
+        let pids = &self.pids;
+        self.names.retain(|pid, _| pids.contains_key(pid));
+    }
+
+    pub fn set_name(&mut self, pid: u32, name: &str) {
+        if self.names.get(&pid).map(String::as_str) != Some(name) {
+            self.names.insert(pid, name.to_string());
+        }
+    }
+
+    /// Last known name of `pid`.
+    pub fn name(&self, pid: u32) -> Option<&str> {
+        self.names.get(&pid).map(String::as_str)
+    }
+
+    pub fn latest(&self) -> Option<&Sample> {
+        self.samples.back()
+    }
+
+    /// Samples with `start <= timestamp < end`, oldest first.
+    pub fn range(&self, start: u64, end: u64) -> impl Iterator<Item = &Sample> {
+        let from = self.samples.partition_point(|s| s.timestamp < start);
+        self.samples
+            .range(from..)
+            .take_while(move |s| s.timestamp < end)
+    }
+
+    /// The sample taken closest to `timestamp`.
+    pub fn nearest(&self, timestamp: u64) -> Option<&Sample> {
+        let index = self.samples.partition_point(|s| s.timestamp < timestamp);
+        [index.checked_sub(1), Some(index)]
+            .into_iter()
+            .flatten()
+            .filter_map(|i| self.samples.get(i))
+            .min_by_key(|s| s.timestamp.abs_diff(timestamp))
+    }
+
+    /// Downsamples `series` over `[start, end)` into buckets `resolution`
+    /// milliseconds wide, aligned to multiples of `resolution`.
+    ///
+    /// A `resolution` of 0 returns every sample as its own bucket. Buckets
+    /// without any sample for the series (e.g. before a process started) are
+    /// omitted.
+    pub fn query(
+        &self,
+        series: HistorySeries,
+        start: u64,
+        end: u64,
+        resolution: u64,
+    ) -> Vec<HistoryBucket> {
+        let points = self
+            .range(start, end)
+            .filter_map(|sample| Some((sample.timestamp, series.value(sample)?)));
+
+        if resolution == 0 {
+            return points
+                .map(|(timestamp, value)| HistoryBucket {
+                    start: timestamp,
+                    end: timestamp,
+                    min: value,
+                    max: value,
+                    avg: value,
+                    count: 1,
+                })
+                .collect_vec();
+        }
+
+        points
+            .chunk_by(|(timestamp, _)| timestamp / resolution)
+            .into_iter()
+            .map(|(slot, values)| {
+                let (mut min, mut max, mut sum, mut count) = (u64::MAX, 0, 0u128, 0u32);
+                for (_, value) in values {
+                    min = min.min(value);
+                    max = max.max(value);
+                    sum += value as u128;
+                    count += 1;
+                }
+                HistoryBucket {
+                    start: slot * resolution,
+                    end: (slot + 1) * resolution,
+                    min,
+                    max,
+                    avg: (sum / count as u128) as u64,
+                    count,
+                }
+            })
+            .collect_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, process, sample};
+
+    #[test]
+    fn test_capacity() {
+        let mut history = History::new(3);
+        for i in 0..5 {
+            history.record(sample(i * 1000, i, &[]));
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.range(0, u64::MAX).next().unwrap().timestamp, 2000);
+
+        history.set_capacity(1);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.latest().unwrap().timestamp, 4000);
+    }
+
+    #[test]
+    fn test_out_of_order_samples_are_dropped() {
+        let mut history = History::new(10);
+        history.record(sample(2000, 1, &[]));
+        history.record(sample(1000, 2, &[]));
+        assert_eq!(history.len(), 1);
+    }
+
+    #[test]
+    fn test_query_raw() {
+        let mut history = History::new(10);
+        for i in 0..5 {
+            history.record(sample(i * 1000, i * 10, &[]));
+        }
+
+        let buckets = history.query(HistorySeries::UsedMemory, 1000, 4000, 0);
+        assert_eq!(
+            buckets.iter().map(|b| (b.start, b.avg)).collect_vec(),
+            vec![(1000, 10), (2000, 20), (3000, 30)]
+        );
+    }
+
+    #[test]
+    fn test_query_downsampled() {
+        let mut history = History::new(100);
+        for i in 0..6 {
+            history.record(sample(i * 1000, i * 10, &[]));
+        }
+
+        let buckets = history.query(HistorySeries::UsedMemory, 0, u64::MAX, 4000);
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(
+            buckets[0],
+            HistoryBucket {
+                start: 0,
+                end: 4000,
+                min: 0,
+                max: 30,
+                avg: 15,
+                count: 4,
+            }
+        );
+        assert_eq!(
+            (buckets[1].min, buckets[1].max, buckets[1].count),
+            (40, 50, 2)
+        );
+    }
+
+    #[test]
+    fn test_query_process() {
+        let mut history = History::new(10);
+        history.record(sample(0, 0, &[(1, 100)]));
+        history.record(sample(1000, 0, &[(1, 200), (2, 50)]));
+        history.record(sample(2000, 0, &[(2, 70)]));
+
+        let buckets = history.query(HistorySeries::ProcessMemory(1), 0, u64::MAX, 0);
+        assert_eq!(buckets.iter().map(|b| b.avg).collect_vec(), vec![100, 200]);
+
+        let buckets = history.query(HistorySeries::ProcessTotalMemory(2), 0, u64::MAX, 10_000);
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(
+            (buckets[0].min, buckets[0].max, buckets[0].avg),
+            (50, 70, 60)
+        );
+    }
+
+    #[test]
+    fn test_nearest() {
+        let mut history = History::new(10);
+        for i in 0..3 {
+            history.record(sample(i * 1000, i, &[]));
+        }
+        assert_eq!(history.nearest(1400).unwrap().timestamp, 1000);
+        assert_eq!(history.nearest(1600).unwrap().timestamp, 2000);
+        assert_eq!(history.nearest(10_000).unwrap().timestamp, 2000);
+        assert!(History::new(1).nearest(0).is_none());
+    }
+
+    #[test]
+    fn test_names_are_pruned() {
+        let mut history = History::new(2);
+        history.set_name(1, "one");
+        history.set_name(2, "two");
+        history.record(sample(0, 0, &[(1, 10), (2, 10)]));
+        history.record(sample(1000, 0, &[(2, 10)]));
+        assert_eq!(history.name(1), Some("one"));
+
+        history.record(sample(2000, 0, &[(2, 10)]));
+        assert_eq!(history.name(1), None);
+        assert_eq!(history.name(2), Some("two"));
+    }
+
+    #[test]
+    fn test_retain_largest() {
+        let mut sample = sample(0, 0, &[(1, 10), (2, 30), (3, 20), (4, 5)]);
+        sample.retain_largest(4, |_| false);
+        assert!(!sample.truncated);
+
+        let mut kept = sample.clone();
+        kept.retain_largest(2, |pid| pid == 4);
+        assert!(kept.truncated);
+        assert_eq!(
+            kept.processes.iter().map(|p| p.pid).collect_vec(),
+            vec![2, 3, 4]
+        );
+
+        sample.retain_largest(2, |_| false);
+        assert!(sample.truncated);
+        assert_eq!(
+            sample.processes.iter().map(|p| p.pid).collect_vec(),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn test_record_memory_keeps_known_processes() {
+        let mut history = History::new(10);
+        history.set_process_limit(1);
+        history.record_memory(&test_support::memory([process(1, None, 100)]), 0);
+
+        // A larger process shows up; the known one is still recorded.
+        let memory = test_support::memory([
+            process(1, None, 100),
+            process(2, None, 500),
+            process(3, None, 50),
+        ]);
+        history.record_memory(&memory, 1000);
+        let latest = history.latest().unwrap();
+        assert_eq!(
+            latest.processes.iter().map(|p| p.pid).collect_vec(),
+            vec![1, 2]
+        );
+        assert!(latest.truncated);
+        assert_eq!(history.name(1), Some("p1"));
+    }
+}
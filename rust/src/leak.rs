@@ -0,0 +1,288 @@
+use crate::{history::History, utils::Storage};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+const MINUTE_MS: f64 = 60_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeakDetectorOptions {
+    /// Sliding window the trend is fitted over.
+    pub window_ms: u64,
+    /// Processes with fewer samples in the window are not considered.
+    pub min_samples: u32,
+    pub min_slope_bytes_per_minute: u64,
+    /// Minimum goodness of fit of the linear trend, between 0 and 1.
+    pub min_r_squared: f64,
+    /// Minimum share of sample-to-sample steps that did not shrink, between
+    /// 0 and 1.
+    pub min_monotonic_ratio: f64,
+}
+
+impl Default for LeakDetectorOptions {
+    fn default() -> Self {
+        Self {
+            window_ms: 10 * 60 * 1000,
+            min_samples: 30,
+            min_slope_bytes_per_minute: 1024 * 1024,
+            min_r_squared: 0.8,
+            min_monotonic_ratio: 0.9,
+        }
+    }
+}
+
+/// A process whose resident memory grew steadily over the window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeakReport {
+    pub pid: u32,
+    pub name: String,
+    pub samples: u32,
+    /// Slope of the fitted linear trend.
+    pub slope_bytes_per_minute: f64,
+    pub r_squared: f64,
+    pub monotonic_ratio: f64,
+    /// `r_squared` weighted by `monotonic_ratio`, between 0 and 1.
+    pub confidence: f64,
+    pub current: Storage,
+    /// Growth between the first and last sample in the window.
+    pub growth: Storage,
+    /// Time until the system runs out of available memory if the process
+    /// keeps growing at this rate.
+    pub time_to_oom_ms: Option<u64>,
+}
+
+/// Least-squares fit of `bytes` over time, in bytes per minute.
+struct Trend {
+    slope: f64,
+    r_squared: f64,
+}
+
+impl Trend {
+    fn fit(points: &[(u64, u64)]) -> Option<Self> {
+        let (t0, _) = *points.first()?;
+        let n = points.len() as f64;
+        let xs = points.iter().map(|(t, _)| (t - t0) as f64 / MINUTE_MS);
+        let ys = points.iter().map(|(_, bytes)| *bytes as f64);
+
+        let mean_x = xs.clone().sum::<f64>() / n;
+        let mean_y = ys.clone().sum::<f64>() / n;
+        let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+        for (x, y) in xs.zip(ys) {
+            cov += (x - mean_x) * (y - mean_y);
+            var_x += (x - mean_x) * (x - mean_x);
+            var_y += (y - mean_y) * (y - mean_y);
+        }
+        if var_x == 0.0 {
+            return None;
+        }
+
+        let r_squared = if var_y == 0.0 {
+            0.0
+        } else {
+            cov * cov / (var_x * var_y)
+        };
+        Some(Self {
+            slope: cov / var_x,
+            r_squared,
+        })
+    }
+}
+
+fn monotonic_ratio(points: &[(u64, u64)]) -> f64 {
+    let steps = points.len().saturating_sub(1);
+    if steps == 0 {
+        return 0.0;
+    }
+    let growing = points.windows(2).filter(|w| w[1].1 >= w[0].1).count();
+    growing as f64 / steps as f64
+}
+
+/// Looks for processes with sustained memory growth in the samples of
+/// `history` taken during the `window_ms` before `now`.
+///
+/// Processes that reused a PID get separate series.
+///
+/// Returns the suspects, fastest-growing first.
+pub fn detect_leaks(history: &History, now: u64, options: &LeakDetectorOptions) -> Vec<LeakReport> {
+    let start = now.saturating_sub(options.window_ms);
+    let mut series: HashMap<(u32, u64), Vec<(u64, u64)>> = HashMap::new();
+    for sample in history.range(start, now.saturating_add(1)) {
+        for process in &sample.processes {
+            series
+                .entry((process.pid, process.start_time))
+                .or_default()
+                .push((sample.timestamp, process.memory));
+        }
+    }
+
+    let available = history
+        .latest()
+        .map(|s| s.total_memory.saturating_sub(s.used_memory));
+
+    series
+        .into_iter()
+        .filter(|(_, points)| points.len() >= options.min_samples.max(2) as usize)
+        .filter_map(|((pid, _), points)| {
+            let trend = Trend::fit(&points)?;
+            let monotonic_ratio = monotonic_ratio(&points);
+            if trend.slope < options.min_slope_bytes_per_minute as f64
+                || trend.r_squared < options.min_r_squared
+                || monotonic_ratio < options.min_monotonic_ratio
+            {
+                return None;
+            }
+
+            let first = points.first()?.1;
+            let last = points.last()?.1;
+            let time_to_oom_ms =
+                available.map(|bytes| (bytes as f64 / trend.slope * MINUTE_MS) as u64);
+
+            Some(LeakReport {
+                pid,
+                name: history.name(pid).unwrap_or_default().to_string(),
+                samples: points.len() as u32,
+                slope_bytes_per_minute: trend.slope,
+                r_squared: trend.r_squared,
+                monotonic_ratio,
+                confidence: trend.r_squared * monotonic_ratio,
+                current: Storage::from_bytes(last),
+                growth: Storage::from_bytes(last.saturating_sub(first)),
+                time_to_oom_ms,
+            })
+        })
+        .sorted_by(|a, b| {
+            b.slope_bytes_per_minute
+                .total_cmp(&a.slope_bytes_per_minute)
+        })
+        .collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::{ProcessSample, Sample};
+
+    const MB: u64 = 1024 * 1024;
+
+    fn history(series: &[(u32, &dyn Fn(u64) -> u64)], minutes: u64) -> History {
+        let mut history = History::new(1000);
+        for (pid, _) in series {
+            history.set_name(*pid, &format!("p{pid}"));
+        }
+        for minute in 0..minutes {
+            history.record(Sample {
+                timestamp: minute * 60_000,
+                total_memory: 1000 * MB,
+                used_memory: 400 * MB,
+                total_swap: 0,
+                used_swap: 0,
+                processes: series
+                    .iter()
+                    .map(|(pid, f)| ProcessSample {
+                        pid: *pid,
+                        start_time: 0,
+                        memory: f(minute),
+                        total_memory: f(minute),
+                    })
+                    .collect_vec(),
+                truncated: false,
+            });
+        }
+        history
+    }
+
+    fn options() -> LeakDetectorOptions {
+        LeakDetectorOptions {
+            window_ms: u64::MAX,
+            min_samples: 10,
+            ..LeakDetectorOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_detects_steady_growth() {
+        let steady = |m: u64| 100 * MB + m * 10 * MB;
+        let flat = |_: u64| 100 * MB;
+        let history = history(&[(1, &steady), (2, &flat)], 20);
+
+        let reports = detect_leaks(&history, 19 * 60_000, &options());
+        assert_eq!(reports.len(), 1);
+
+        let report = &reports[0];
+        assert_eq!(report.pid, 1);
+        assert_eq!(report.name, "p1");
+        assert_eq!(report.samples, 20);
+        assert!((report.slope_bytes_per_minute - (10 * MB) as f64).abs() < 1.0);
+        assert!((report.r_squared - 1.0).abs() < 1e-9);
+        assert!((report.confidence - 1.0).abs() < 1e-9);
+        assert_eq!(report.growth.to_bytes(), 190 * MB);
+        // 600 MB available at 10 MB/min.
+        assert_eq!(report.time_to_oom_ms, Some(60 * 60_000));
+    }
+
+    #[test]
+    fn test_ignores_noisy_and_shrinking_processes() {
+        let sawtooth = |m: u64| 100 * MB + (m % 4) * 50 * MB + m * MB;
+        let shrinking = |m: u64| 500 * MB - m * 10 * MB;
+        let history = history(&[(1, &sawtooth), (2, &shrinking)], 20);
+
+        assert!(detect_leaks(&history, 19 * 60_000, &options()).is_empty());
+    }
+
+    #[test]
+    fn test_requires_enough_samples() {
+        let steady = |m: u64| m * 10 * MB;
+        let history = history(&[(1, &steady)], 5);
+        assert!(detect_leaks(&history, 4 * 60_000, &options()).is_empty());
+    }
+
+    #[test]
+    fn test_window() {
+        // Flat for 10 minutes, then growing.
+        let late = |m: u64| {
+            if m < 10 {
+                100 * MB
+            } else {
+                100 * MB + (m - 10) * 10 * MB
+            }
+        };
+        let history = history(&[(1, &late)], 30);
+
+        let options = LeakDetectorOptions {
+            window_ms: 15 * 60_000,
+            ..options()
+        };
+        let reports = detect_leaks(&history, 29 * 60_000, &options);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].samples, 16);
+    }
+
+    #[test]
+    fn test_reused_pids_are_separate_series() {
+        // Three flat processes in a row with the same PID, each larger than
+        // the one before.
+        let mut history = History::new(1000);
+        for minute in 0..21 {
+            let generation = minute / 7;
+            history.record(Sample {
+                timestamp: minute * 60_000,
+                total_memory: 1000 * MB,
+                used_memory: 400 * MB,
+                total_swap: 0,
+                used_swap: 0,
+                processes: vec![ProcessSample {
+                    pid: 1,
+                    start_time: generation,
+                    memory: 100 * MB + generation * 200 * MB,
+                    total_memory: 100 * MB + generation * 200 * MB,
+                }],
+                truncated: false,
+            });
+        }
+
+        let options = LeakDetectorOptions {
+            min_samples: 5,
+            ..options()
+        };
+        assert!(detect_leaks(&history, 20 * 60_000, &options).is_empty());
+    }
+}
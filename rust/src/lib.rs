@@ -1,4 +1,24 @@
+pub mod alerts;
 pub mod api;
+pub mod cgroup;
+pub mod control;
+pub mod diff;
+pub mod error;
+pub mod format;
 mod frb_generated;
+pub mod group;
+pub mod history;
+pub mod leak;
+pub mod meminfo;
 pub mod memory;
+pub mod monitor;
+mod procfs;
+pub mod query;
+pub mod recording;
+pub mod sampler;
+pub mod smaps;
+pub mod source;
+pub mod store;
+#[cfg(test)]
+mod test_support;
 pub mod utils;
@@ -0,0 +1,158 @@
+use crate::{procfs, utils::Storage};
+use std::io;
+
+/// System-wide memory breakdown from `/proc/meminfo`.
+///
+/// `used_memory` alone cannot tell page cache, which the kernel drops on
+/// demand, apart from memory that is actually pinned; these counters can.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryBreakdown {
+    pub total: Storage,
+    pub free: Storage,
+    /// Estimate of how much memory can be allocated without swapping.
+    pub available: Storage,
+    pub buffers: Storage,
+    /// Page cache, excluding swap cache.
+    pub cached: Storage,
+    pub swap_cached: Storage,
+    /// Shared memory and tmpfs; counted in `cached` but not reclaimable.
+    pub shmem: Storage,
+    pub slab_reclaimable: Storage,
+    pub slab_unreclaimable: Storage,
+    pub active: Storage,
+    pub inactive: Storage,
+    pub dirty: Storage,
+    pub writeback: Storage,
+    pub hugepages_total: u64,
+    pub hugepages_free: u64,
+    pub hugepage_size: Storage,
+    /// Memory that would be needed to back every allocation made so far.
+    pub committed_as: Storage,
+    pub commit_limit: Storage,
+}
+
+impl MemoryBreakdown {
+    pub fn parse(text: &str) -> Self {
+        let mut breakdown = Self {
+            total: Storage::from_bytes(0),
+            free: Storage::from_bytes(0),
+            available: Storage::from_bytes(0),
+            buffers: Storage::from_bytes(0),
+            cached: Storage::from_bytes(0),
+            swap_cached: Storage::from_bytes(0),
+            shmem: Storage::from_bytes(0),
+            slab_reclaimable: Storage::from_bytes(0),
+            slab_unreclaimable: Storage::from_bytes(0),
+            active: Storage::from_bytes(0),
+            inactive: Storage::from_bytes(0),
+            dirty: Storage::from_bytes(0),
+            writeback: Storage::from_bytes(0),
+            hugepages_total: 0,
+            hugepages_free: 0,
+            hugepage_size: Storage::from_bytes(0),
+            committed_as: Storage::from_bytes(0),
+            commit_limit: Storage::from_bytes(0),
+        };
+
+        for (key, value) in text.lines().filter_map(procfs::parse_field) {
+            let field = match key {
+                "MemTotal" => &mut breakdown.total,
+                "MemFree" => &mut breakdown.free,
+                "MemAvailable" => &mut breakdown.available,
+                "Buffers" => &mut breakdown.buffers,
+                "Cached" => &mut breakdown.cached,
+                "SwapCached" => &mut breakdown.swap_cached,
+                "Shmem" => &mut breakdown.shmem,
+                "SReclaimable" => &mut breakdown.slab_reclaimable,
+                "SUnreclaim" => &mut breakdown.slab_unreclaimable,
+                "Active" => &mut breakdown.active,
+                "Inactive" => &mut breakdown.inactive,
+                "Dirty" => &mut breakdown.dirty,
+                "Writeback" => &mut breakdown.writeback,
+                "Hugepagesize" => &mut breakdown.hugepage_size,
+                "Committed_AS" => &mut breakdown.committed_as,
+                "CommitLimit" => &mut breakdown.commit_limit,
+                "HugePages_Total" => {
+                    breakdown.hugepages_total = value;
+                    continue;
+                }
+                "HugePages_Free" => {
+                    breakdown.hugepages_free = value;
+                    continue;
+                }
+                _ => continue,
+            };
+            *field = Storage::from_bytes(value);
+        }
+
+        breakdown
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn read() -> io::Result<Self> {
+        let text =
+            std::fs::read_to_string(std::path::Path::new(procfs::PROC_ROOT).join("meminfo"))?;
+        Ok(Self::parse(&text))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read() -> io::Result<Self> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    /// Total slab allocator memory.
+    pub fn slab(&self) -> Storage {
+        &self.slab_reclaimable + &self.slab_unreclaimable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMINFO: &str = "\
+MemTotal:        6147400 kB
+MemFree:         3940236 kB
+MemAvailable:    5568064 kB
+Buffers:           66916 kB
+Cached:          1759312 kB
+SwapCached:            0 kB
+Active:           668304 kB
+Inactive:        1336972 kB
+Active(anon):         20 kB
+Dirty:            151572 kB
+Writeback:             0 kB
+Shmem:              9288 kB
+Slab:              62444 kB
+SReclaimable:      43304 kB
+SUnreclaim:        19140 kB
+CommitLimit:     3073700 kB
+Committed_AS:     362712 kB
+HugePages_Total:       4
+HugePages_Free:        1
+Hugepagesize:       2048 kB
+";
+
+    #[test]
+    fn test_parse() {
+        let breakdown = MemoryBreakdown::parse(MEMINFO);
+        assert_eq!(breakdown.total.to_bytes(), 6147400 * 1024);
+        assert_eq!(breakdown.available.to_bytes(), 5568064 * 1024);
+        assert_eq!(breakdown.cached.to_bytes(), 1759312 * 1024);
+        assert_eq!(breakdown.active.to_bytes(), 668304 * 1024);
+        assert_eq!(breakdown.shmem.to_bytes(), 9288 * 1024);
+        assert_eq!(breakdown.slab().to_bytes(), 62444 * 1024);
+        assert_eq!(breakdown.committed_as.to_bytes(), 362712 * 1024);
+        assert_eq!(breakdown.hugepages_total, 4);
+        assert_eq!(breakdown.hugepages_free, 1);
+        assert_eq!(breakdown.hugepage_size.to_bytes(), 2048 * 1024);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_read() {
+        let breakdown = MemoryBreakdown::read().unwrap();
+        assert!(breakdown.total.to_bytes() > 0);
+        assert!(breakdown.available.to_bytes() <= breakdown.total.to_bytes());
+    }
+}
@@ -1,8 +1,15 @@
-use crate::utils::Storage;
+use crate::{
+    error::MemoryError,
+    sampler::Sampler,
+    source::RawSnapshot,
+    utils::{Storage, StorageRate},
+};
 use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
-use sysinfo::{MemoryRefreshKind, ProcessRefreshKind, RefreshKind, System, UpdateKind};
-use tree_ds::prelude::*;
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+use tree_ds::prelude::{Node, Tree};
 
 const VIRTUAL_ROOT_PID: u32 = 0;
 
@@ -24,89 +31,217 @@ pub struct ProcessMemoryInfo {
     pub parent: Option<u32>,
     pub root: Option<String>,
     pub total_memory: Storage,
+    /// Proportional set size; only collected on Linux when the sampler
+    /// aggregates by [`MemoryMetric::Pss`] or [`MemoryMetric::Uss`].
+    pub pss: Option<Storage>,
+    /// Unique set size, collected alongside `pss`.
+    pub uss: Option<Storage>,
+    /// Swapped-out memory, collected alongside `pss`.
+    pub swap: Option<Storage>,
+    /// Name of the user owning the process, when it can be resolved.
+    pub user: Option<String>,
+    /// When the process started, in seconds since the Unix epoch.
+    pub start_time: u64,
+    /// Path of the process' cgroup v2, such as `/system.slice/sshd.service`.
+    /// Only read on Linux.
+    pub cgroup: Option<String>,
+    /// Change of the sampler's metric since the previous sample, when the
+    /// same process was present in both.
+    pub growth_rate: Option<StorageRate>,
+}
+
+/// Which per-process figure `total_memory` sums over a subtree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MemoryMetric {
+    /// Resident set size, as reported by sysinfo. Cheap, but counts shared
+    /// pages once per process.
+    #[default]
+    Rss,
+    /// Proportional set size. Falls back to RSS for processes whose
+    /// `smaps_rollup` cannot be read.
+    Pss,
+    /// Unique set size. Falls back to RSS like [`MemoryMetric::Pss`].
+    Uss,
+}
+
+impl MemoryMetric {
+    pub fn needs_smaps(&self) -> bool {
+        !matches!(self, MemoryMetric::Rss)
+    }
+
+    pub fn bytes(&self, process: &ProcessMemoryInfo) -> u64 {
+        let value = match self {
+            MemoryMetric::Rss => None,
+            MemoryMetric::Pss => process.pss.as_ref(),
+            MemoryMetric::Uss => process.uss.as_ref(),
+        };
+        value.map_or(process.raw_memory, Storage::to_bytes)
+    }
+}
+
+/// A process in the parent/child hierarchy, in a shape that can cross the
+/// FFI boundary (unlike [`Tree`]).
+#[derive(Debug, Clone)]
+pub struct ProcessTreeNode {
+    pub pid: u32,
+    pub name: String,
+    /// Distance from the top of the hierarchy; processes without a known
+    /// parent are at depth 0.
+    pub depth: u32,
+    pub memory: Storage,
+    pub total_memory: Storage,
+    /// Sorted by `total_memory`, largest first.
+    pub children: Vec<ProcessTreeNode>,
 }
 
 impl Memory {
+    /// Takes a one-off snapshot. Every `growth_rate` is `None`, as there is
+    /// no earlier snapshot to compare with; use a [`Sampler`] for those.
     pub fn get() -> Self {
-        let mut sys = System::new_with_specifics(
-            RefreshKind::nothing()
-                .with_memory(MemoryRefreshKind::everything())
-                .with_processes(
-                    ProcessRefreshKind::nothing()
-                        .with_exe(UpdateKind::Always)
-                        .with_memory()
-                        .with_root(UpdateKind::Always),
-                ),
-        );
-        sys.refresh_all();
-
-        let total_memory = Storage::from_bytes(sys.total_memory());
-        let used_memory = Storage::from_bytes(sys.used_memory());
-        let total_swap = Storage::from_bytes(sys.total_swap());
-        let used_swap = Storage::from_bytes(sys.used_swap());
-        let mut processes = sys
-            .processes()
-            .iter()
-            .map(|(pid, process)| {
-                let raw_memory = process.memory();
-                let memory = Storage::from_bytes(raw_memory);
-                let name = process.name().to_string_lossy().to_string();
-                let exe = process.exe().map(|path| path.to_string_lossy().to_string());
-                let parent = process.parent().map(|pid| pid.as_u32());
-                let root = process
-                    .root()
-                    .map(|path| path.to_string_lossy().to_string());
-                let total_memory = memory.clone();
+        Sampler::new().refresh().clone()
+    }
 
+    /// Builds a snapshot from what a [`MemorySource`](crate::source::MemorySource)
+    /// read, aggregating `total_memory` by `metric`.
+    pub fn from_raw(snapshot: RawSnapshot, metric: MemoryMetric) -> Self {
+        let mut processes = snapshot
+            .processes
+            .into_iter()
+            .map(|process| {
+                let memory = Storage::from_bytes(process.memory);
                 (
-                    pid.as_u32(),
+                    process.pid,
                     ProcessMemoryInfo {
+                        total_memory: memory.clone(),
                         memory,
-                        raw_memory,
-                        name,
-                        exe,
-                        parent,
-                        root,
-                        total_memory,
+                        raw_memory: process.memory,
+                        name: process.name,
+                        exe: process.exe,
+                        parent: process.parent,
+                        root: process.root,
+                        pss: process.pss.map(Storage::from_bytes),
+                        uss: process.uss.map(Storage::from_bytes),
+                        swap: process.swap.map(Storage::from_bytes),
+                        user: process.user,
+                        start_time: process.start_time,
+                        cgroup: process.cgroup,
+                        growth_rate: None,
                     },
                 )
             })
             .collect::<HashMap<u32, ProcessMemoryInfo>>();
 
-        let tree = build_tree(&processes).unwrap();
-
-        aggregate_total_memory(&tree, &mut processes);
+        // Without a hierarchy, totals stay at each process' own memory.
+        if let Ok(tree) = build_tree(&processes) {
+            aggregate_total_memory(&tree, &mut processes, metric);
+        }
 
         Self {
-            total_memory,
-            used_memory,
-            total_swap,
-            used_swap,
+            total_memory: Storage::from_bytes(snapshot.total_memory),
+            used_memory: Storage::from_bytes(snapshot.used_memory),
+            total_swap: Storage::from_bytes(snapshot.total_swap),
+            used_swap: Storage::from_bytes(snapshot.used_swap),
             processes,
         }
     }
 
-    pub fn tree(&self) -> anyhow::Result<Tree<u32, ()>> {
+    /// Sets every `growth_rate` from the change of `metric` since `previous`,
+    /// taken `elapsed` earlier. Processes that were not in `previous`, or
+    /// whose PID was reused since, get no rate.
+    pub fn set_growth_rates(&mut self, previous: &Memory, elapsed: Duration, metric: MemoryMetric) {
+        for (pid, process) in &mut self.processes {
+            process.growth_rate = previous
+                .processes
+                .get(pid)
+                .filter(|before| before.start_time == process.start_time)
+                .and_then(|before| {
+                    StorageRate::between(
+                        &Storage::from_bytes(metric.bytes(before)),
+                        &Storage::from_bytes(metric.bytes(process)),
+                        elapsed,
+                    )
+                });
+        }
+    }
+
+    pub fn tree(&self) -> Result<Tree<u32, ()>, MemoryError> {
         build_tree(&self.processes)
     }
 
+    /// Recomputes every `total_memory` by summing `metric` over each subtree.
+    pub fn aggregate(&mut self, metric: MemoryMetric) -> Result<(), MemoryError> {
+        let tree = self.tree()?;
+        aggregate_total_memory(&tree, &mut self.processes, metric);
+        Ok(())
+    }
+
     pub fn first(&self, n: usize) -> Vec<(u32, ProcessMemoryInfo)> {
         self.processes
             .iter()
             .filter(|(_, process)| matches!(process.parent, None | Some(1)))
-            .sorted_by(|(_, a), (_, b)| b.total_memory.to_bytes().cmp(&a.total_memory.to_bytes()))
+            .sorted_by(|(_, a), (_, b)| b.total_memory.cmp(&a.total_memory))
             .take(n)
             .map(|(pid, process)| (*pid, process.clone()))
             .collect_vec()
     }
+
+    /// Returns the full process hierarchy, one entry per top-level process,
+    /// with each node's own memory measured by `metric`.
+    pub fn process_tree(&self, metric: MemoryMetric) -> Result<Vec<ProcessTreeNode>, MemoryError> {
+        let tree = self.tree()?;
+        Ok(tree_children(
+            &tree,
+            &self.processes,
+            metric,
+            VIRTUAL_ROOT_PID,
+            0,
+        ))
+    }
 }
 
-fn aggregate_total_memory(tree: &Tree<u32, ()>, processes: &mut HashMap<u32, ProcessMemoryInfo>) {
+fn tree_children(
+    tree: &Tree<u32, ()>,
+    processes: &HashMap<u32, ProcessMemoryInfo>,
+    metric: MemoryMetric,
+    pid: u32,
+    depth: u32,
+) -> Vec<ProcessTreeNode> {
+    let children_ids = match tree.get_node_by_id(&pid) {
+        Some(node) => node.get_children_ids().unwrap_or_default(),
+        None => return Vec::new(),
+    };
+
+    children_ids
+        .into_iter()
+        .filter_map(|child_pid| {
+            let process = processes.get(&child_pid)?;
+            Some(ProcessTreeNode {
+                pid: child_pid,
+                name: process.name.clone(),
+                depth,
+                memory: Storage::from_bytes(metric.bytes(process)),
+                total_memory: process.total_memory.clone(),
+                children: tree_children(tree, processes, metric, child_pid, depth + 1),
+            })
+        })
+        .sorted_by(|a, b| b.total_memory.cmp(&a.total_memory).then(a.pid.cmp(&b.pid)))
+        .collect_vec()
+}
+
+fn aggregate_total_memory(
+    tree: &Tree<u32, ()>,
+    processes: &mut HashMap<u32, ProcessMemoryInfo>,
+    metric: MemoryMetric,
+) {
+    for process in processes.values_mut() {
+        process.total_memory = Storage::from_bytes(metric.bytes(process));
+    }
+
     if let Some(root_node) = tree.get_node_by_id(&VIRTUAL_ROOT_PID)
         && let Ok(children) = root_node.get_children_ids()
     {
         for child_pid in children {
-            let _ = calculate_subtree_memory(tree, processes, child_pid);
+            let _ = calculate_subtree_memory(tree, processes, child_pid, metric);
         }
     }
 }
@@ -115,6 +250,7 @@ fn calculate_subtree_memory(
     tree: &Tree<u32, ()>,
     processes: &mut HashMap<u32, ProcessMemoryInfo>,
     pid: u32,
+    metric: MemoryMetric,
 ) -> Storage {
     let node = match tree.get_node_by_id(&pid) {
         Some(n) => n,
@@ -124,19 +260,19 @@ fn calculate_subtree_memory(
     if pid == 1 {
         if let Ok(children_ids) = node.get_children_ids() {
             for child_pid in children_ids {
-                let _ = calculate_subtree_memory(tree, processes, child_pid);
+                let _ = calculate_subtree_memory(tree, processes, child_pid, metric);
             }
         }
         return processes
             .get(&pid)
-            .map(|p| p.memory.clone())
+            .map(|p| Storage::from_bytes(metric.bytes(p)))
             .unwrap_or_else(|| Storage::from_bytes(0));
     }
 
     let children_total = if let Ok(children_ids) = node.get_children_ids() {
         children_ids
             .iter()
-            .map(|child_pid| calculate_subtree_memory(tree, processes, *child_pid))
+            .map(|child_pid| calculate_subtree_memory(tree, processes, *child_pid, metric))
             .fold(Storage::from_bytes(0), |acc, mem| acc + mem)
     } else {
         Storage::from_bytes(0)
@@ -144,7 +280,7 @@ fn calculate_subtree_memory(
 
     let self_memory = processes
         .get(&pid)
-        .map(|p| p.memory.clone())
+        .map(|p| Storage::from_bytes(metric.bytes(p)))
         .unwrap_or_else(|| Storage::from_bytes(0));
 
     let total = &self_memory + &children_total;
@@ -156,66 +292,277 @@ fn calculate_subtree_memory(
     total
 }
 
-fn build_tree(processes: &HashMap<u32, ProcessMemoryInfo>) -> anyhow::Result<Tree<u32, ()>> {
+/// Builds the parent/child hierarchy below a virtual root.
+///
+/// The process table is read while processes come and go, so it is not
+/// always a proper tree. Processes whose parent is missing from the table,
+/// or which are part of a parent cycle, are attached to the root instead of
+/// being dropped. Only failing to create the root itself is an error.
+fn build_tree(processes: &HashMap<u32, ProcessMemoryInfo>) -> Result<Tree<u32, ()>, MemoryError> {
     let mut tree = Tree::new(Some("Process Tree"));
+    tree.add_node(Node::new(VIRTUAL_ROOT_PID, None), None)
+        .map_err(|err| MemoryError::TreeInconsistent {
+            message: err.to_string(),
+        })?;
 
-    tree.add_node(Node::new(VIRTUAL_ROOT_PID, None), None)?;
-
-    let mut pending_children: HashMap<u32, Vec<u32>> = HashMap::new();
-
-    let mut added: HashSet<u32> = HashSet::new();
-
-    for (pid, process) in processes {
+    let mut roots = Vec::new();
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (pid, process) in processes.iter().sorted_by_key(|(pid, _)| **pid) {
+        if *pid == VIRTUAL_ROOT_PID {
+            // Some platforms report a real PID 0, which would clash with the
+            // virtual root; its children are attached to the root instead.
+            continue;
+        }
         match process.parent {
-            None => {
-                tree.add_node(Node::new(*pid, None), Some(&VIRTUAL_ROOT_PID))?;
-                added.insert(*pid);
-
-                process_pending(&mut tree, &mut pending_children, &mut added, *pid)?;
+            Some(parent) if parent != *pid && processes.contains_key(&parent) => {
+                children.entry(parent).or_default().push(*pid);
             }
-            Some(parent_pid) => {
-                if added.contains(&parent_pid) {
-                    tree.add_node(Node::new(*pid, None), Some(&parent_pid))?;
-                    added.insert(*pid);
-
-                    process_pending(&mut tree, &mut pending_children, &mut added, *pid)?;
-                } else {
-                    pending_children.entry(parent_pid).or_default().push(*pid);
-                }
+            _ => roots.push(*pid),
+        }
+    }
+    if let Some(orphans) = children.remove(&VIRTUAL_ROOT_PID) {
+        roots.extend(orphans);
+    }
+
+    let mut added = HashSet::new();
+    attach(
+        &mut tree,
+        &mut children,
+        &mut added,
+        VIRTUAL_ROOT_PID,
+        roots,
+    );
+
+    // Whatever is left is only reachable through a parent cycle. Following
+    // the parents of any of these processes leads into the cycle, which is
+    // then attached to the root at the first process seen twice.
+    while let Some(start) = children
+        .values()
+        .flatten()
+        .filter(|pid| !added.contains(*pid))
+        .min()
+        .copied()
+    {
+        let mut seen = HashSet::new();
+        let mut pid = start;
+        while seen.insert(pid) {
+            match processes.get(&pid).and_then(|process| process.parent) {
+                Some(parent) if !added.contains(&parent) => pid = parent,
+                _ => break,
             }
         }
+        attach(
+            &mut tree,
+            &mut children,
+            &mut added,
+            VIRTUAL_ROOT_PID,
+            vec![pid],
+        );
     }
 
     Ok(tree)
 }
 
-fn process_pending(
+/// Adds `pids` below `parent`, followed by their pending descendants. PIDs
+/// that were already visited are skipped.
+fn attach(
     tree: &mut Tree<u32, ()>,
-    pending_children: &mut HashMap<u32, Vec<u32>>,
+    children: &mut HashMap<u32, Vec<u32>>,
     added: &mut HashSet<u32>,
-    parent_id: u32,
-) -> anyhow::Result<()> {
-    if let Some(children) = pending_children.remove(&parent_id) {
-        for child_id in children {
-            tree.add_node(Node::new(child_id, None), Some(&parent_id))?;
-            added.insert(child_id);
-
-            process_pending(tree, pending_children, added, child_id)?;
+    parent: u32,
+    pids: Vec<u32>,
+) {
+    let mut pending = pids.into_iter().map(|pid| (parent, pid)).collect_vec();
+    while let Some((parent, pid)) = pending.pop() {
+        // A PID counts as visited even if `tree_ds` rejects it, so that
+        // `build_tree` does not retry it forever; its children are then
+        // picked up as if they were part of a cycle.
+        if !added.insert(pid) || tree.add_node(Node::new(pid, None), Some(&parent)).is_err() {
+            continue;
+        }
+        if let Some(grandchildren) = children.remove(&pid) {
+            pending.extend(grandchildren.into_iter().map(|child| (pid, child)));
         }
     }
-    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        source::{RawProcess, RawSnapshot},
+        test_support::{memory, process as raw},
+    };
+
+    #[test]
+    fn test_process_tree() {
+        let memory = memory([
+            raw(1, None, 100),
+            raw(10, Some(1), 200),
+            raw(11, Some(10), 300),
+            raw(12, Some(10), 400),
+            raw(20, None, 50),
+        ]);
+        let roots = memory.process_tree(MemoryMetric::Rss).unwrap();
+
+        assert_eq!(roots.iter().map(|n| n.pid).collect_vec(), vec![1, 20]);
+        assert_eq!(roots[0].depth, 0);
+
+        let node = &roots[0].children[0];
+        assert_eq!(node.pid, 10);
+        assert_eq!(node.depth, 1);
+        assert_eq!(node.memory.to_bytes(), 200);
+        assert_eq!(node.total_memory.to_bytes(), 900);
+        assert_eq!(
+            node.children.iter().map(|n| n.pid).collect_vec(),
+            vec![12, 11]
+        );
+        assert!(node.children.iter().all(|n| n.depth == 2));
+    }
+
+    #[test]
+    fn test_aggregate_by_pss() {
+        let mut memory = memory([
+            RawProcess {
+                pss: Some(400),
+                ..raw(10, None, 1000)
+            },
+            RawProcess {
+                pss: Some(300),
+                ..raw(11, Some(10), 1000)
+            },
+            raw(12, Some(10), 100),
+        ]);
+
+        memory.aggregate(MemoryMetric::Rss).unwrap();
+        assert_eq!(memory.processes[&10].total_memory.to_bytes(), 2100);
+
+        memory.aggregate(MemoryMetric::Pss).unwrap();
+        assert_eq!(memory.processes[&10].total_memory.to_bytes(), 800);
+        assert_eq!(memory.processes[&11].total_memory.to_bytes(), 300);
+        assert_eq!(memory.processes[&12].total_memory.to_bytes(), 100);
+
+        // A node's own memory is measured like its total.
+        let roots = memory.process_tree(MemoryMetric::Pss).unwrap();
+        assert_eq!(roots[0].memory.to_bytes(), 400);
+        assert_eq!(roots[0].total_memory.to_bytes(), 800);
+        assert_eq!(roots[0].children[0].memory.to_bytes(), 300);
+    }
+
+    #[test]
+    fn test_tree_with_orphans_and_cycles() {
+        let memory = memory([
+            raw(1, None, 100),
+            // Parent already exited.
+            raw(10, Some(9), 200),
+            raw(11, Some(10), 300),
+            // Parent of itself.
+            raw(20, Some(20), 400),
+            // Two processes claiming each other as parent, with a child
+            // whose PID is lower than theirs.
+            raw(30, Some(31), 500),
+            raw(31, Some(30), 600),
+            raw(29, Some(31), 700),
+        ]);
+        let roots = memory.process_tree(MemoryMetric::Rss).unwrap();
+        assert_eq!(
+            roots.iter().map(|n| n.pid).sorted().collect_vec(),
+            vec![1, 10, 20, 31]
+        );
+
+        let cycle = roots.iter().find(|n| n.pid == 31).unwrap();
+        assert_eq!(cycle.total_memory.to_bytes(), 1800);
+        assert_eq!(
+            cycle.children.iter().map(|n| n.pid).collect_vec(),
+            vec![29, 30]
+        );
+        assert_eq!(memory.processes[&10].total_memory.to_bytes(), 500);
+    }
+
+    #[test]
+    fn test_from_raw() {
+        let snapshot = RawSnapshot {
+            total_memory: 10_000,
+            used_memory: 5_000,
+            processes: vec![
+                raw(1, None, 100),
+                raw(10, Some(1), 200),
+                raw(11, Some(10), 300),
+                // Parent already exited.
+                raw(20, Some(19), 400),
+                raw(21, Some(20), 500),
+            ],
+            ..RawSnapshot::default()
+        };
+        let memory = Memory::from_raw(snapshot, MemoryMetric::Rss);
+
+        assert_eq!(memory.total_memory.to_bytes(), 10_000);
+        assert_eq!(memory.used_memory.to_bytes(), 5_000);
+        assert_eq!(memory.processes[&10].name, "p10");
+        assert_eq!(memory.processes[&10].total_memory.to_bytes(), 500);
+        assert_eq!(memory.processes[&20].total_memory.to_bytes(), 900);
+        // Init keeps its own memory, since everything is below it.
+        assert_eq!(memory.processes[&1].total_memory.to_bytes(), 100);
+    }
+
+    #[test]
+    fn test_set_growth_rates() {
+        let previous = memory([raw(1, None, 100), raw(10, Some(1), 1000)]);
+        let mut memory = memory([
+            raw(1, None, 100),
+            raw(10, Some(1), 3000),
+            raw(11, Some(1), 50),
+        ]);
+        memory.set_growth_rates(&previous, Duration::from_secs(2), MemoryMetric::Rss);
+
+        assert_eq!(
+            memory.processes[&10].growth_rate,
+            Some(StorageRate::new(1000.0))
+        );
+        assert_eq!(
+            memory.processes[&1].growth_rate,
+            Some(StorageRate::new(0.0))
+        );
+        assert_eq!(memory.processes[&11].growth_rate, None);
+
+        // PID 10 now belongs to a different process.
+        memory.processes.get_mut(&10).unwrap().start_time = 42;
+        memory.set_growth_rates(&previous, Duration::from_secs(2), MemoryMetric::Rss);
+        assert_eq!(memory.processes[&10].growth_rate, None);
+    }
 
     #[test]
     fn test_first() {
-        let info = Memory::get();
-        let first = info.first(10);
-        for (pid, process) in first {
-            println!("{}: {}, {}", pid, process.name, process.total_memory);
-        }
+        let memory = memory([
+            raw(1, None, 100),
+            raw(10, Some(1), 200),
+            raw(11, Some(10), 300),
+            raw(20, Some(1), 600),
+            raw(30, None, 50),
+            // Not a root, despite being the largest process.
+            raw(40, Some(30), 1000),
+        ]);
+
+        let first = memory.first(3);
+        assert_eq!(
+            first
+                .iter()
+                .map(|(pid, process)| (*pid, process.total_memory.to_bytes()))
+                .collect_vec(),
+            vec![(30, 1050), (20, 600), (10, 500)]
+        );
+        assert_eq!(memory.first(10).len(), 4);
+        assert!(memory.first(0).is_empty());
+    }
+
+    #[test]
+    fn test_first_of_cycle() {
+        let memory = memory([raw(30, Some(31), 500), raw(31, Some(30), 600)]);
+
+        // Neither is a root by its parent, but the hierarchy still sums them.
+        assert!(memory.first(10).is_empty());
+        let roots = memory.process_tree(MemoryMetric::Rss).unwrap();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].total_memory.to_bytes(), 1100);
     }
 }
@@ -0,0 +1,292 @@
+use crate::memory::Memory;
+use std::{
+    io,
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Intervals below this are clamped, so a bogus value from Dart cannot turn
+/// the sampling thread into a busy loop.
+pub const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MonitorState {
+    interval: Duration,
+    paused: bool,
+}
+
+/// Shared control block for a push-based sampling loop.
+///
+/// The loop itself runs on its own thread (see [`run`]); the interval and the
+/// paused flag can be changed from any other thread and take effect
+/// immediately, without waiting for the current interval to elapse.
+pub struct MonitorControl {
+    state: Mutex<MonitorState>,
+    changed: Condvar,
+}
+
+impl MonitorControl {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            state: Mutex::new(MonitorState {
+                interval: interval.max(MIN_INTERVAL),
+                paused: false,
+            }),
+            changed: Condvar::new(),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, MonitorState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.state().interval
+    }
+
+    pub fn set_interval(&self, interval: Duration) {
+        self.state().interval = interval.max(MIN_INTERVAL);
+        self.changed.notify_all();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.state().paused
+    }
+
+    pub fn pause(&self) {
+        self.state().paused = true;
+        self.changed.notify_all();
+    }
+
+    pub fn resume(&self) {
+        self.state().paused = false;
+        self.changed.notify_all();
+    }
+
+    /// Blocks until the next sample is due, i.e. the monitor is not paused
+    /// and a full interval has passed since `last`.
+    fn wait_until_due(&self, last: Instant) {
+        let mut state = self.state();
+        loop {
+            if state.paused {
+                state = self
+                    .changed
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner);
+                continue;
+            }
+
+            let elapsed = last.elapsed();
+            if elapsed >= state.interval {
+                return;
+            }
+
+            let timeout = state.interval - elapsed;
+            state = self
+                .changed
+                .wait_timeout(state, timeout)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+}
+
+type Emit = Box<dyn FnMut(Memory) -> bool + Send>;
+
+struct SubscriberState {
+    emit: Option<Emit>,
+    running: bool,
+}
+
+/// The receiver of a sampling loop that outlives its subscribers.
+///
+/// Subscribing again replaces the receiver instead of starting another loop,
+/// so re-subscribing (e.g. after a hot restart in Dart) never leaves several
+/// loops sampling at once.
+pub struct Subscriber {
+    state: Mutex<SubscriberState>,
+}
+
+impl Default for Subscriber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Subscriber {
+    pub const fn new() -> Self {
+        Self {
+            state: Mutex::new(SubscriberState {
+                emit: None,
+                running: false,
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, SubscriberState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Makes `emit` the receiver. If no loop is running, `start` is called to
+    /// spawn one, which must feed its samples to [`Subscriber::emit`].
+    pub fn subscribe(&self, emit: Emit, start: impl FnOnce() -> io::Result<()>) -> io::Result<()> {
+        let mut state = self.state();
+        state.emit = Some(emit);
+        if !state.running {
+            if let Err(err) = start() {
+                state.emit = None;
+                return Err(err);
+            }
+            state.running = true;
+        }
+        Ok(())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.state().running
+    }
+
+    /// Passes `memory` to the current receiver. Returns `false` once the
+    /// receiver is gone, after which the loop must stop.
+    pub fn emit(&self, memory: Memory) -> bool {
+        let mut state = self.state();
+        if let Some(emit) = state.emit.as_mut()
+            && emit(memory)
+        {
+            return true;
+        }
+        state.emit = None;
+        state.running = false;
+        false
+    }
+}
+
+/// Runs a sampling loop on the current thread.
+///
+/// A sample is taken immediately, then once per interval for as long as
+/// `emit` returns `true`. Returning `false` from `emit` (e.g. because the
+/// receiving stream was closed) ends the loop.
+pub fn run(
+    control: &MonitorControl,
+    mut sample: impl FnMut() -> Memory,
+    mut emit: impl FnMut(Memory) -> bool,
+) {
+    loop {
+        let started_at = Instant::now();
+        if !emit(sample()) {
+            return;
+        }
+        control.wait_until_due(started_at);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        thread,
+    };
+
+    fn empty_memory() -> Memory {
+        test_support::memory([])
+    }
+
+    #[test]
+    fn test_run_stops_when_emit_fails() {
+        let control = MonitorControl::new(MIN_INTERVAL);
+        let mut emitted = 0;
+        run(&control, empty_memory, |_| {
+            emitted += 1;
+            emitted < 3
+        });
+        assert_eq!(emitted, 3);
+    }
+
+    #[test]
+    fn test_interval_is_clamped() {
+        let control = MonitorControl::new(Duration::ZERO);
+        assert_eq!(control.interval(), MIN_INTERVAL);
+
+        control.set_interval(Duration::from_secs(2));
+        assert_eq!(control.interval(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        let control = Arc::new(MonitorControl::new(MIN_INTERVAL));
+        let emitted = Arc::new(AtomicUsize::new(0));
+
+        control.pause();
+        let handle = {
+            let control = control.clone();
+            let emitted = emitted.clone();
+            thread::spawn(move || {
+                run(&control, empty_memory, |_| {
+                    emitted.fetch_add(1, Ordering::SeqCst) < 1
+                })
+            })
+        };
+
+        thread::sleep(MIN_INTERVAL * 3);
+        assert_eq!(emitted.load(Ordering::SeqCst), 1);
+        assert!(control.is_paused());
+
+        control.resume();
+        handle.join().unwrap();
+        assert_eq!(emitted.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_subscriber_keeps_one_loop() {
+        let subscriber = Subscriber::new();
+        let received = Arc::new(AtomicUsize::new(0));
+        let mut starts = 0;
+
+        subscriber
+            .subscribe(Box::new(|_| true), || {
+                starts += 1;
+                Ok(())
+            })
+            .unwrap();
+        let counter = received.clone();
+        subscriber
+            .subscribe(
+                Box::new(move |_| counter.fetch_add(1, Ordering::SeqCst) < 1),
+                || {
+                    starts += 1;
+                    Ok(())
+                },
+            )
+            .unwrap();
+        assert_eq!(starts, 1);
+
+        // Only the latest receiver gets samples, until it goes away.
+        assert!(subscriber.emit(empty_memory()));
+        assert!(!subscriber.emit(empty_memory()));
+        assert_eq!(received.load(Ordering::SeqCst), 2);
+        assert!(!subscriber.is_running());
+
+        subscriber
+            .subscribe(Box::new(|_| true), || {
+                starts += 1;
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(starts, 2);
+    }
+
+    #[test]
+    fn test_subscriber_start_failure() {
+        let subscriber = Subscriber::new();
+        let result =
+            subscriber.subscribe(Box::new(|_| true), || Err(io::Error::other("no threads")));
+        assert!(result.is_err());
+        assert!(!subscriber.is_running());
+        assert!(!subscriber.emit(empty_memory()));
+    }
+}
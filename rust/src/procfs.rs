@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+pub(crate) const PROC_ROOT: &str = "/proc";
+
+pub(crate) fn pid_path(pid: u32, file: &str) -> PathBuf {
+    [PROC_ROOT, &pid.to_string(), file].iter().collect()
+}
+
+/// Parses a `Key:   1234 kB` line as found in `smaps`, `smaps_rollup` and
+/// `meminfo`.
+///
+/// Values with a `kB` suffix are converted to bytes, values without one
+/// (e.g. `HugePages_Total`) are returned as-is.
+pub(crate) fn parse_field(line: &str) -> Option<(&str, u64)> {
+    let (key, rest) = line.split_once(':')?;
+    let mut parts = rest.split_whitespace();
+    let value = parts.next()?.parse::<u64>().ok()?;
+    match parts.next() {
+        Some("kB") => Some((key.trim(), value.saturating_mul(1024))),
+        None => Some((key.trim(), value)),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_field() {
+        assert_eq!(
+            parse_field("Rss:                1256 kB"),
+            Some(("Rss", 1256 * 1024))
+        );
+        assert_eq!(
+            parse_field("HugePages_Total:       0"),
+            Some(("HugePages_Total", 0))
+        );
+        assert_eq!(parse_field("VmFlags: rd mr mw me"), None);
+        assert_eq!(
+            parse_field("THPeligible:           0"),
+            Some(("THPeligible", 0))
+        );
+        assert_eq!(parse_field("no separator"), None);
+    }
+}
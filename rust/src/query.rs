@@ -0,0 +1,327 @@
+use crate::{
+    error::MemoryError,
+    memory::{Memory, ProcessMemoryInfo},
+};
+use itertools::Itertools;
+use regex::Regex;
+use std::cmp::Ordering;
+
+/// Conditions a process must meet to be listed. Unset fields match every
+/// process.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessFilter {
+    /// Case-insensitive substring of the name or executable path.
+    pub text: Option<String>,
+    /// Regular expression matched against the name or executable path.
+    pub pattern: Option<String>,
+    /// Minimum of the process' own memory, in bytes.
+    pub min_memory: Option<u64>,
+    /// Only list top-level processes, whose parent is unknown or init.
+    pub roots_only: bool,
+    pub pids: Option<Vec<u32>>,
+    pub user: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProcessSortKey {
+    Memory,
+    #[default]
+    TotalMemory,
+    Name,
+    Pid,
+    StartTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    Ascending,
+    #[default]
+    Descending,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessQuery {
+    pub filter: ProcessFilter,
+    pub sort_key: ProcessSortKey,
+    pub direction: SortDirection,
+    pub offset: u32,
+    /// Maximum number of items in the page; 0 returns everything after
+    /// `offset`.
+    pub limit: u32,
+}
+
+/// One page of a process listing.
+#[derive(Debug, Clone)]
+pub struct ProcessPage {
+    pub items: Vec<(u32, ProcessMemoryInfo)>,
+    /// Number of processes matching the filter, across all pages.
+    pub total: u32,
+}
+
+struct Matcher<'a> {
+    filter: &'a ProcessFilter,
+    text: Option<String>,
+    pattern: Option<Regex>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(filter: &'a ProcessFilter) -> Result<Self, MemoryError> {
+        Ok(Self {
+            filter,
+            text: filter.text.as_ref().map(|text| text.to_lowercase()),
+            pattern: filter.pattern.as_deref().map(Regex::new).transpose()?,
+        })
+    }
+
+    fn matches(&self, pid: u32, process: &ProcessMemoryInfo) -> bool {
+        let filter = self.filter;
+        let fields = || std::iter::once(process.name.as_str()).chain(process.exe.as_deref());
+
+        if filter.roots_only && !matches!(process.parent, None | Some(1)) {
+            return false;
+        }
+        if filter
+            .min_memory
+            .is_some_and(|min| process.raw_memory < min)
+        {
+            return false;
+        }
+        if filter
+            .pids
+            .as_ref()
+            .is_some_and(|pids| !pids.contains(&pid))
+        {
+            return false;
+        }
+        if filter.user.is_some() && filter.user != process.user {
+            return false;
+        }
+        if let Some(text) = &self.text
+            && !fields().any(|field| field.to_lowercase().contains(text))
+        {
+            return false;
+        }
+        if let Some(pattern) = &self.pattern
+            && !fields().any(|field| pattern.is_match(field))
+        {
+            return false;
+        }
+        true
+    }
+}
+
+fn compare(
+    key: ProcessSortKey,
+    (a_pid, a): (u32, &ProcessMemoryInfo),
+    (b_pid, b): (u32, &ProcessMemoryInfo),
+) -> Ordering {
+    let ordering = match key {
+        ProcessSortKey::Memory => a.raw_memory.cmp(&b.raw_memory),
+        ProcessSortKey::TotalMemory => a.total_memory.cmp(&b.total_memory),
+        ProcessSortKey::Name => a.name.cmp(&b.name),
+        ProcessSortKey::Pid => Ordering::Equal,
+        ProcessSortKey::StartTime => a.start_time.cmp(&b.start_time),
+    };
+    // Ties are broken by PID so that pages stay stable between calls.
+    ordering.then(a_pid.cmp(&b_pid))
+}
+
+impl ProcessQuery {
+    pub fn run(&self, memory: &Memory) -> Result<ProcessPage, MemoryError> {
+        let matcher = Matcher::new(&self.filter)?;
+        let matching = memory
+            .processes
+            .iter()
+            .filter(|(pid, process)| matcher.matches(**pid, process))
+            .sorted_by(|(a_pid, a), (b_pid, b)| {
+                let ordering = compare(self.sort_key, (**a_pid, a), (**b_pid, b));
+                match self.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            })
+            .collect_vec();
+
+        let limit = match self.limit {
+            0 => usize::MAX,
+            limit => limit as usize,
+        };
+        let items = matching
+            .iter()
+            .skip(self.offset as usize)
+            .take(limit)
+            .map(|(pid, process)| (**pid, (*process).clone()))
+            .collect_vec();
+
+        Ok(ProcessPage {
+            items,
+            total: matching.len() as u32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{source::RawProcess, test_support};
+
+    fn memory() -> Memory {
+        let processes = [
+            (1, "systemd", None, 10, 0),
+            (100, "Chrome", Some(1), 300, 50),
+            (101, "chrome", Some(100), 200, 60),
+            (102, "chrome", Some(100), 100, 70),
+            (200, "bash", Some(1), 50, 10),
+        ];
+        test_support::memory(
+            processes
+                .into_iter()
+                .map(|(pid, name, parent, bytes, start_time)| RawProcess {
+                    name: name.to_string(),
+                    exe: Some(format!("/usr/bin/{}", name.to_lowercase())),
+                    user: Some(String::from(if pid == 200 { "bob" } else { "root" })),
+                    start_time,
+                    ..test_support::process(pid, parent, bytes)
+                }),
+        )
+    }
+
+    fn pids(page: &ProcessPage) -> Vec<u32> {
+        page.items.iter().map(|(pid, _)| *pid).collect_vec()
+    }
+
+    #[test]
+    fn test_default_query() {
+        let page = ProcessQuery::default().run(&memory()).unwrap();
+        assert_eq!(page.total, 5);
+        // Init keeps its own memory as its total.
+        assert_eq!(pids(&page), vec![100, 101, 102, 200, 1]);
+    }
+
+    #[test]
+    fn test_filters() {
+        let memory = memory();
+        let run = |filter: ProcessFilter| {
+            let query = ProcessQuery {
+                filter,
+                sort_key: ProcessSortKey::Pid,
+                direction: SortDirection::Ascending,
+                ..ProcessQuery::default()
+            };
+            pids(&query.run(&memory).unwrap())
+        };
+
+        let text = Some(String::from("CHROME"));
+        assert_eq!(
+            run(ProcessFilter {
+                text,
+                ..Default::default()
+            }),
+            vec![100, 101, 102]
+        );
+
+        let pattern = Some(String::from("^/usr/bin/(bash|systemd)$"));
+        assert_eq!(
+            run(ProcessFilter {
+                pattern,
+                ..Default::default()
+            }),
+            vec![1, 200]
+        );
+
+        let min_memory = Some(100);
+        assert_eq!(
+            run(ProcessFilter {
+                min_memory,
+                ..Default::default()
+            }),
+            vec![100, 101, 102]
+        );
+
+        assert_eq!(
+            run(ProcessFilter {
+                roots_only: true,
+                ..Default::default()
+            }),
+            vec![1, 100, 200]
+        );
+
+        let pids = Some(vec![101, 200, 999]);
+        assert_eq!(
+            run(ProcessFilter {
+                pids,
+                ..Default::default()
+            }),
+            vec![101, 200]
+        );
+
+        let user = Some(String::from("bob"));
+        assert_eq!(
+            run(ProcessFilter {
+                user,
+                ..Default::default()
+            }),
+            vec![200]
+        );
+
+        let invalid = ProcessQuery {
+            filter: ProcessFilter {
+                pattern: Some(String::from("[")),
+                ..Default::default()
+            },
+            ..ProcessQuery::default()
+        };
+        assert!(invalid.run(&memory).is_err());
+    }
+
+    #[test]
+    fn test_sorting() {
+        let memory = memory();
+        let run = |sort_key, direction| {
+            let query = ProcessQuery {
+                sort_key,
+                direction,
+                ..ProcessQuery::default()
+            };
+            pids(&query.run(&memory).unwrap())
+        };
+
+        assert_eq!(
+            run(ProcessSortKey::Memory, SortDirection::Descending),
+            vec![100, 101, 102, 200, 1]
+        );
+        assert_eq!(
+            run(ProcessSortKey::Name, SortDirection::Ascending),
+            vec![100, 200, 101, 102, 1]
+        );
+        assert_eq!(
+            run(ProcessSortKey::StartTime, SortDirection::Ascending),
+            vec![1, 200, 100, 101, 102]
+        );
+        assert_eq!(
+            run(ProcessSortKey::Pid, SortDirection::Descending),
+            vec![200, 102, 101, 100, 1]
+        );
+    }
+
+    #[test]
+    fn test_pagination() {
+        let memory = memory();
+        let page = |offset, limit| {
+            let query = ProcessQuery {
+                sort_key: ProcessSortKey::Pid,
+                direction: SortDirection::Ascending,
+                offset,
+                limit,
+                ..ProcessQuery::default()
+            };
+            query.run(&memory).unwrap()
+        };
+
+        assert_eq!(pids(&page(0, 2)), vec![1, 100]);
+        assert_eq!(pids(&page(2, 2)), vec![101, 102]);
+        assert_eq!(pids(&page(4, 2)), vec![200]);
+        assert!(page(10, 2).items.is_empty());
+        assert_eq!(page(10, 2).total, 5);
+    }
+}
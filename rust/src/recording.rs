@@ -0,0 +1,281 @@
+use crate::{
+    memory::MemoryMetric,
+    source::{MemorySource, RawSnapshot},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// A raw snapshot and when it was taken, one per line of a recording.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedSnapshot {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    pub snapshot: RawSnapshot,
+}
+
+/// Writes snapshots to a recording file as JSON lines.
+pub struct Recorder {
+    writer: BufWriter<File>,
+    last_timestamp: Option<u64>,
+}
+
+impl Recorder {
+    /// Creates the recording at `path`, replacing any existing file.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            last_timestamp: None,
+        })
+    }
+
+    /// Appends `snapshot`. Snapshots older than the last recorded one are
+    /// skipped, so that the recording can be replayed in file order.
+    pub fn record(&mut self, snapshot: &RawSnapshot, timestamp: u64) -> io::Result<bool> {
+        if self.last_timestamp.is_some_and(|last| timestamp < last) {
+            return Ok(false);
+        }
+
+        let recorded = RecordedSnapshotRef {
+            timestamp,
+            snapshot,
+        };
+        serde_json::to_writer(&mut self.writer, &recorded)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+
+        self.last_timestamp = Some(timestamp);
+        Ok(true)
+    }
+}
+
+/// Borrowing twin of [`RecordedSnapshot`], to record without cloning.
+#[derive(Serialize)]
+struct RecordedSnapshotRef<'a> {
+    timestamp: u64,
+    snapshot: &'a RawSnapshot,
+}
+
+/// Reads every snapshot of the recording at `path`, oldest first.
+pub fn read_recording(path: impl AsRef<Path>) -> io::Result<Vec<RecordedSnapshot>> {
+    let mut snapshots = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        // The last line is broken if recording stopped mid-write.
+        if let Ok(snapshot) = serde_json::from_str::<RecordedSnapshot>(&line) {
+            snapshots.push(snapshot);
+        }
+    }
+    Ok(snapshots)
+}
+
+/// Plays a recording back as a [`MemorySource`].
+///
+/// The recording starts playing on the first read. Every read returns the
+/// last snapshot recorded before the current playback position, which moves
+/// `speed` times as fast as the wall clock. After the end of the recording,
+/// the last snapshot is returned over and over.
+pub struct ReplaySource {
+    snapshots: Vec<RecordedSnapshot>,
+    speed: f64,
+    started_at: Option<Instant>,
+    recorded_at: Option<u64>,
+}
+
+/// Whether a recording can be played back at `speed`.
+pub fn is_valid_speed(speed: f64) -> bool {
+    speed.is_finite() && speed > 0.0
+}
+
+impl ReplaySource {
+    /// `speed` should pass [`is_valid_speed`]. Playback jumps straight to
+    /// the end otherwise.
+    pub fn new(snapshots: Vec<RecordedSnapshot>, speed: f64) -> Self {
+        Self {
+            snapshots,
+            speed,
+            started_at: None,
+            recorded_at: None,
+        }
+    }
+
+    pub fn open(path: impl AsRef<Path>, speed: f64) -> io::Result<Self> {
+        Ok(Self::new(read_recording(path)?, speed))
+    }
+
+    /// Length of the recording, in recorded time.
+    pub fn duration(&self) -> Duration {
+        match (self.snapshots.first(), self.snapshots.last()) {
+            (Some(first), Some(last)) => {
+                Duration::from_millis(last.timestamp.saturating_sub(first.timestamp))
+            }
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Whether playback has reached the last snapshot.
+    pub fn is_finished(&self) -> bool {
+        self.started_at
+            .is_some_and(|started_at| self.position(started_at.elapsed()) >= self.duration())
+    }
+
+    fn position(&self, elapsed: Duration) -> Duration {
+        // `Duration::mul_f64` panics on overflow.
+        Duration::try_from_secs_f64(elapsed.as_secs_f64() * self.speed).unwrap_or(Duration::MAX)
+    }
+
+    /// The snapshot shown `elapsed` wall-clock time after playback started.
+    fn snapshot_at(&self, elapsed: Duration) -> Option<&RecordedSnapshot> {
+        let first = self.snapshots.first()?;
+        let position = u64::try_from(self.position(elapsed).as_millis()).unwrap_or(u64::MAX);
+        let timestamp = first.timestamp.saturating_add(position);
+        let index = self
+            .snapshots
+            .partition_point(|snapshot| snapshot.timestamp <= timestamp);
+        self.snapshots.get(index.saturating_sub(1))
+    }
+}
+
+impl MemorySource for ReplaySource {
+    fn read(&mut self, _metric: MemoryMetric) -> RawSnapshot {
+        let started_at = *self.started_at.get_or_insert_with(Instant::now);
+        let Some(recorded) = self.snapshot_at(started_at.elapsed()) else {
+            return RawSnapshot::default();
+        };
+        let snapshot = recorded.snapshot.clone();
+        self.recorded_at = Some(recorded.timestamp);
+        snapshot
+    }
+
+    fn recorded_at(&self) -> Option<u64> {
+        self.recorded_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{memory::Memory, source::RawProcess};
+
+    fn snapshot(used_memory: u64) -> RawSnapshot {
+        RawSnapshot {
+            total_memory: 1000,
+            used_memory,
+            processes: vec![RawProcess {
+                pid: 1,
+                name: String::from("init"),
+                memory: used_memory / 2,
+                pss: Some(used_memory / 4),
+                ..RawProcess::default()
+            }],
+            ..RawSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn test_record_and_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trace.jsonl");
+
+        let mut recorder = Recorder::create(&path).unwrap();
+        assert!(recorder.record(&snapshot(100), 1000).unwrap());
+        assert!(recorder.record(&snapshot(200), 2000).unwrap());
+        assert!(!recorder.record(&snapshot(150), 1500).unwrap());
+        drop(recorder);
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        file.write_all(b"{\"timestamp\": 3000, \"snap").unwrap();
+
+        let recording = read_recording(&path).unwrap();
+        assert_eq!(
+            recording,
+            vec![
+                RecordedSnapshot {
+                    timestamp: 1000,
+                    snapshot: snapshot(100),
+                },
+                RecordedSnapshot {
+                    timestamp: 2000,
+                    snapshot: snapshot(200),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_replay_position() {
+        let recording = [(1000, 100), (2000, 200), (4000, 400)]
+            .into_iter()
+            .map(|(timestamp, used)| RecordedSnapshot {
+                timestamp,
+                snapshot: snapshot(used),
+            })
+            .collect();
+        let replay = ReplaySource::new(recording, 2.0);
+        assert_eq!(replay.duration(), Duration::from_secs(3));
+
+        let used_at = |ms| {
+            replay
+                .snapshot_at(Duration::from_millis(ms))
+                .unwrap()
+                .snapshot
+                .used_memory
+        };
+        assert_eq!(used_at(0), 100);
+        assert_eq!(used_at(499), 100);
+        assert_eq!(used_at(500), 200);
+        assert_eq!(used_at(1499), 200);
+        assert_eq!(used_at(1500), 400);
+        assert_eq!(used_at(60_000), 400);
+        assert_eq!(used_at(u64::MAX), 400);
+    }
+
+    #[test]
+    fn test_replay_speed() {
+        assert!(is_valid_speed(0.5));
+        for speed in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(!is_valid_speed(speed));
+        }
+
+        let recording = vec![
+            RecordedSnapshot {
+                timestamp: 1000,
+                snapshot: snapshot(100),
+            },
+            RecordedSnapshot {
+                timestamp: 2000,
+                snapshot: snapshot(200),
+            },
+        ];
+        let replay = ReplaySource::new(recording, f64::MAX);
+        let last = replay.snapshot_at(Duration::from_secs(1)).unwrap();
+        assert_eq!(last.timestamp, 2000);
+    }
+
+    #[test]
+    fn test_replay_feeds_memory() {
+        let recording = vec![RecordedSnapshot {
+            timestamp: 1000,
+            snapshot: snapshot(100),
+        }];
+        let mut replay = ReplaySource::new(recording, 1.0);
+        assert!(!replay.is_finished());
+        assert_eq!(replay.recorded_at(), None);
+
+        let memory = Memory::from_raw(replay.read(MemoryMetric::Pss), MemoryMetric::Pss);
+        assert!(replay.is_finished());
+        assert_eq!(replay.recorded_at(), Some(1000));
+        assert_eq!(memory.used_memory.to_bytes(), 100);
+        assert_eq!(memory.processes[&1].total_memory.to_bytes(), 25);
+
+        let mut empty = ReplaySource::new(Vec::new(), 1.0);
+        assert_eq!(empty.read(MemoryMetric::Rss), RawSnapshot::default());
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{
+    history,
+    memory::{Memory, MemoryMetric},
+    source::{MemorySource, SysinfoSource},
+};
+use std::time::{Duration, Instant};
+
+/// Snapshots younger than this are shared between callers instead of
+/// triggering another refresh.
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_millis(500);
+
+/// Long-lived memory sampler.
+///
+/// Keeps its [`MemorySource`] around between samples, and caches the latest
+/// [`Memory`] snapshot so that several consumers polling at the same moment
+/// see identical data.
+pub struct Sampler<S = SysinfoSource> {
+    source: S,
+    snapshot: Option<Snapshot>,
+    max_age: Duration,
+    metric: MemoryMetric,
+}
+
+struct Snapshot {
+    taken_at: Instant,
+    /// Milliseconds since the Unix epoch, as recorded for replays.
+    timestamp: u64,
+    replayed: bool,
+    memory: Memory,
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sampler {
+    pub fn new() -> Self {
+        Self::with_max_age(DEFAULT_MAX_AGE)
+    }
+
+    pub fn with_max_age(max_age: Duration) -> Self {
+        Self::with_source(SysinfoSource::new(), max_age)
+    }
+}
+
+impl<S: MemorySource> Sampler<S> {
+    pub fn with_source(source: S, max_age: Duration) -> Self {
+        Self {
+            source,
+            snapshot: None,
+            max_age,
+            metric: MemoryMetric::default(),
+        }
+    }
+
+    pub fn source_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    /// Switches to reading from `source` and returns the previous one. The
+    /// cached snapshot is dropped, since it came from the old source.
+    pub fn replace_source(&mut self, source: S) -> S {
+        self.snapshot = None;
+        std::mem::replace(&mut self.source, source)
+    }
+
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    pub fn set_max_age(&mut self, max_age: Duration) {
+        self.max_age = max_age;
+    }
+
+    pub fn metric(&self) -> MemoryMetric {
+        self.metric
+    }
+
+    /// Changes the figure `total_memory` is aggregated from. The cached
+    /// snapshot is dropped, since its totals were computed with the old
+    /// metric.
+    pub fn set_metric(&mut self, metric: MemoryMetric) {
+        if self.metric != metric {
+            self.metric = metric;
+            self.snapshot = None;
+        }
+    }
+
+    /// Reads the source and takes a new snapshot, regardless of how old the
+    /// cached one is.
+    ///
+    /// Growth rates are measured against the snapshot this one replaces,
+    /// over the time between their timestamps, so a replay reports the
+    /// rates of the recording rather than of the playback.
+    pub fn refresh(&mut self) -> &Memory {
+        let mut memory = Memory::from_raw(self.source.read(self.metric), self.metric);
+        let recorded_at = self.source.recorded_at();
+        let timestamp = recorded_at.unwrap_or_else(history::now_millis);
+        let replayed = recorded_at.is_some();
+        if let Some(previous) = self.snapshot.as_ref().filter(|p| p.replayed == replayed) {
+            let elapsed = Duration::from_millis(timestamp.saturating_sub(previous.timestamp));
+            memory.set_growth_rates(&previous.memory, elapsed, self.metric);
+        }
+
+        let snapshot = self.snapshot.insert(Snapshot {
+            taken_at: Instant::now(),
+            timestamp,
+            replayed,
+            memory,
+        });
+        &snapshot.memory
+    }
+
+    /// Returns the cached snapshot if it is younger than the configured max
+    /// age, otherwise takes a new one.
+    pub fn snapshot(&mut self) -> &Memory {
+        match &self.snapshot {
+            Some(snapshot) if snapshot.taken_at.elapsed() < self.max_age => {
+                &self.snapshot.as_ref().unwrap().memory
+            }
+            _ => self.refresh(),
+        }
+    }
+
+    /// When the cached snapshot was taken, if there is one.
+    pub fn taken_at(&self) -> Option<Instant> {
+        self.snapshot.as_ref().map(|snapshot| snapshot.taken_at)
+    }
+
+    /// When the cached snapshot was taken, or recorded if it was replayed,
+    /// in milliseconds since the Unix epoch.
+    pub fn timestamp(&self) -> Option<u64> {
+        self.snapshot.as_ref().map(|snapshot| snapshot.timestamp)
+    }
+
+    /// Whether the cached snapshot was played back from a recording.
+    pub fn is_replayed(&self) -> bool {
+        self.snapshot
+            .as_ref()
+            .is_some_and(|snapshot| snapshot.replayed)
+    }
+
+    /// Returns the last snapshot without refreshing, if there is one.
+    pub fn last(&self) -> Option<&Memory> {
+        self.snapshot.as_ref().map(|snapshot| &snapshot.memory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        recording::{RecordedSnapshot, ReplaySource},
+        source::{FixtureSource, RawProcess, RawSnapshot},
+    };
+
+    #[test]
+    fn test_snapshot_is_shared_within_max_age() {
+        let mut sampler = Sampler::with_max_age(Duration::from_secs(60));
+        let first = sampler.snapshot().processes.len();
+        let taken_at = sampler.snapshot.as_ref().unwrap().taken_at;
+
+        let second = sampler.snapshot().processes.len();
+        assert_eq!(first, second);
+        assert_eq!(sampler.snapshot.as_ref().unwrap().taken_at, taken_at);
+    }
+
+    #[test]
+    fn test_fixture_source() {
+        let raw = |used_memory, memory| RawSnapshot {
+            used_memory,
+            processes: vec![RawProcess {
+                pid: 1,
+                memory,
+                ..RawProcess::default()
+            }],
+            ..RawSnapshot::default()
+        };
+        let source = FixtureSource::from_sequence([raw(100, 10), raw(200, 20)]);
+        let mut sampler = Sampler::with_source(source, Duration::from_secs(60));
+
+        assert_eq!(sampler.snapshot().used_memory.to_bytes(), 100);
+        assert_eq!(sampler.snapshot().processes[&1].growth_rate, None);
+        // Still cached.
+        assert_eq!(sampler.snapshot().used_memory.to_bytes(), 100);
+
+        // Growth is measured in whole milliseconds.
+        std::thread::sleep(Duration::from_millis(2));
+        let memory = sampler.refresh();
+        assert_eq!(memory.used_memory.to_bytes(), 200);
+        assert_eq!(memory.processes[&1].total_memory.to_bytes(), 20);
+        assert!(memory.processes[&1].growth_rate.unwrap().bytes_per_second() > 0.0);
+    }
+
+    #[test]
+    fn test_refresh_replaces_snapshot() {
+        let mut sampler = Sampler::with_max_age(Duration::from_secs(60));
+        sampler.snapshot();
+        let taken_at = sampler.snapshot.as_ref().unwrap().taken_at;
+
+        sampler.refresh();
+        assert!(sampler.snapshot.as_ref().unwrap().taken_at > taken_at);
+        assert!(sampler.last().is_some());
+        assert!(!sampler.is_replayed());
+    }
+
+    #[test]
+    fn test_replayed_timestamps() {
+        let recording = [1000, 2000]
+            .map(|timestamp| RecordedSnapshot {
+                timestamp,
+                snapshot: RawSnapshot::default(),
+            })
+            .to_vec();
+        let replay = ReplaySource::new(recording, 1.0);
+        let mut sampler = Sampler::with_source(replay, Duration::from_secs(60));
+        assert_eq!(sampler.timestamp(), None);
+
+        sampler.snapshot();
+        assert_eq!(sampler.timestamp(), Some(1000));
+        assert!(sampler.is_replayed());
+    }
+
+    /// Plays back `snapshots` one per read, whatever the wall clock says.
+    struct SteppedReplay(Vec<RecordedSnapshot>, Option<u64>);
+
+    impl MemorySource for SteppedReplay {
+        fn read(&mut self, _metric: MemoryMetric) -> RawSnapshot {
+            let recorded = self.0.remove(0);
+            self.1 = Some(recorded.timestamp);
+            recorded.snapshot
+        }
+
+        fn recorded_at(&self) -> Option<u64> {
+            self.1
+        }
+    }
+
+    #[test]
+    fn test_replayed_growth_uses_recorded_time() {
+        let recording = [(1000, 1000), (3000, 5000)]
+            .map(|(timestamp, memory)| RecordedSnapshot {
+                timestamp,
+                snapshot: RawSnapshot {
+                    processes: vec![RawProcess {
+                        pid: 1,
+                        memory,
+                        ..RawProcess::default()
+                    }],
+                    ..RawSnapshot::default()
+                },
+            })
+            .to_vec();
+        let mut sampler = Sampler::with_source(SteppedReplay(recording, None), Duration::ZERO);
+        sampler.refresh();
+
+        // 4000 bytes over the 2 recorded seconds, though both reads were
+        // taken almost at once.
+        let rate = sampler.refresh().processes[&1].growth_rate.unwrap();
+        assert_eq!(rate.bytes_per_second(), 2000.0);
+    }
+}
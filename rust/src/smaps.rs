@@ -0,0 +1,381 @@
+use crate::{procfs, utils::Storage};
+use itertools::Itertools;
+use std::{collections::HashMap, io};
+
+/// Per-process memory counters from `/proc/<pid>/smaps_rollup`, in bytes.
+///
+/// Unlike RSS, PSS splits every shared page evenly between the processes
+/// mapping it, and USS only counts pages private to the process, so both can
+/// be summed across a process family without counting shared libraries
+/// once per process.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SmapsRollup {
+    pub rss: u64,
+    pub pss: u64,
+    pub uss: u64,
+    pub swap: u64,
+}
+
+impl SmapsRollup {
+    pub fn parse(text: &str) -> Self {
+        let mut rollup = Self::default();
+        for (key, value) in text.lines().filter_map(procfs::parse_field) {
+            match key {
+                "Rss" => rollup.rss = value,
+                "Pss" => rollup.pss = value,
+                "Private_Clean" | "Private_Dirty" => rollup.uss += value,
+                "Swap" => rollup.swap = value,
+                _ => {}
+            }
+        }
+        rollup
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn read(pid: u32) -> io::Result<Self> {
+        let text = std::fs::read_to_string(procfs::pid_path(pid, "smaps_rollup"))?;
+        Ok(Self::parse(&text))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read(_pid: u32) -> io::Result<Self> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MappingKind {
+    Heap,
+    Stack,
+    Anonymous,
+    File,
+    /// Kernel-provided mappings such as `[vdso]` or `[vvar]`.
+    Special,
+}
+
+/// One entry of `/proc/<pid>/smaps`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryMapping {
+    pub start: u64,
+    pub end: u64,
+    /// Permission string as printed by the kernel, e.g. `r-xp`.
+    pub permissions: String,
+    pub offset: u64,
+    pub kind: MappingKind,
+    /// Backing file or kernel label (e.g. `[heap]`); `None` for plain
+    /// anonymous memory.
+    pub path: Option<String>,
+    pub size: Storage,
+    pub rss: Storage,
+    pub pss: Storage,
+    /// Shared and private dirty pages.
+    pub dirty: Storage,
+    pub swap: Storage,
+}
+
+/// Mappings sharing the same backing file or label, summed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingGroup {
+    /// Backing file, kernel label, or `[anon]` for anonymous memory.
+    pub name: String,
+    pub kind: MappingKind,
+    pub count: u32,
+    pub size: Storage,
+    pub rss: Storage,
+    pub pss: Storage,
+    pub dirty: Storage,
+    pub swap: Storage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessMemoryMaps {
+    pub pid: u32,
+    /// In address order, as listed by the kernel.
+    pub mappings: Vec<MemoryMapping>,
+    /// Sorted by PSS, largest first.
+    pub groups: Vec<MappingGroup>,
+}
+
+const ANONYMOUS_GROUP: &str = "[anon]";
+
+impl MappingKind {
+    fn classify(path: Option<&str>) -> Self {
+        match path {
+            None => MappingKind::Anonymous,
+            Some("[heap]") => MappingKind::Heap,
+            Some(p) if p == "[stack]" || p.starts_with("[stack:") => MappingKind::Stack,
+            Some(p) if p.starts_with("[anon") => MappingKind::Anonymous,
+            Some(p) if p.starts_with('/') => MappingKind::File,
+            Some(_) => MappingKind::Special,
+        }
+    }
+}
+
+/// Counters of a mapping while its block of `smaps` is being read.
+#[derive(Default)]
+struct MappingCounters {
+    size: u64,
+    rss: u64,
+    pss: u64,
+    dirty: u64,
+    swap: u64,
+}
+
+struct MappingHeader {
+    start: u64,
+    end: u64,
+    permissions: String,
+    offset: u64,
+    path: Option<String>,
+}
+
+impl MappingHeader {
+    /// Parses a line like
+    /// `7f93...-7f93... r--p 00000000 fe:00 395379     /usr/lib/libc.so.6`.
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(6, ' ');
+        let (start, end) = fields.next()?.split_once('-')?;
+        let start = u64::from_str_radix(start, 16).ok()?;
+        let end = u64::from_str_radix(end, 16).ok()?;
+        let permissions = fields.next()?.to_string();
+        let offset = u64::from_str_radix(fields.next()?, 16).ok()?;
+        let _device = fields.next()?;
+        let _inode = fields.next()?;
+        let path = fields
+            .next()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(String::from);
+
+        Some(Self {
+            start,
+            end,
+            permissions,
+            offset,
+            path,
+        })
+    }
+
+    fn finish(self, counters: MappingCounters) -> MemoryMapping {
+        MemoryMapping {
+            start: self.start,
+            end: self.end,
+            permissions: self.permissions,
+            offset: self.offset,
+            kind: MappingKind::classify(self.path.as_deref()),
+            path: self.path,
+            size: Storage::from_bytes(counters.size),
+            rss: Storage::from_bytes(counters.rss),
+            pss: Storage::from_bytes(counters.pss),
+            dirty: Storage::from_bytes(counters.dirty),
+            swap: Storage::from_bytes(counters.swap),
+        }
+    }
+}
+
+impl ProcessMemoryMaps {
+    pub fn parse(pid: u32, text: &str) -> Self {
+        let mut mappings = Vec::new();
+        let mut current: Option<(MappingHeader, MappingCounters)> = None;
+
+        for line in text.lines() {
+            if let Some(header) = MappingHeader::parse(line) {
+                if let Some((header, counters)) = current.take() {
+                    mappings.push(header.finish(counters));
+                }
+                current = Some((header, MappingCounters::default()));
+                continue;
+            }
+
+            let (Some((_, counters)), Some((key, value))) =
+                (current.as_mut(), procfs::parse_field(line))
+            else {
+                continue;
+            };
+            match key {
+                "Size" => counters.size = value,
+                "Rss" => counters.rss = value,
+                "Pss" => counters.pss = value,
+                "Shared_Dirty" | "Private_Dirty" => counters.dirty += value,
+                "Swap" => counters.swap = value,
+                _ => {}
+            }
+        }
+        if let Some((header, counters)) = current {
+            mappings.push(header.finish(counters));
+        }
+
+        let groups = group_mappings(&mappings);
+        Self {
+            pid,
+            mappings,
+            groups,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn read(pid: u32) -> io::Result<Self> {
+        let text = std::fs::read_to_string(procfs::pid_path(pid, "smaps"))?;
+        Ok(Self::parse(pid, &text))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read(_pid: u32) -> io::Result<Self> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+fn group_mappings(mappings: &[MemoryMapping]) -> Vec<MappingGroup> {
+    let mut groups: HashMap<(&str, MappingKind), Vec<&MemoryMapping>> = HashMap::new();
+    for mapping in mappings {
+        let name = match mapping.kind {
+            MappingKind::Anonymous => ANONYMOUS_GROUP,
+            _ => mapping.path.as_deref().unwrap_or(ANONYMOUS_GROUP),
+        };
+        groups
+            .entry((name, mapping.kind))
+            .or_default()
+            .push(mapping);
+    }
+
+    let sum = |members: &[&MemoryMapping], field: fn(&MemoryMapping) -> &Storage| {
+        members.iter().map(|m| field(m)).sum::<Storage>()
+    };
+
+    groups
+        .into_iter()
+        .map(|((name, kind), members)| MappingGroup {
+            name: name.to_string(),
+            kind,
+            count: members.len() as u32,
+            size: sum(&members, |m| &m.size),
+            rss: sum(&members, |m| &m.rss),
+            pss: sum(&members, |m| &m.pss),
+            dirty: sum(&members, |m| &m.dirty),
+            swap: sum(&members, |m| &m.swap),
+        })
+        .sorted_by(|a, b| b.pss.cmp(&a.pss).then_with(|| a.name.cmp(&b.name)))
+        .collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROLLUP: &str = "\
+55cae8e8b000-7ffcdcbdb000 ---p 00000000 00:00 0                          [rollup]
+Rss:                1256 kB
+Pss:                 375 kB
+Pss_Dirty:           104 kB
+Shared_Clean:       1112 kB
+Shared_Dirty:          0 kB
+Private_Clean:        40 kB
+Private_Dirty:       104 kB
+Swap:                 12 kB
+SwapPss:               6 kB
+";
+
+    #[test]
+    fn test_parse_rollup() {
+        assert_eq!(
+            SmapsRollup::parse(ROLLUP),
+            SmapsRollup {
+                rss: 1256 * 1024,
+                pss: 375 * 1024,
+                uss: 144 * 1024,
+                swap: 12 * 1024,
+            }
+        );
+    }
+
+    const SMAPS: &str = "\
+562eb1cd0000-562eb1cd2000 r--p 00000000 fe:00 317563                     /usr/bin/cat
+Size:                  8 kB
+Rss:                   8 kB
+Pss:                   8 kB
+Private_Clean:         8 kB
+Swap:                  0 kB
+VmFlags: rd mr mw me
+562eb1cdb000-562eb1cdc000 rw-p 0000a000 fe:00 317563                     /usr/bin/cat
+Size:                  4 kB
+Rss:                   4 kB
+Pss:                   4 kB
+Private_Dirty:         4 kB
+Swap:                  0 kB
+562ebf1b1000-562ebf1d2000 rw-p 00000000 00:00 0                          [heap]
+Size:                132 kB
+Rss:                  20 kB
+Pss:                  20 kB
+Shared_Dirty:          4 kB
+Private_Dirty:        16 kB
+Swap:                  8 kB
+7f930e553000-7f930e578000 rw-p 00000000 00:00 0 
+Size:                148 kB
+Rss:                  12 kB
+Pss:                  12 kB
+Private_Dirty:        12 kB
+Swap:                  0 kB
+7f930e76a000-7f930e76c000 r-xp 00000000 00:00 0                          [vdso]
+Size:                  8 kB
+Rss:                   4 kB
+Pss:                   0 kB
+Swap:                  0 kB
+7ffc558a1000-7ffc558c2000 rw-p 00000000 00:00 0                          [stack]
+Size:                132 kB
+Rss:                  16 kB
+Pss:                  16 kB
+Private_Dirty:        16 kB
+Swap:                  0 kB
+";
+
+    #[test]
+    fn test_parse_mappings() {
+        let maps = ProcessMemoryMaps::parse(42, SMAPS);
+        assert_eq!(maps.pid, 42);
+        assert_eq!(maps.mappings.len(), 6);
+
+        let first = &maps.mappings[0];
+        assert_eq!(first.start, 0x562eb1cd0000);
+        assert_eq!(first.end, 0x562eb1cd2000);
+        assert_eq!(first.permissions, "r--p");
+        assert_eq!(first.kind, MappingKind::File);
+        assert_eq!(first.path.as_deref(), Some("/usr/bin/cat"));
+
+        let heap = &maps.mappings[2];
+        assert_eq!(heap.kind, MappingKind::Heap);
+        assert_eq!(heap.dirty.to_bytes(), 20 * 1024);
+        assert_eq!(heap.swap.to_bytes(), 8 * 1024);
+
+        let anon = &maps.mappings[3];
+        assert_eq!(anon.kind, MappingKind::Anonymous);
+        assert_eq!(anon.path, None);
+
+        assert_eq!(maps.mappings[4].kind, MappingKind::Special);
+        assert_eq!(maps.mappings[5].kind, MappingKind::Stack);
+    }
+
+    #[test]
+    fn test_group_mappings() {
+        let maps = ProcessMemoryMaps::parse(42, SMAPS);
+        let names = maps.groups.iter().map(|g| g.name.as_str()).collect_vec();
+        assert_eq!(
+            names,
+            vec!["[heap]", "[stack]", "/usr/bin/cat", "[anon]", "[vdso]"]
+        );
+
+        let cat = &maps.groups[2];
+        assert_eq!(cat.kind, MappingKind::File);
+        assert_eq!(cat.count, 2);
+        assert_eq!(cat.rss.to_bytes(), 12 * 1024);
+        assert_eq!(cat.dirty.to_bytes(), 4 * 1024);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_read_own_rollup() {
+        let rollup = SmapsRollup::read(std::process::id()).unwrap();
+        assert!(rollup.rss > 0);
+        assert!(rollup.pss <= rollup.rss);
+        assert!(rollup.uss <= rollup.rss);
+    }
+}
@@ -0,0 +1,363 @@
+use crate::{
+    cgroup,
+    memory::{Memory, MemoryMetric},
+    smaps::SmapsRollup,
+};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use sysinfo::{
+    MemoryRefreshKind, Pid, ProcessRefreshKind, ProcessesToUpdate, RefreshKind, System, UpdateKind,
+    Users,
+};
+
+/// A process as read from a [`MemorySource`], in bytes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RawProcess {
+    pub pid: u32,
+    pub name: String,
+    pub exe: Option<String>,
+    pub parent: Option<u32>,
+    pub root: Option<String>,
+    /// Resident set size.
+    pub memory: u64,
+    pub pss: Option<u64>,
+    pub uss: Option<u64>,
+    pub swap: Option<u64>,
+    pub user: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub start_time: u64,
+    pub cgroup: Option<String>,
+}
+
+/// System-wide counters and the process table at one point in time, in
+/// bytes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RawSnapshot {
+    pub total_memory: u64,
+    pub used_memory: u64,
+    pub total_swap: u64,
+    pub used_swap: u64,
+    pub processes: Vec<RawProcess>,
+}
+
+impl From<&Memory> for RawSnapshot {
+    /// Recovers what was read from the source; aggregated totals are
+    /// dropped, since [`Memory::from_raw`] recomputes them.
+    fn from(memory: &Memory) -> Self {
+        let processes = memory
+            .processes
+            .iter()
+            .map(|(pid, process)| RawProcess {
+                pid: *pid,
+                name: process.name.clone(),
+                exe: process.exe.clone(),
+                parent: process.parent,
+                root: process.root.clone(),
+                memory: process.raw_memory,
+                pss: process.pss.as_ref().map(|pss| pss.to_bytes()),
+                uss: process.uss.as_ref().map(|uss| uss.to_bytes()),
+                swap: process.swap.as_ref().map(|swap| swap.to_bytes()),
+                user: process.user.clone(),
+                start_time: process.start_time,
+                cgroup: process.cgroup.clone(),
+            })
+            .sorted_by_key(|process| process.pid)
+            .collect_vec();
+
+        Self {
+            total_memory: memory.total_memory.to_bytes(),
+            used_memory: memory.used_memory.to_bytes(),
+            total_swap: memory.total_swap.to_bytes(),
+            used_swap: memory.used_swap.to_bytes(),
+            processes,
+        }
+    }
+}
+
+/// Where [`Sampler`](crate::sampler::Sampler) reads memory usage from.
+pub trait MemorySource {
+    /// Reads the current state of the system. PSS, USS and swap only have
+    /// to be filled in when `metric` needs them.
+    fn read(&mut self, metric: MemoryMetric) -> RawSnapshot;
+
+    /// When the snapshot last read was recorded, in milliseconds since the
+    /// Unix epoch, for sources that play back a recording. Live sources
+    /// return `None`.
+    fn recorded_at(&self) -> Option<u64> {
+        None
+    }
+}
+
+impl<S: MemorySource + ?Sized> MemorySource for Box<S> {
+    fn read(&mut self, metric: MemoryMetric) -> RawSnapshot {
+        (**self).read(metric)
+    }
+
+    fn recorded_at(&self) -> Option<u64> {
+        (**self).recorded_at()
+    }
+}
+
+/// Reads the live system through sysinfo.
+///
+/// Keeps a single [`System`] around so that sysinfo only has to refresh the
+/// data that actually changes between reads (memory counters and the
+/// process table).
+pub struct SysinfoSource {
+    system: System,
+    users: Users,
+    /// Cgroup of each process by PID, along with the start time of the
+    /// process it was read for.
+    cgroups: HashMap<u32, (u64, Option<String>)>,
+}
+
+impl Default for SysinfoSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SysinfoSource {
+    pub fn new() -> Self {
+        let system = System::new_with_specifics(
+            RefreshKind::nothing()
+                .with_memory(MemoryRefreshKind::everything())
+                .with_processes(process_refresh_kind()),
+        );
+
+        Self {
+            system,
+            users: Users::new_with_refreshed_list(),
+            cgroups: HashMap::new(),
+        }
+    }
+}
+
+impl MemorySource for SysinfoSource {
+    fn read(&mut self, metric: MemoryMetric) -> RawSnapshot {
+        self.system
+            .refresh_memory_specifics(MemoryRefreshKind::everything());
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            process_refresh_kind(),
+        );
+
+        // Only re-read the user database when a process is owned by a user
+        // that was not known yet.
+        let unknown_user = self.system.processes().values().any(|process| {
+            process
+                .user_id()
+                .is_some_and(|uid| self.users.get_user_by_id(uid).is_none())
+        });
+        if unknown_user {
+            self.users.refresh();
+        }
+
+        // Processes rarely change cgroups, so only read the cgroup of new
+        // processes.
+        let processes = self.system.processes();
+        self.cgroups.retain(|pid, (start_time, _)| {
+            processes
+                .get(&Pid::from_u32(*pid))
+                .is_some_and(|process| process.start_time() == *start_time)
+        });
+        for (pid, process) in processes {
+            self.cgroups.entry(pid.as_u32()).or_insert_with(|| {
+                let cgroup = cgroup::read_process_cgroup(pid.as_u32()).ok().flatten();
+                (process.start_time(), cgroup)
+            });
+        }
+
+        let processes = self
+            .system
+            .processes()
+            .iter()
+            .map(|(pid, process)| {
+                let pid = pid.as_u32();
+                let rollup = if metric.needs_smaps() {
+                    SmapsRollup::read(pid).ok()
+                } else {
+                    None
+                };
+
+                RawProcess {
+                    pid,
+                    name: process.name().to_string_lossy().to_string(),
+                    exe: process.exe().map(|path| path.to_string_lossy().to_string()),
+                    parent: process.parent().map(|pid| pid.as_u32()),
+                    root: process
+                        .root()
+                        .map(|path| path.to_string_lossy().to_string()),
+                    memory: process.memory(),
+                    pss: rollup.as_ref().map(|r| r.pss),
+                    uss: rollup.as_ref().map(|r| r.uss),
+                    swap: rollup.as_ref().map(|r| r.swap),
+                    user: process
+                        .user_id()
+                        .and_then(|uid| self.users.get_user_by_id(uid))
+                        .map(|user| user.name().to_string()),
+                    start_time: process.start_time(),
+                    cgroup: self
+                        .cgroups
+                        .get(&pid)
+                        .and_then(|(_, cgroup)| cgroup.clone()),
+                }
+            })
+            .collect();
+
+        RawSnapshot {
+            total_memory: self.system.total_memory(),
+            used_memory: self.system.used_memory(),
+            total_swap: self.system.total_swap(),
+            used_swap: self.system.used_swap(),
+            processes,
+        }
+    }
+}
+
+fn process_refresh_kind() -> ProcessRefreshKind {
+    // The executable path and root directory of a live process do not
+    // change, so only read them the first time a PID is seen.
+    ProcessRefreshKind::nothing()
+        .with_exe(UpdateKind::OnlyIfNotSet)
+        .with_memory()
+        .with_root(UpdateKind::OnlyIfNotSet)
+        .with_user(UpdateKind::OnlyIfNotSet)
+}
+
+/// Serves predefined snapshots, for tests.
+///
+/// Every read returns the next queued snapshot; once only one is left, it is
+/// returned over and over.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureSource {
+    snapshots: VecDeque<RawSnapshot>,
+}
+
+impl FixtureSource {
+    pub fn new(snapshot: RawSnapshot) -> Self {
+        Self::from_sequence([snapshot])
+    }
+
+    pub fn from_sequence(snapshots: impl IntoIterator<Item = RawSnapshot>) -> Self {
+        Self {
+            snapshots: snapshots.into_iter().collect(),
+        }
+    }
+
+    pub fn push(&mut self, snapshot: RawSnapshot) {
+        self.snapshots.push_back(snapshot);
+    }
+}
+
+impl MemorySource for FixtureSource {
+    fn read(&mut self, _metric: MemoryMetric) -> RawSnapshot {
+        if self.snapshots.len() > 1 {
+            self.snapshots.pop_front().unwrap_or_default()
+        } else {
+            self.snapshots.front().cloned().unwrap_or_default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(used_memory: u64) -> RawSnapshot {
+        RawSnapshot {
+            used_memory,
+            ..RawSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn test_fixture_sequence() {
+        let mut source = FixtureSource::from_sequence([snapshot(1), snapshot(2)]);
+        assert_eq!(source.read(MemoryMetric::Rss).used_memory, 1);
+        assert_eq!(source.read(MemoryMetric::Rss).used_memory, 2);
+        assert_eq!(source.read(MemoryMetric::Rss).used_memory, 2);
+
+        source.push(snapshot(3));
+        assert_eq!(source.read(MemoryMetric::Rss).used_memory, 2);
+        assert_eq!(source.read(MemoryMetric::Rss).used_memory, 3);
+
+        assert_eq!(
+            FixtureSource::default().read(MemoryMetric::Rss),
+            RawSnapshot::default()
+        );
+    }
+
+    #[test]
+    fn test_round_trip_through_memory() {
+        let raw = RawSnapshot {
+            total_memory: 1000,
+            used_memory: 500,
+            total_swap: 100,
+            used_swap: 10,
+            processes: vec![
+                RawProcess {
+                    pid: 1,
+                    name: String::from("init"),
+                    memory: 100,
+                    pss: Some(50),
+                    uss: Some(40),
+                    swap: Some(0),
+                    ..RawProcess::default()
+                },
+                RawProcess {
+                    pid: 2,
+                    name: String::from("shell"),
+                    exe: Some(String::from("/bin/sh")),
+                    parent: Some(1),
+                    memory: 200,
+                    user: Some(String::from("root")),
+                    start_time: 42,
+                    cgroup: Some(String::from("/user.slice")),
+                    ..RawProcess::default()
+                },
+            ],
+        };
+
+        let memory = Memory::from_raw(raw.clone(), MemoryMetric::Pss);
+        assert_eq!(RawSnapshot::from(&memory), raw);
+    }
+
+    #[test]
+    fn test_sysinfo_source() {
+        let snapshot = SysinfoSource::new().read(MemoryMetric::Rss);
+        assert!(snapshot.total_memory > 0);
+        let own = snapshot
+            .processes
+            .iter()
+            .find(|process| process.pid == std::process::id())
+            .unwrap();
+        assert!(own.memory > 0);
+        assert!(own.pss.is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_sysinfo_source_caches_cgroups() {
+        let mut source = SysinfoSource::new();
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let snapshot = source.read(MemoryMetric::Rss);
+        let child_start = snapshot
+            .processes
+            .iter()
+            .find(|process| process.pid == child.id())
+            .unwrap()
+            .start_time;
+        assert_eq!(source.cgroups[&child.id()].0, child_start);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+        source.read(MemoryMetric::Rss);
+        assert!(!source.cgroups.contains_key(&child.id()));
+    }
+}
@@ -0,0 +1,599 @@
+use crate::history::{History, HistoryBucket, HistorySeries, ProcessSample, Sample};
+use anyhow::Context;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{self, AtomicU64},
+};
+
+const MINUTE_MS: u64 = 60 * 1000;
+const HOUR_MS: u64 = 60 * MINUTE_MS;
+const DAY_MS: u64 = 24 * HOUR_MS;
+
+/// How long stored samples are kept, and at which resolution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetentionPolicy {
+    /// Samples older than this are deleted on compaction.
+    pub max_age_ms: u64,
+    /// Samples younger than this are kept at the resolution they were
+    /// recorded at.
+    pub full_resolution_ms: u64,
+    /// Older samples are thinned out to one per this many milliseconds.
+    pub compacted_interval_ms: u64,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            max_age_ms: 7 * DAY_MS,
+            full_resolution_ms: DAY_MS,
+            compacted_interval_ms: MINUTE_MS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistoryStoreOptions {
+    /// Minimum time between two stored samples; samples arriving faster are
+    /// skipped.
+    pub record_interval_ms: u64,
+    /// How many processes, largest `total_memory` first, are stored with
+    /// each sample.
+    pub top_processes: u32,
+    /// How often the store should be compacted while recording, see
+    /// [`HistoryStore::compaction_due`].
+    pub compaction_interval_ms: u64,
+    pub retention: RetentionPolicy,
+}
+
+impl Default for HistoryStoreOptions {
+    fn default() -> Self {
+        Self {
+            record_interval_ms: 10 * 1000,
+            top_processes: 20,
+            compaction_interval_ms: HOUR_MS,
+            retention: RetentionPolicy::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CompactionStats {
+    pub kept: u64,
+    pub removed: u64,
+}
+
+/// On-disk representation of a [`Sample`], one JSON object per line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct StoredSample {
+    timestamp: u64,
+    total_memory: u64,
+    used_memory: u64,
+    total_swap: u64,
+    used_swap: u64,
+    processes: Vec<StoredProcess>,
+    #[serde(default)]
+    truncated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct StoredProcess {
+    pid: u32,
+    #[serde(default)]
+    start_time: u64,
+    name: String,
+    memory: u64,
+    total_memory: u64,
+}
+
+impl StoredSample {
+    fn new(sample: &Sample, top_processes: usize, name: impl Fn(u32) -> Option<String>) -> Self {
+        let processes = sample
+            .processes
+            .iter()
+            .sorted_by(|a, b| b.total_memory.cmp(&a.total_memory))
+            .take(top_processes)
+            .map(|process| StoredProcess {
+                pid: process.pid,
+                start_time: process.start_time,
+                name: name(process.pid).unwrap_or_default(),
+                memory: process.memory,
+                total_memory: process.total_memory,
+            })
+            .collect_vec();
+
+        Self {
+            timestamp: sample.timestamp,
+            total_memory: sample.total_memory,
+            used_memory: sample.used_memory,
+            total_swap: sample.total_swap,
+            used_swap: sample.used_swap,
+            truncated: sample.truncated || sample.processes.len() > processes.len(),
+            processes,
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Sample {
+            timestamp: self.timestamp,
+            total_memory: self.total_memory,
+            used_memory: self.used_memory,
+            total_swap: self.total_swap,
+            used_swap: self.used_swap,
+            processes: self
+                .processes
+                .into_iter()
+                .map(|process| ProcessSample {
+                    pid: process.pid,
+                    start_time: process.start_time,
+                    memory: process.memory,
+                    total_memory: process.total_memory,
+                })
+                .sorted_by_key(|process| process.pid)
+                .collect_vec(),
+            truncated: self.truncated,
+        }
+    }
+}
+
+/// Append-only, line-delimited JSON store for memory samples, so history
+/// survives app restarts.
+///
+/// Only the largest processes of each sample are kept, and samples are
+/// rate-limited, to keep the file small enough to cover days of history.
+/// Compaction rewrites the file according to the [`RetentionPolicy`].
+pub struct HistoryStore {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    options: HistoryStoreOptions,
+    last_recorded: Option<u64>,
+    last_compacted: u64,
+    /// Unique across all stores of this process, and replaced whenever the
+    /// file is rewritten, so compactions prepared on an earlier store or
+    /// file cannot be applied.
+    generation: u64,
+}
+
+impl HistoryStore {
+    /// Opens (or creates) the store at `path` and compacts it.
+    pub fn open(
+        path: impl AsRef<Path>,
+        options: HistoryStoreOptions,
+        now: u64,
+    ) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+
+        let mut store = Self {
+            writer: open_append(&path)?,
+            path,
+            options,
+            last_recorded: None,
+            last_compacted: now,
+            generation: next_id(),
+        };
+        store.compact(now)?;
+        store.last_recorded = store.reader().read_all()?.last().map(|s| s.timestamp);
+        Ok(store)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn options(&self) -> &HistoryStoreOptions {
+        &self.options
+    }
+
+    /// A handle for reading the stored samples without borrowing the store.
+    pub fn reader(&self) -> HistoryStoreReader {
+        HistoryStoreReader {
+            path: self.path.clone(),
+        }
+    }
+
+    /// Appends `sample` unless one was stored less than the record interval
+    /// ago.
+    ///
+    /// Returns whether the sample was written.
+    pub fn record(&mut self, sample: &Sample, history: &History) -> anyhow::Result<bool> {
+        if self.last_recorded.is_some_and(|last| {
+            sample.timestamp < last.saturating_add(self.options.record_interval_ms)
+        }) {
+            return Ok(false);
+        }
+
+        let stored = StoredSample::new(sample, self.options.top_processes as usize, |pid| {
+            history.name(pid).map(String::from)
+        });
+        serde_json::to_writer(&mut self.writer, &stored)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+
+        self.last_recorded = Some(sample.timestamp);
+        Ok(true)
+    }
+
+    /// Whether the compaction interval has passed since the last compaction
+    /// was started.
+    pub fn compaction_due(&self, now: u64) -> bool {
+        now >= self
+            .last_compacted
+            .saturating_add(self.options.compaction_interval_ms)
+    }
+
+    /// Loads the stored samples with `start <= timestamp < end` into a
+    /// [`History`], oldest first.
+    pub fn load(&self, start: u64, end: u64) -> anyhow::Result<History> {
+        self.reader().load(start, end)
+    }
+
+    /// Same as [`History::query`], over the stored samples.
+    pub fn query(
+        &self,
+        series: HistorySeries,
+        start: u64,
+        end: u64,
+        resolution: u64,
+    ) -> anyhow::Result<Vec<HistoryBucket>> {
+        self.reader().query(series, start, end, resolution)
+    }
+
+    /// Drops samples past the retention period, thins out samples older
+    /// than the full-resolution window, and rewrites the file.
+    pub fn compact(&mut self, now: u64) -> anyhow::Result<CompactionStats> {
+        let prepared = self.begin_compaction(now)?.run()?;
+        Ok(self.finish_compaction(prepared)?.unwrap_or_default())
+    }
+
+    /// Starts a compaction of the samples stored so far. The returned
+    /// [`PendingCompaction`] can run on another thread while recording
+    /// goes on, and is applied with [`HistoryStore::finish_compaction`].
+    pub fn begin_compaction(&mut self, now: u64) -> anyhow::Result<PendingCompaction> {
+        self.writer.flush()?;
+        let len = fs::metadata(&self.path)
+            .with_context(|| format!("failed to read {}", self.path.display()))?
+            .len();
+        self.last_compacted = now;
+
+        Ok(PendingCompaction {
+            path: self.path.clone(),
+            tmp_path: self
+                .path
+                .with_extension(format!("compacting-{}", next_id())),
+            len,
+            generation: self.generation,
+            now,
+            retention: self.options.retention,
+        })
+    }
+
+    /// Replaces the file with the compacted one, keeping samples recorded
+    /// since the compaction began.
+    ///
+    /// Returns `None`, and discards `prepared`, if it was begun on another
+    /// store, or the file was rewritten by another compaction in the
+    /// meantime.
+    pub fn finish_compaction(
+        &mut self,
+        prepared: PreparedCompaction,
+    ) -> anyhow::Result<Option<CompactionStats>> {
+        if prepared.generation != self.generation {
+            let _ = fs::remove_file(&prepared.tmp_path);
+            return Ok(None);
+        }
+
+        self.writer.flush()?;
+        let mut recent = File::open(&self.path)
+            .with_context(|| format!("failed to open {}", self.path.display()))?;
+        recent.seek(SeekFrom::Start(prepared.len))?;
+        let mut tmp = OpenOptions::new()
+            .append(true)
+            .open(&prepared.tmp_path)
+            .with_context(|| format!("failed to open {}", prepared.tmp_path.display()))?;
+        io::copy(&mut recent, &mut tmp)?;
+        drop(tmp);
+
+        fs::rename(&prepared.tmp_path, &self.path)
+            .with_context(|| format!("failed to replace {}", self.path.display()))?;
+        self.writer = open_append(&self.path)?;
+        self.generation = next_id();
+        Ok(Some(prepared.stats))
+    }
+}
+
+/// Hands out store generations and temporary file names.
+fn next_id() -> u64 {
+    static IDS: AtomicU64 = AtomicU64::new(0);
+    IDS.fetch_add(1, atomic::Ordering::Relaxed)
+}
+
+/// See [`HistoryStore::begin_compaction`].
+pub struct PendingCompaction {
+    path: PathBuf,
+    tmp_path: PathBuf,
+    /// Length of the file when the compaction began.
+    len: u64,
+    generation: u64,
+    now: u64,
+    retention: RetentionPolicy,
+}
+
+impl PendingCompaction {
+    /// Reads the samples stored before the compaction began and writes the
+    /// ones to keep to a temporary file.
+    pub fn run(self) -> anyhow::Result<PreparedCompaction> {
+        let retention = self.retention;
+        let oldest = self.now.saturating_sub(retention.max_age_ms);
+        let full_resolution_since = self.now.saturating_sub(retention.full_resolution_ms);
+        let interval = retention.compacted_interval_ms.max(1);
+
+        let samples = read_samples(&self.path, self.len)?;
+        let total = samples.len() as u64;
+
+        let mut last_slot = None;
+        let kept = samples
+            .into_iter()
+            .filter(|s| s.timestamp >= oldest)
+            .sorted_by_key(|s| s.timestamp)
+            .filter(|s| {
+                if s.timestamp >= full_resolution_since {
+                    return true;
+                }
+                let slot = s.timestamp / interval;
+                last_slot.replace(slot) != Some(slot)
+            })
+            .collect_vec();
+
+        let mut writer = BufWriter::new(
+            File::create(&self.tmp_path)
+                .with_context(|| format!("failed to create {}", self.tmp_path.display()))?,
+        );
+        for sample in &kept {
+            serde_json::to_writer(&mut writer, sample)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+
+        Ok(PreparedCompaction {
+            tmp_path: self.tmp_path,
+            len: self.len,
+            generation: self.generation,
+            stats: CompactionStats {
+                kept: kept.len() as u64,
+                removed: total - kept.len() as u64,
+            },
+        })
+    }
+}
+
+/// A compacted copy of the store, see [`HistoryStore::finish_compaction`].
+pub struct PreparedCompaction {
+    tmp_path: PathBuf,
+    len: u64,
+    generation: u64,
+    stats: CompactionStats,
+}
+
+/// Reads a [`HistoryStore`]'s file, see [`HistoryStore::reader`].
+#[derive(Debug, Clone)]
+pub struct HistoryStoreReader {
+    path: PathBuf,
+}
+
+impl HistoryStoreReader {
+    fn read_all(&self) -> anyhow::Result<Vec<StoredSample>> {
+        read_samples(&self.path, u64::MAX)
+    }
+
+    /// See [`HistoryStore::load`].
+    pub fn load(&self, start: u64, end: u64) -> anyhow::Result<History> {
+        let samples = self
+            .read_all()?
+            .into_iter()
+            .filter(|s| (start..end).contains(&s.timestamp))
+            .sorted_by_key(|s| s.timestamp)
+            .collect_vec();
+
+        let mut history = History::new(samples.len());
+        for stored in samples {
+            for process in &stored.processes {
+                history.set_name(process.pid, &process.name);
+            }
+            history.record(stored.into_sample());
+        }
+        Ok(history)
+    }
+
+    /// See [`HistoryStore::query`].
+    pub fn query(
+        &self,
+        series: HistorySeries,
+        start: u64,
+        end: u64,
+        resolution: u64,
+    ) -> anyhow::Result<Vec<HistoryBucket>> {
+        Ok(self.load(start, end)?.query(series, start, end, resolution))
+    }
+}
+
+/// Reads the samples in the first `len` bytes of `path`.
+fn read_samples(path: &Path, len: u64) -> anyhow::Result<Vec<StoredSample>> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+
+    let mut samples = Vec::new();
+    for line in BufReader::new(file.take(len)).lines() {
+        let line = line?;
+        // Skip the line the app may have died writing.
+        if let Ok(sample) = serde_json::from_str::<StoredSample>(&line) {
+            samples.push(sample);
+        }
+    }
+    Ok(samples)
+}
+
+fn open_append(path: &Path) -> anyhow::Result<BufWriter<File>> {
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    Ok(BufWriter::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample;
+
+    fn options() -> HistoryStoreOptions {
+        HistoryStoreOptions {
+            record_interval_ms: 1000,
+            top_processes: 2,
+            compaction_interval_ms: u64::MAX,
+            retention: RetentionPolicy {
+                max_age_ms: 100_000,
+                full_resolution_ms: 10_000,
+                compacted_interval_ms: 5000,
+            },
+        }
+    }
+
+    #[test]
+    fn test_record_and_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let mut history = History::default();
+        history.set_name(3, "big");
+
+        let mut store = HistoryStore::open(&path, options(), 0).unwrap();
+        assert!(
+            store
+                .record(&sample(0, 0, &[(1, 10), (2, 20), (3, 30)]), &history)
+                .unwrap()
+        );
+        assert!(!store.record(&sample(500, 500, &[]), &history).unwrap());
+        assert!(
+            store
+                .record(&sample(1000, 1000, &[(1, 10)]), &history)
+                .unwrap()
+        );
+        drop(store);
+
+        let store = HistoryStore::open(&path, options(), 1000).unwrap();
+        let loaded = store.load(0, u64::MAX).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.name(3), Some("big"));
+
+        let first = loaded.nearest(0).unwrap();
+        assert_eq!(
+            first.processes.iter().map(|p| p.pid).collect_vec(),
+            vec![2, 3]
+        );
+
+        let buckets = store
+            .query(HistorySeries::UsedMemory, 0, u64::MAX, 0)
+            .unwrap();
+        assert_eq!(buckets.iter().map(|b| b.avg).collect_vec(), vec![0, 1000]);
+    }
+
+    #[test]
+    fn test_compaction() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let history = History::default();
+
+        let mut store = HistoryStore::open(&path, options(), 0).unwrap();
+        for timestamp in (0..=200_000).step_by(1000) {
+            store
+                .record(&sample(timestamp, timestamp, &[]), &history)
+                .unwrap();
+        }
+
+        let stats = store.compact(200_000).unwrap();
+        let loaded = store.load(0, u64::MAX).unwrap();
+        assert_eq!(stats.kept, loaded.len() as u64);
+        assert_eq!(stats.kept + stats.removed, 201);
+
+        let timestamps = loaded.range(0, u64::MAX).map(|s| s.timestamp).collect_vec();
+        assert_eq!(timestamps.first(), Some(&100_000));
+        // One sample per 5s slot up to the full-resolution window...
+        assert!(timestamps.contains(&185_000));
+        assert!(!timestamps.contains(&186_000));
+        // ...and every sample within it.
+        assert!(
+            (190_000..=200_000)
+                .step_by(1000)
+                .all(|t| timestamps.contains(&t))
+        );
+    }
+
+    #[test]
+    fn test_broken_lines_are_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let history = History::default();
+
+        let mut store = HistoryStore::open(&path, options(), 0).unwrap();
+        store.record(&sample(0, 0, &[]), &history).unwrap();
+        drop(store);
+
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"timestamp\": 10").unwrap();
+        drop(file);
+
+        let store = HistoryStore::open(&path, options(), 0).unwrap();
+        assert_eq!(store.load(0, u64::MAX).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_recording_during_compaction() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let history = History::default();
+
+        let mut store = HistoryStore::open(&path, options(), 0).unwrap();
+        for timestamp in (0..=200_000).step_by(1000) {
+            store
+                .record(&sample(timestamp, timestamp, &[]), &history)
+                .unwrap();
+        }
+
+        let pending = store.begin_compaction(200_000).unwrap();
+        store
+            .record(&sample(201_000, 201_000, &[]), &history)
+            .unwrap();
+        let prepared = pending.run().unwrap();
+        store
+            .record(&sample(202_000, 202_000, &[]), &history)
+            .unwrap();
+        let stats = store.finish_compaction(prepared).unwrap().unwrap();
+
+        let loaded = store.load(0, u64::MAX).unwrap();
+        assert_eq!(loaded.len() as u64, stats.kept + 2);
+        assert_eq!(loaded.latest().unwrap().timestamp, 202_000);
+
+        // A compaction that began before the file was rewritten is dropped.
+        let stale = store.begin_compaction(202_000).unwrap().run().unwrap();
+        store.compact(202_000).unwrap();
+        assert!(store.finish_compaction(stale).unwrap().is_none());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        // Nor is one begun on a store that was since reopened.
+        drop(store);
+        let mut first = HistoryStore::open(&path, options(), 202_000).unwrap();
+        let stale = first.begin_compaction(202_000).unwrap().run().unwrap();
+        drop(first);
+        let mut store = HistoryStore::open(&path, options(), 202_000).unwrap();
+        assert!(store.finish_compaction(stale).unwrap().is_none());
+        let reloaded = store.load(0, u64::MAX).unwrap();
+        assert_eq!(reloaded.latest().unwrap().timestamp, 202_000);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    history::{ProcessSample, Sample},
+    memory::{Memory, MemoryMetric},
+    source::{RawProcess, RawSnapshot},
+};
+use itertools::Itertools;
+
+/// A process named `p{pid}` using `memory` bytes.
+pub fn process(pid: u32, parent: Option<u32>, memory: u64) -> RawProcess {
+    RawProcess {
+        pid,
+        name: format!("p{pid}"),
+        parent,
+        memory,
+        ..RawProcess::default()
+    }
+}
+
+/// Builds a snapshot of `processes` the way the sampler does, with totals
+/// summed by RSS and system counters at 0.
+pub fn memory(processes: impl IntoIterator<Item = RawProcess>) -> Memory {
+    let snapshot = RawSnapshot {
+        processes: processes.into_iter().collect_vec(),
+        ..RawSnapshot::default()
+    };
+    Memory::from_raw(snapshot, MemoryMetric::Rss)
+}
+
+/// A history sample of `(pid, memory)` pairs, each process without
+/// children.
+pub fn sample(timestamp: u64, used_memory: u64, processes: &[(u32, u64)]) -> Sample {
+    Sample {
+        timestamp,
+        total_memory: 1000,
+        used_memory,
+        total_swap: 0,
+        used_swap: 0,
+        processes: processes
+            .iter()
+            .map(|&(pid, memory)| ProcessSample {
+                pid,
+                start_time: 0,
+                memory,
+                total_memory: memory,
+            })
+            .collect_vec(),
+        truncated: false,
+    }
+}
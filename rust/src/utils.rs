@@ -1,13 +1,28 @@
-#[derive(Debug, Clone, PartialEq)]
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+/// An amount of bytes, written in a unit.
+///
+/// In Rust, comparison and hashing only look at the amount: `1 KiB` equals
+/// `1024 B`.
+#[derive(Debug, Clone)]
 pub struct Storage {
     pub(crate) quotient: u64,
     pub(crate) remainder: u64,
-    pub(crate) unit: Unit,
+    pub(crate) unit: StorageUnit,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Unit {
+/// Units of binary (IEC) and decimal (SI) prefixes. Bytes belong to both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageUnit {
     B,
+    KiB,
+    MiB,
+    GiB,
+    TiB,
+    PiB,
     KB,
     MB,
     GB,
@@ -15,31 +30,114 @@ pub enum Unit {
     PB,
 }
 
-impl std::fmt::Display for Unit {
+/// Family of units a [`Storage`] is scaled to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitSystem {
+    /// Powers of 1024 (KiB, MiB, ...), as memory is sized.
+    #[default]
+    Iec,
+    /// Powers of 1000 (kB, MB, ...), as disks are usually sold.
+    Si,
+}
+
+static UNIT_SYSTEM: Mutex<UnitSystem> = Mutex::new(UnitSystem::Iec);
+
+/// Unit system used by [`Storage::format`] when none is given.
+///
+/// Amounts keep the unit they were created in: [`Storage::from_bytes`] and
+/// `Display` stay in IEC units whatever this is set to.
+pub fn default_unit_system() -> UnitSystem {
+    *UNIT_SYSTEM.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub fn set_default_unit_system(system: UnitSystem) {
+    *UNIT_SYSTEM.lock().unwrap_or_else(PoisonError::into_inner) = system;
+}
+
+impl UnitSystem {
+    /// Units of this system from smallest to largest.
+    pub fn units(self) -> [StorageUnit; 6] {
+        match self {
+            UnitSystem::Iec => [
+                StorageUnit::B,
+                StorageUnit::KiB,
+                StorageUnit::MiB,
+                StorageUnit::GiB,
+                StorageUnit::TiB,
+                StorageUnit::PiB,
+            ],
+            UnitSystem::Si => [
+                StorageUnit::B,
+                StorageUnit::KB,
+                StorageUnit::MB,
+                StorageUnit::GB,
+                StorageUnit::TB,
+                StorageUnit::PB,
+            ],
+        }
+    }
+}
+
+impl StorageUnit {
+    /// Number of bytes in one of this unit.
+    pub fn bytes(self) -> u64 {
+        match self {
+            StorageUnit::B => 1,
+            StorageUnit::KiB => 1 << 10,
+            StorageUnit::MiB => 1 << 20,
+            StorageUnit::GiB => 1 << 30,
+            StorageUnit::TiB => 1 << 40,
+            StorageUnit::PiB => 1 << 50,
+            StorageUnit::KB => 1_000,
+            StorageUnit::MB => 1_000_000,
+            StorageUnit::GB => 1_000_000_000,
+            StorageUnit::TB => 1_000_000_000_000,
+            StorageUnit::PB => 1_000_000_000_000_000,
+        }
+    }
+
+    /// The system this unit belongs to, or `None` for bytes.
+    pub fn system(self) -> Option<UnitSystem> {
+        match self {
+            StorageUnit::B => None,
+            StorageUnit::KiB
+            | StorageUnit::MiB
+            | StorageUnit::GiB
+            | StorageUnit::TiB
+            | StorageUnit::PiB => Some(UnitSystem::Iec),
+            StorageUnit::KB
+            | StorageUnit::MB
+            | StorageUnit::GB
+            | StorageUnit::TB
+            | StorageUnit::PB => Some(UnitSystem::Si),
+        }
+    }
+}
+
+impl std::fmt::Display for StorageUnit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{}",
             match self {
-                Unit::B => "B",
-                Unit::KB => "KB",
-                Unit::MB => "MB",
-                Unit::GB => "GB",
-                Unit::TB => "TB",
-                Unit::PB => "PB",
+                StorageUnit::B => "B",
+                StorageUnit::KiB => "KiB",
+                StorageUnit::MiB => "MiB",
+                StorageUnit::GiB => "GiB",
+                StorageUnit::TiB => "TiB",
+                StorageUnit::PiB => "PiB",
+                StorageUnit::KB => "kB",
+                StorageUnit::MB => "MB",
+                StorageUnit::GB => "GB",
+                StorageUnit::TB => "TB",
+                StorageUnit::PB => "PB",
             }
         )
     }
 }
 
 impl Storage {
-    const SHIFT_KB: u64 = 10;
-    const SHIFT_MB: u64 = 20;
-    const SHIFT_GB: u64 = 30;
-    const SHIFT_TB: u64 = 40;
-    const SHIFT_PB: u64 = 50;
-
-    pub fn new(quotient: u64, remainder: u64, unit: Unit) -> Self {
+    pub fn new(quotient: u64, remainder: u64, unit: StorageUnit) -> Self {
         Self {
             quotient,
             remainder,
@@ -47,60 +145,97 @@ impl Storage {
         }
     }
 
+    /// `bytes` in the largest IEC unit not exceeding it.
     pub fn from_bytes(bytes: u64) -> Self {
-        if bytes >= (1 << Self::SHIFT_PB) {
-            let q = bytes >> Self::SHIFT_PB;
-            let r = bytes & ((1 << Self::SHIFT_PB) - 1);
-            Storage::new(q, r, Unit::PB)
-        } else if bytes >= (1 << Self::SHIFT_TB) {
-            let q = bytes >> Self::SHIFT_TB;
-            let r = bytes & ((1 << Self::SHIFT_TB) - 1);
-            Storage::new(q, r, Unit::TB)
-        } else if bytes >= (1 << Self::SHIFT_GB) {
-            let q = bytes >> Self::SHIFT_GB;
-            let r = bytes & ((1 << Self::SHIFT_GB) - 1);
-            Storage::new(q, r, Unit::GB)
-        } else if bytes >= (1 << Self::SHIFT_MB) {
-            let q = bytes >> Self::SHIFT_MB;
-            let r = bytes & ((1 << Self::SHIFT_MB) - 1);
-            Storage::new(q, r, Unit::MB)
-        } else if bytes >= (1 << Self::SHIFT_KB) {
-            let q = bytes >> Self::SHIFT_KB;
-            let r = bytes & ((1 << Self::SHIFT_KB) - 1);
-            Storage::new(q, r, Unit::KB)
-        } else {
-            Storage::new(bytes, 0, Unit::B)
-        }
+        Self::from_bytes_in(bytes, UnitSystem::Iec)
+    }
+
+    /// `bytes` in the largest unit of `system` not exceeding it.
+    pub fn from_bytes_in(bytes: u64, system: UnitSystem) -> Self {
+        let unit = system
+            .units()
+            .into_iter()
+            .rev()
+            .find(|unit| bytes >= unit.bytes())
+            .unwrap_or(StorageUnit::B);
+        Self::from_bytes_as(bytes, unit)
+    }
+
+    /// `bytes` in `unit`, however small or large the quotient gets.
+    pub fn from_bytes_as(bytes: u64, unit: StorageUnit) -> Self {
+        let scale = unit.bytes();
+        Storage::new(bytes / scale, bytes % scale, unit)
     }
 
     pub fn to_bytes(&self) -> u64 {
-        match self.unit {
-            Unit::B => self.quotient,
-            Unit::KB => (self.quotient << Self::SHIFT_KB) | self.remainder,
-            Unit::MB => (self.quotient << Self::SHIFT_MB) | self.remainder,
-            Unit::GB => (self.quotient << Self::SHIFT_GB) | self.remainder,
-            Unit::TB => (self.quotient << Self::SHIFT_TB) | self.remainder,
-            Unit::PB => (self.quotient << Self::SHIFT_PB) | self.remainder,
-        }
+        self.quotient
+            .saturating_mul(self.unit.bytes())
+            .saturating_add(self.remainder)
     }
 
     pub fn to_float(&self) -> f64 {
-        const SCALE_KB: f64 = 1.0 / 1024.0;
-        const SCALE_MB: f64 = 1.0 / (1024.0 * 1024.0);
-        const SCALE_GB: f64 = 1.0 / (1024.0 * 1024.0 * 1024.0);
-        const SCALE_TB: f64 = 1.0 / (1024.0 * 1024.0 * 1024.0 * 1024.0);
-        const SCALE_PB: f64 = 1.0 / (1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0);
-
-        match self.unit {
-            Unit::B => self.quotient as f64,
-            Unit::KB => self.quotient as f64 + (self.remainder as f64 * SCALE_KB),
-            Unit::MB => self.quotient as f64 + (self.remainder as f64 * SCALE_MB),
-            Unit::GB => self.quotient as f64 + (self.remainder as f64 * SCALE_GB),
-            Unit::TB => self.quotient as f64 + (self.remainder as f64 * SCALE_TB),
-            Unit::PB => self.quotient as f64 + (self.remainder as f64 * SCALE_PB),
+        self.quotient as f64 + self.remainder as f64 / self.unit.bytes() as f64
+    }
+
+    /// The same amount expressed in `unit`.
+    pub fn to_unit(&self, unit: StorageUnit) -> Storage {
+        Self::from_bytes_as(self.to_bytes(), unit)
+    }
+
+    /// The same amount rescaled to the best fitting unit of `system`.
+    pub fn to_system(&self, system: UnitSystem) -> Storage {
+        Self::from_bytes_in(self.to_bytes(), system)
+    }
+
+    /// Formats the amount in `system`, or in [`default_unit_system`] if
+    /// `None`.
+    pub fn format(&self, system: Option<UnitSystem>) -> String {
+        self.to_system(system.unwrap_or_else(default_unit_system))
+            .to_string()
+    }
+
+    /// The sum, or `None` if it exceeds `u64::MAX` bytes.
+    pub fn checked_add(&self, other: &Storage) -> Option<Storage> {
+        let bytes = self.to_bytes().checked_add(other.to_bytes())?;
+        Some(self.rescaled_with(other, bytes))
+    }
+
+    /// The sum, capped at `u64::MAX` bytes.
+    pub fn saturating_add(&self, other: &Storage) -> Storage {
+        self.rescaled_with(other, self.to_bytes().saturating_add(other.to_bytes()))
+    }
+
+    /// The difference, or `None` if `other` is larger.
+    pub fn checked_sub(&self, other: &Storage) -> Option<Storage> {
+        let bytes = self.to_bytes().checked_sub(other.to_bytes())?;
+        Some(self.rescaled_with(other, bytes))
+    }
+
+    /// The difference, or zero if `other` is larger.
+    pub fn saturating_sub(&self, other: &Storage) -> Storage {
+        self.rescaled_with(other, self.to_bytes().saturating_sub(other.to_bytes()))
+    }
+
+    /// `self / other` as a fraction, or `None` if `other` is zero.
+    pub fn ratio(&self, other: &Storage) -> Option<f64> {
+        match other.to_bytes() {
+            0 => None,
+            total => Some(self.to_bytes() as f64 / total as f64),
         }
     }
 
+    /// `bytes` in the unit system of `self`.
+    fn rescaled(&self, bytes: u64) -> Storage {
+        Self::from_bytes_in(bytes, self.unit.system().unwrap_or_default())
+    }
+
+    /// `bytes` in the unit system of `self`, or of `other` if `self` is in
+    /// plain bytes.
+    fn rescaled_with(&self, other: &Storage, bytes: u64) -> Storage {
+        let system = self.unit.system().or(other.unit.system());
+        Self::from_bytes_in(bytes, system.unwrap_or_default())
+    }
+
     pub fn quotient(&self) -> u64 {
         self.quotient
     }
@@ -109,44 +244,232 @@ impl Storage {
         self.remainder
     }
 
-    pub fn unit(&self) -> Unit {
+    pub fn unit(&self) -> StorageUnit {
         self.unit
     }
 }
 
-impl std::ops::Add<Storage> for Storage {
-    type Output = Self;
+/// Error returned when parsing a [`Storage`] from text fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseStorageError {
+    Empty,
+    /// The numeric part is missing or malformed, e.g. `GB` or `1.2.3 MB`.
+    InvalidNumber {
+        number: String,
+    },
+    UnknownUnit {
+        unit: String,
+    },
+    /// A plain byte count such as `1.5` or `1.5 B`.
+    FractionalBytes,
+    /// The size does not fit in a `u64` byte count.
+    Overflow,
+}
+
+impl std::fmt::Display for ParseStorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("size is empty"),
+            Self::InvalidNumber { number } => write!(f, "invalid number `{number}`"),
+            Self::UnknownUnit { unit } => write!(f, "unknown unit `{unit}`"),
+            Self::FractionalBytes => f.write_str("byte counts cannot be fractional"),
+            Self::Overflow => f.write_str("size exceeds 2^64 bytes"),
+        }
+    }
+}
+
+impl std::error::Error for ParseStorageError {}
+
+impl StorageUnit {
+    /// Case-insensitive unit suffix. A bare prefix such as `M` means the
+    /// binary unit, as in `ulimit` or systemd; `MB` is decimal.
+    fn parse_suffix(suffix: &str) -> Option<StorageUnit> {
+        Some(match suffix.to_ascii_lowercase().as_str() {
+            "" | "b" | "byte" | "bytes" => StorageUnit::B,
+            "k" | "ki" | "kib" => StorageUnit::KiB,
+            "m" | "mi" | "mib" => StorageUnit::MiB,
+            "g" | "gi" | "gib" => StorageUnit::GiB,
+            "t" | "ti" | "tib" => StorageUnit::TiB,
+            "p" | "pi" | "pib" => StorageUnit::PiB,
+            "kb" => StorageUnit::KB,
+            "mb" => StorageUnit::MB,
+            "gb" => StorageUnit::GB,
+            "tb" => StorageUnit::TB,
+            "pb" => StorageUnit::PB,
+            _ => return None,
+        })
+    }
+}
+
+impl std::str::FromStr for Storage {
+    type Err = ParseStorageError;
+
+    /// Parses sizes like `1024`, `512M`, `2 GB` or `1.5 GiB`. The result is
+    /// expressed in the unit that was written, rounded to the nearest byte.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseStorageError::Empty);
+        }
+
+        let split = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(s.len());
+        let (number, suffix) = (&s[..split], s[split..].trim_start());
+        let invalid_number = || ParseStorageError::InvalidNumber {
+            number: number.to_string(),
+        };
+
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if (whole.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+            return Err(invalid_number());
+        }
+        let unit =
+            StorageUnit::parse_suffix(suffix).ok_or_else(|| ParseStorageError::UnknownUnit {
+                unit: suffix.to_string(),
+            })?;
+        if unit == StorageUnit::B && fraction.bytes().any(|digit| digit != b'0') {
+            return Err(ParseStorageError::FractionalBytes);
+        }
+
+        let whole = match whole {
+            "" => 0,
+            whole => whole
+                .parse::<u64>()
+                .map_err(|_| ParseStorageError::Overflow)?,
+        };
+        // Digits past the 19th are below a byte even for PiB.
+        let fraction = &fraction[..fraction.len().min(19)];
+        let denominator = 10u128.pow(fraction.len() as u32);
+        let numerator = match fraction {
+            "" => 0,
+            fraction => fraction.parse::<u128>().map_err(|_| invalid_number())?,
+        };
+
+        let scale = unit.bytes() as u128;
+        let bytes = whole as u128 * scale + (numerator * scale + denominator / 2) / denominator;
+        let bytes = u64::try_from(bytes).map_err(|_| ParseStorageError::Overflow)?;
+        Ok(Storage::from_bytes_as(bytes, unit))
+    }
+}
+
+/// Implements a binary operator for every combination of owned and borrowed
+/// operands in terms of a `fn(&Storage, &Storage) -> Storage` method.
+macro_rules! storage_binop {
+    ($trait:ident, $method:ident, $via:ident) => {
+        impl std::ops::$trait<Storage> for Storage {
+            type Output = Storage;
+
+            fn $method(self, other: Storage) -> Storage {
+                Storage::$via(&self, &other)
+            }
+        }
+
+        impl std::ops::$trait<&Storage> for Storage {
+            type Output = Storage;
+
+            fn $method(self, other: &Storage) -> Storage {
+                Storage::$via(&self, other)
+            }
+        }
+
+        impl std::ops::$trait<Storage> for &Storage {
+            type Output = Storage;
+
+            fn $method(self, other: Storage) -> Storage {
+                Storage::$via(self, &other)
+            }
+        }
 
-    fn add(self, other: Self) -> Self {
-        let total_bytes = self.to_bytes() + other.to_bytes();
-        Storage::from_bytes(total_bytes)
+        impl std::ops::$trait<&Storage> for &Storage {
+            type Output = Storage;
+
+            fn $method(self, other: &Storage) -> Storage {
+                Storage::$via(self, other)
+            }
+        }
+    };
+}
+
+storage_binop!(Add, add, saturating_add);
+storage_binop!(Sub, sub, saturating_sub);
+
+impl std::ops::Mul<u64> for Storage {
+    type Output = Storage;
+
+    fn mul(self, factor: u64) -> Storage {
+        &self * factor
     }
 }
 
-impl std::ops::Add<&Storage> for Storage {
-    type Output = Self;
+impl std::ops::Mul<u64> for &Storage {
+    type Output = Storage;
 
-    fn add(self, other: &Self) -> Self {
-        let total_bytes = self.to_bytes() + other.to_bytes();
-        Storage::from_bytes(total_bytes)
+    /// Saturates at `u64::MAX` bytes.
+    fn mul(self, factor: u64) -> Storage {
+        self.rescaled(self.to_bytes().saturating_mul(factor))
     }
 }
 
-impl std::ops::Add<Storage> for &Storage {
+impl std::ops::Div<u64> for Storage {
     type Output = Storage;
 
-    fn add(self, other: Storage) -> Self::Output {
-        let total_bytes = self.to_bytes() + other.to_bytes();
-        Storage::from_bytes(total_bytes)
+    fn div(self, divisor: u64) -> Storage {
+        &self / divisor
     }
 }
 
-impl std::ops::Add<&Storage> for &Storage {
+impl std::ops::Div<u64> for &Storage {
     type Output = Storage;
 
-    fn add(self, other: &Storage) -> Self::Output {
-        let total_bytes = self.to_bytes() + other.to_bytes();
-        Storage::from_bytes(total_bytes)
+    /// Rounds down. Panics if `divisor` is zero, like integer division.
+    fn div(self, divisor: u64) -> Storage {
+        self.rescaled(self.to_bytes() / divisor)
+    }
+}
+
+impl std::iter::Sum for Storage {
+    fn sum<I: Iterator<Item = Storage>>(iter: I) -> Storage {
+        iter.fold(Storage::from_bytes(0), |total, storage| total + storage)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Storage> for Storage {
+    fn sum<I: Iterator<Item = &'a Storage>>(iter: I) -> Storage {
+        iter.fold(Storage::from_bytes(0), |total, storage| total + storage)
+    }
+}
+
+impl Storage {
+    /// The amount in bytes, without saturating.
+    fn exact_bytes(&self) -> u128 {
+        u128::from(self.quotient) * u128::from(self.unit.bytes()) + u128::from(self.remainder)
+    }
+}
+
+impl PartialEq for Storage {
+    fn eq(&self, other: &Self) -> bool {
+        self.exact_bytes() == other.exact_bytes()
+    }
+}
+
+impl Eq for Storage {}
+
+impl std::hash::Hash for Storage {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.exact_bytes().hash(state);
+    }
+}
+
+impl PartialOrd for Storage {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Storage {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.exact_bytes().cmp(&other.exact_bytes())
     }
 }
 
@@ -157,108 +480,203 @@ impl std::fmt::Display for Storage {
     }
 }
 
+/// Signed change of a [`Storage`] amount per second, such as memory growth
+/// or I/O throughput.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct StorageRate {
+    pub(crate) bytes_per_second: f64,
+}
+
+impl StorageRate {
+    pub fn new(bytes_per_second: f64) -> Self {
+        Self { bytes_per_second }
+    }
+
+    /// Rate at which `from` turned into `to` over `elapsed`, or `None` if no
+    /// time passed.
+    pub fn between(from: &Storage, to: &Storage, elapsed: Duration) -> Option<Self> {
+        let delta = to.to_bytes() as f64 - from.to_bytes() as f64;
+        Self::per(delta, elapsed)
+    }
+
+    /// Rate of transferring `amount` over `elapsed`, or `None` if no time
+    /// passed.
+    pub fn over(amount: &Storage, elapsed: Duration) -> Option<Self> {
+        Self::per(amount.to_bytes() as f64, elapsed)
+    }
+
+    fn per(bytes: f64, elapsed: Duration) -> Option<Self> {
+        let seconds = elapsed.as_secs_f64();
+        (seconds > 0.0).then(|| Self::new(bytes / seconds))
+    }
+
+    pub fn bytes_per_second(&self) -> f64 {
+        self.bytes_per_second
+    }
+
+    pub fn bytes_per_minute(&self) -> f64 {
+        self.bytes_per_second * 60.0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.bytes_per_second < 0.0
+    }
+
+    /// Bytes per second without the sign, rounded to whole bytes.
+    pub fn magnitude(&self) -> Storage {
+        Storage::from_bytes(self.bytes_per_second.abs().round() as u64)
+    }
+
+    /// Formats the rate in `system`, or in [`default_unit_system`] if
+    /// `None`.
+    pub fn format(&self, system: Option<UnitSystem>) -> String {
+        format!("{}{}/s", self.sign(), self.magnitude().format(system))
+    }
+
+    /// `-` for rates that round to a non-zero decrease.
+    pub(crate) fn sign(&self) -> &'static str {
+        if self.is_negative() && self.magnitude().to_bytes() > 0 {
+            "-"
+        } else {
+            ""
+        }
+    }
+}
+
+impl std::fmt::Display for StorageRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}/s", self.sign(), self.magnitude())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Like `assert_eq!`, but also checks that both sides are written in the
+    /// same unit, which `==` ignores.
+    macro_rules! assert_same {
+        ($left:expr, $right:expr $(,)?) => {
+            assert_eq!(format!("{:?}", $left), format!("{:?}", $right))
+        };
+    }
+
     #[test]
     fn test_from_bytes() {
-        assert_eq!(Storage::from_bytes(0), Storage::new(0, 0, Unit::B));
+        assert_same!(Storage::from_bytes(0), Storage::new(0, 0, StorageUnit::B));
 
-        assert_eq!(Storage::from_bytes(1), Storage::new(1, 0, Unit::B));
-        assert_eq!(Storage::from_bytes(512), Storage::new(512, 0, Unit::B));
-        assert_eq!(Storage::from_bytes(1023), Storage::new(1023, 0, Unit::B));
+        assert_same!(Storage::from_bytes(1), Storage::new(1, 0, StorageUnit::B));
+        assert_same!(
+            Storage::from_bytes(512),
+            Storage::new(512, 0, StorageUnit::B)
+        );
+        assert_same!(
+            Storage::from_bytes(1023),
+            Storage::new(1023, 0, StorageUnit::B)
+        );
 
-        assert_eq!(Storage::from_bytes(1024), Storage::new(1, 0, Unit::KB));
-        assert_eq!(
+        assert_same!(
+            Storage::from_bytes(1024),
+            Storage::new(1, 0, StorageUnit::KiB)
+        );
+        assert_same!(
             Storage::from_bytes(1024 * 1024),
-            Storage::new(1, 0, Unit::MB)
+            Storage::new(1, 0, StorageUnit::MiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(1024 * 1024 * 1024),
-            Storage::new(1, 0, Unit::GB)
+            Storage::new(1, 0, StorageUnit::GiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(1024 * 1024 * 1024 * 1024),
-            Storage::new(1, 0, Unit::TB)
+            Storage::new(1, 0, StorageUnit::TiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(1024 * 1024 * 1024 * 1024 * 1024),
-            Storage::new(1, 0, Unit::PB)
+            Storage::new(1, 0, StorageUnit::PiB)
         );
 
-        assert_eq!(Storage::from_bytes(1536), Storage::new(1, 512, Unit::KB));
-        assert_eq!(
+        assert_same!(
+            Storage::from_bytes(1536),
+            Storage::new(1, 512, StorageUnit::KiB)
+        );
+        assert_same!(
             Storage::from_bytes(1024 * 1024 + 512),
-            Storage::new(1, 512, Unit::MB)
+            Storage::new(1, 512, StorageUnit::MiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(3 * 1024 * 1024 + 256),
-            Storage::new(3, 256, Unit::MB)
+            Storage::new(3, 256, StorageUnit::MiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(5 * 1024 * 1024 * 1024 + 1024 * 1024),
-            Storage::new(5, 1024 * 1024, Unit::GB)
+            Storage::new(5, 1024 * 1024, StorageUnit::GiB)
         );
 
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(1024 - 1),
-            Storage::new(1023, 0, Unit::B)
+            Storage::new(1023, 0, StorageUnit::B)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(1024 * 1024 - 1),
-            Storage::new(1023, 1023, Unit::KB)
+            Storage::new(1023, 1023, StorageUnit::KiB)
         );
 
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(10 * 1024 * 1024 * 1024 * 1024 * 1024),
-            Storage::new(10, 0, Unit::PB)
+            Storage::new(10, 0, StorageUnit::PiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(100 * 1024 * 1024 * 1024 * 1024 * 1024 + 512),
-            Storage::new(100, 512, Unit::PB)
+            Storage::new(100, 512, StorageUnit::PiB)
         );
     }
 
     #[test]
     fn test_to_bytes() {
-        assert_eq!(Storage::new(0, 0, Unit::B).to_bytes(), 0);
-        assert_eq!(Storage::new(1, 0, Unit::B).to_bytes(), 1);
-        assert_eq!(Storage::new(1023, 0, Unit::B).to_bytes(), 1023);
+        assert_eq!(Storage::new(0, 0, StorageUnit::B).to_bytes(), 0);
+        assert_eq!(Storage::new(1, 0, StorageUnit::B).to_bytes(), 1);
+        assert_eq!(Storage::new(1023, 0, StorageUnit::B).to_bytes(), 1023);
 
-        assert_eq!(Storage::new(1, 0, Unit::KB).to_bytes(), 1024);
-        assert_eq!(Storage::new(1, 0, Unit::MB).to_bytes(), 1024 * 1024);
-        assert_eq!(Storage::new(1, 0, Unit::GB).to_bytes(), 1024 * 1024 * 1024);
+        assert_eq!(Storage::new(1, 0, StorageUnit::KiB).to_bytes(), 1024);
+        assert_eq!(Storage::new(1, 0, StorageUnit::MiB).to_bytes(), 1024 * 1024);
+        assert_eq!(
+            Storage::new(1, 0, StorageUnit::GiB).to_bytes(),
+            1024 * 1024 * 1024
+        );
         assert_eq!(
-            Storage::new(1, 0, Unit::TB).to_bytes(),
+            Storage::new(1, 0, StorageUnit::TiB).to_bytes(),
             1024 * 1024 * 1024 * 1024
         );
         assert_eq!(
-            Storage::new(1, 0, Unit::PB).to_bytes(),
+            Storage::new(1, 0, StorageUnit::PiB).to_bytes(),
             1024 * 1024 * 1024 * 1024 * 1024
         );
 
-        assert_eq!(Storage::new(1, 512, Unit::KB).to_bytes(), 1536);
-        assert_eq!(Storage::new(1, 512, Unit::MB).to_bytes(), 1024 * 1024 + 512);
+        assert_eq!(Storage::new(1, 512, StorageUnit::KiB).to_bytes(), 1536);
         assert_eq!(
-            Storage::new(3, 256, Unit::MB).to_bytes(),
+            Storage::new(1, 512, StorageUnit::MiB).to_bytes(),
+            1024 * 1024 + 512
+        );
+        assert_eq!(
+            Storage::new(3, 256, StorageUnit::MiB).to_bytes(),
             3 * 1024 * 1024 + 256
         );
         assert_eq!(
-            Storage::new(5, 1024 * 1024, Unit::GB).to_bytes(),
+            Storage::new(5, 1024 * 1024, StorageUnit::GiB).to_bytes(),
             5 * 1024 * 1024 * 1024 + 1024 * 1024
         );
         assert_eq!(
-            Storage::new(100, 512, Unit::PB).to_bytes(),
+            Storage::new(100, 512, StorageUnit::PiB).to_bytes(),
             100 * 1024 * 1024 * 1024 * 1024 * 1024 + 512
         );
 
         assert_eq!(
-            Storage::new(1023, 1023, Unit::KB).to_bytes(),
+            Storage::new(1023, 1023, StorageUnit::KiB).to_bytes(),
             1023 * 1024 + 1023
         );
         assert_eq!(
-            Storage::new(10, 0, Unit::TB).to_bytes(),
+            Storage::new(10, 0, StorageUnit::TiB).to_bytes(),
             10 * 1024 * 1024 * 1024 * 1024
         );
     }
@@ -299,48 +717,57 @@ mod tests {
 
     #[test]
     fn test_boundary_values() {
-        assert_eq!(Storage::from_bytes(1023), Storage::new(1023, 0, Unit::B));
-        assert_eq!(Storage::from_bytes(1024), Storage::new(1, 0, Unit::KB));
-        assert_eq!(Storage::from_bytes(1025), Storage::new(1, 1, Unit::KB));
+        assert_same!(
+            Storage::from_bytes(1023),
+            Storage::new(1023, 0, StorageUnit::B)
+        );
+        assert_same!(
+            Storage::from_bytes(1024),
+            Storage::new(1, 0, StorageUnit::KiB)
+        );
+        assert_same!(
+            Storage::from_bytes(1025),
+            Storage::new(1, 1, StorageUnit::KiB)
+        );
 
         let mb_boundary = 1024 * 1024;
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(mb_boundary - 1),
-            Storage::new(1023, 1023, Unit::KB)
+            Storage::new(1023, 1023, StorageUnit::KiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(mb_boundary),
-            Storage::new(1, 0, Unit::MB)
+            Storage::new(1, 0, StorageUnit::MiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(mb_boundary + 1),
-            Storage::new(1, 1, Unit::MB)
+            Storage::new(1, 1, StorageUnit::MiB)
         );
 
         let gb_boundary = 1024 * 1024 * 1024;
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(gb_boundary - 1),
-            Storage::new(1023, 1023 * 1024 + 1023, Unit::MB)
+            Storage::new(1023, 1023 * 1024 + 1023, StorageUnit::MiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(gb_boundary),
-            Storage::new(1, 0, Unit::GB)
+            Storage::new(1, 0, StorageUnit::GiB)
         );
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(gb_boundary + 1),
-            Storage::new(1, 1, Unit::GB)
+            Storage::new(1, 1, StorageUnit::GiB)
         );
 
         let tb_boundary = 1024_u64 * 1024 * 1024 * 1024;
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(tb_boundary),
-            Storage::new(1, 0, Unit::TB)
+            Storage::new(1, 0, StorageUnit::TiB)
         );
 
         let pb_boundary = 1024_u64 * 1024 * 1024 * 1024 * 1024;
-        assert_eq!(
+        assert_same!(
             Storage::from_bytes(pb_boundary),
-            Storage::new(1, 0, Unit::PB)
+            Storage::new(1, 0, StorageUnit::PiB)
         );
     }
 
@@ -348,71 +775,71 @@ mod tests {
     fn test_to_float() {
         const EPSILON: f64 = 1e-10;
 
-        let value = Storage::new(0, 0, Unit::B).to_float();
+        let value = Storage::new(0, 0, StorageUnit::B).to_float();
         assert!((value - 0.0).abs() < EPSILON);
 
-        let value = Storage::new(1, 0, Unit::B).to_float();
+        let value = Storage::new(1, 0, StorageUnit::B).to_float();
         assert!((value - 1.0).abs() < EPSILON);
 
-        let value = Storage::new(512, 0, Unit::B).to_float();
+        let value = Storage::new(512, 0, StorageUnit::B).to_float();
         assert!((value - 512.0).abs() < EPSILON);
 
-        let value = Storage::new(1023, 0, Unit::B).to_float();
+        let value = Storage::new(1023, 0, StorageUnit::B).to_float();
         assert!((value - 1023.0).abs() < EPSILON);
 
-        let value = Storage::new(1, 0, Unit::KB).to_float();
+        let value = Storage::new(1, 0, StorageUnit::KiB).to_float();
         assert!((value - 1.0).abs() < EPSILON);
 
-        let value = Storage::new(5, 0, Unit::KB).to_float();
+        let value = Storage::new(5, 0, StorageUnit::KiB).to_float();
         assert!((value - 5.0).abs() < EPSILON);
 
-        let value = Storage::new(1, 512, Unit::KB).to_float();
+        let value = Storage::new(1, 512, StorageUnit::KiB).to_float();
         assert!((value - 1.0 - (512.0 / 1024.0)).abs() < EPSILON);
 
-        let value = Storage::new(2, 256, Unit::KB).to_float();
+        let value = Storage::new(2, 256, StorageUnit::KiB).to_float();
         assert!((value - 2.0 - (256.0 / 1024.0)).abs() < EPSILON);
 
-        let value = Storage::new(1, 0, Unit::MB).to_float();
+        let value = Storage::new(1, 0, StorageUnit::MiB).to_float();
         assert!((value - 1.0).abs() < EPSILON);
 
-        let value = Storage::new(3, 0, Unit::MB).to_float();
+        let value = Storage::new(3, 0, StorageUnit::MiB).to_float();
         assert!((value - 3.0).abs() < EPSILON);
 
-        let value = Storage::new(1, 512, Unit::MB).to_float();
+        let value = Storage::new(1, 512, StorageUnit::MiB).to_float();
         let expected = 1.0 + (512.0 / (1024.0 * 1024.0));
         assert!((value - expected).abs() < EPSILON);
 
-        let value = Storage::new(3, 256, Unit::MB).to_float();
+        let value = Storage::new(3, 256, StorageUnit::MiB).to_float();
         let expected = 3.0 + (256.0 / (1024.0 * 1024.0));
         assert!((value - expected).abs() < EPSILON);
 
-        let value = Storage::new(1, 0, Unit::GB).to_float();
+        let value = Storage::new(1, 0, StorageUnit::GiB).to_float();
         assert!((value - 1.0).abs() < EPSILON);
 
-        let value = Storage::new(5, 0, Unit::GB).to_float();
+        let value = Storage::new(5, 0, StorageUnit::GiB).to_float();
         assert!((value - 5.0).abs() < EPSILON);
 
-        let value = Storage::new(5, 1024 * 1024, Unit::GB).to_float();
+        let value = Storage::new(5, 1024 * 1024, StorageUnit::GiB).to_float();
         let expected = 5.0 + ((1024.0 * 1024.0) / (1024.0 * 1024.0 * 1024.0));
         assert!((value - expected).abs() < EPSILON);
 
-        let value = Storage::new(1, 0, Unit::TB).to_float();
+        let value = Storage::new(1, 0, StorageUnit::TiB).to_float();
         assert!((value - 1.0).abs() < EPSILON);
 
-        let value = Storage::new(10, 0, Unit::TB).to_float();
+        let value = Storage::new(10, 0, StorageUnit::TiB).to_float();
         assert!((value - 10.0).abs() < EPSILON);
 
-        let value = Storage::new(2, 512, Unit::TB).to_float();
+        let value = Storage::new(2, 512, StorageUnit::TiB).to_float();
         let expected = 2.0 + (512.0 / (1024.0 * 1024.0 * 1024.0 * 1024.0));
         assert!((value - expected).abs() < EPSILON);
 
-        let value = Storage::new(1, 0, Unit::PB).to_float();
+        let value = Storage::new(1, 0, StorageUnit::PiB).to_float();
         assert!((value - 1.0).abs() < EPSILON);
 
-        let value = Storage::new(100, 0, Unit::PB).to_float();
+        let value = Storage::new(100, 0, StorageUnit::PiB).to_float();
         assert!((value - 100.0).abs() < EPSILON);
 
-        let value = Storage::new(100, 512, Unit::PB).to_float();
+        let value = Storage::new(100, 512, StorageUnit::PiB).to_float();
         let expected = 100.0 + (512.0 / (1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0));
         assert!((value - expected).abs() < EPSILON);
     }
@@ -422,22 +849,22 @@ mod tests {
         const EPSILON: f64 = 1e-5;
 
         let test_cases = vec![
-            Storage::new(0, 0, Unit::B),
-            Storage::new(1, 0, Unit::B),
-            Storage::new(512, 0, Unit::B),
-            Storage::new(1023, 0, Unit::B),
-            Storage::new(1, 0, Unit::KB),
-            Storage::new(1, 512, Unit::KB),
-            Storage::new(2, 256, Unit::KB),
-            Storage::new(1, 0, Unit::MB),
-            Storage::new(1, 512, Unit::MB),
-            Storage::new(3, 256, Unit::MB),
-            Storage::new(1, 0, Unit::GB),
-            Storage::new(5, 1024 * 1024, Unit::GB),
-            Storage::new(1, 0, Unit::TB),
-            Storage::new(2, 512, Unit::TB),
-            Storage::new(1, 0, Unit::PB),
-            Storage::new(100, 512, Unit::PB),
+            Storage::new(0, 0, StorageUnit::B),
+            Storage::new(1, 0, StorageUnit::B),
+            Storage::new(512, 0, StorageUnit::B),
+            Storage::new(1023, 0, StorageUnit::B),
+            Storage::new(1, 0, StorageUnit::KiB),
+            Storage::new(1, 512, StorageUnit::KiB),
+            Storage::new(2, 256, StorageUnit::KiB),
+            Storage::new(1, 0, StorageUnit::MiB),
+            Storage::new(1, 512, StorageUnit::MiB),
+            Storage::new(3, 256, StorageUnit::MiB),
+            Storage::new(1, 0, StorageUnit::GiB),
+            Storage::new(5, 1024 * 1024, StorageUnit::GiB),
+            Storage::new(1, 0, StorageUnit::TiB),
+            Storage::new(2, 512, StorageUnit::TiB),
+            Storage::new(1, 0, StorageUnit::PiB),
+            Storage::new(100, 512, StorageUnit::PiB),
         ];
 
         for storage in test_cases {
@@ -445,12 +872,13 @@ mod tests {
             let value = storage.to_float();
 
             let calculated_bytes = match storage.unit {
-                Unit::B => value,
-                Unit::KB => value * 1024.0,
-                Unit::MB => value * 1024.0 * 1024.0,
-                Unit::GB => value * 1024.0 * 1024.0 * 1024.0,
-                Unit::TB => value * 1024.0 * 1024.0 * 1024.0 * 1024.0,
-                Unit::PB => value * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0,
+                StorageUnit::B => value,
+                StorageUnit::KiB => value * 1024.0,
+                StorageUnit::MiB => value * 1024.0 * 1024.0,
+                StorageUnit::GiB => value * 1024.0 * 1024.0 * 1024.0,
+                StorageUnit::TiB => value * 1024.0 * 1024.0 * 1024.0 * 1024.0,
+                StorageUnit::PiB => value * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0,
+                _ => unreachable!("only IEC units are listed"),
             };
 
             let diff = (calculated_bytes - bytes as f64).abs();
@@ -469,15 +897,256 @@ mod tests {
     fn test_to_float_edge_cases() {
         const EPSILON: f64 = 1e-10;
 
-        let value = Storage::new(1023, 1023, Unit::KB).to_float();
+        let value = Storage::new(1023, 1023, StorageUnit::KiB).to_float();
         let expected = 1023.0 + (1023.0 / 1024.0);
         assert!((value - expected).abs() < EPSILON);
 
-        let value = Storage::new(1000, 0, Unit::PB).to_float();
+        let value = Storage::new(1000, 0, StorageUnit::PiB).to_float();
         assert!((value - 1000.0).abs() < EPSILON);
 
-        let value = Storage::new(10, 1024 * 1024 * 1023, Unit::GB).to_float();
+        let value = Storage::new(10, 1024 * 1024 * 1023, StorageUnit::GiB).to_float();
         let expected = 10.0 + ((1024.0 * 1024.0 * 1023.0) / (1024.0 * 1024.0 * 1024.0));
         assert!((value - expected).abs() < EPSILON);
     }
+
+    #[test]
+    fn test_from_bytes_si() {
+        assert_same!(
+            Storage::from_bytes_in(999, UnitSystem::Si),
+            Storage::new(999, 0, StorageUnit::B)
+        );
+        assert_same!(
+            Storage::from_bytes_in(1000, UnitSystem::Si),
+            Storage::new(1, 0, StorageUnit::KB)
+        );
+        assert_same!(
+            Storage::from_bytes_in(1024, UnitSystem::Si),
+            Storage::new(1, 24, StorageUnit::KB)
+        );
+        assert_same!(
+            Storage::from_bytes_in(2_500_000_000, UnitSystem::Si),
+            Storage::new(2, 500_000_000, StorageUnit::GB)
+        );
+        assert_same!(
+            Storage::from_bytes_in(u64::MAX, UnitSystem::Si),
+            Storage::new(18446, 744_073_709_551_615, StorageUnit::PB)
+        );
+        assert_eq!(
+            Storage::new(3, 141_592, StorageUnit::MB).to_bytes(),
+            3_141_592
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        let storage = Storage::from_bytes(1536);
+        assert_same!(
+            storage.to_unit(StorageUnit::B),
+            Storage::new(1536, 0, StorageUnit::B)
+        );
+        assert_same!(
+            storage.to_unit(StorageUnit::KB),
+            Storage::new(1, 536, StorageUnit::KB)
+        );
+        assert_same!(
+            storage.to_unit(StorageUnit::MiB),
+            Storage::new(0, 1536, StorageUnit::MiB)
+        );
+        assert_same!(
+            storage.to_system(UnitSystem::Si),
+            Storage::new(1, 536, StorageUnit::KB)
+        );
+        assert_same!(
+            Storage::new(1, 536, StorageUnit::KB).to_system(UnitSystem::Iec),
+            storage
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let storage = Storage::from_bytes(64 * 1024 * 1024);
+        assert_eq!(storage.to_string(), "64.00 MiB");
+        assert_eq!(storage.format(Some(UnitSystem::Iec)), "64.00 MiB");
+        assert_eq!(storage.format(Some(UnitSystem::Si)), "67.11 MB");
+        assert_eq!(
+            Storage::from_bytes_in(1500, UnitSystem::Si).to_string(),
+            "1.50 kB"
+        );
+        assert_eq!(
+            Storage::from_bytes(12).format(Some(UnitSystem::Si)),
+            "12.00 B"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |s: &str| s.parse::<Storage>().unwrap();
+
+        assert_same!(parse("1024"), Storage::new(1024, 0, StorageUnit::B));
+        assert_same!(parse(" 12 bytes "), Storage::new(12, 0, StorageUnit::B));
+        assert_same!(parse("512M"), Storage::new(512, 0, StorageUnit::MiB));
+        assert_same!(parse("512mib"), Storage::new(512, 0, StorageUnit::MiB));
+        assert_same!(parse("2 GB"), Storage::new(2, 0, StorageUnit::GB));
+        assert_same!(parse("2gb"), Storage::new(2, 0, StorageUnit::GB));
+        assert_same!(parse("1.5 kB"), Storage::new(1, 500, StorageUnit::KB));
+        assert_same!(
+            parse("1.5 GiB"),
+            Storage::new(1, 512 * 1024 * 1024, StorageUnit::GiB)
+        );
+        assert_same!(parse(".5K"), Storage::new(0, 512, StorageUnit::KiB));
+        assert_same!(parse("1.0 B"), Storage::new(1, 0, StorageUnit::B));
+        assert_eq!(parse("0.0001 kB").to_bytes(), 0);
+        assert_eq!(parse("0.0009 kB").to_bytes(), 1);
+        assert_eq!(parse("18446744073709551615").to_bytes(), u64::MAX);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |s: &str| s.parse::<Storage>().unwrap_err();
+
+        assert_eq!(parse("  "), ParseStorageError::Empty);
+        assert_eq!(
+            parse("GB"),
+            ParseStorageError::InvalidNumber {
+                number: String::new()
+            }
+        );
+        assert_eq!(
+            parse("1.2.3 MB"),
+            ParseStorageError::InvalidNumber {
+                number: String::from("1.2.3")
+            }
+        );
+        assert_eq!(
+            parse("-1 MB"),
+            ParseStorageError::InvalidNumber {
+                number: String::new()
+            }
+        );
+        assert_eq!(
+            parse("3 parsecs"),
+            ParseStorageError::UnknownUnit {
+                unit: String::from("parsecs")
+            }
+        );
+        assert_eq!(parse("1.5"), ParseStorageError::FractionalBytes);
+        assert_eq!(parse("16385 PiB"), ParseStorageError::Overflow);
+        assert_eq!(parse("18446744073709551616"), ParseStorageError::Overflow);
+        assert_eq!(parse("1e3").to_string(), "unknown unit `e3`");
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let kib = Storage::from_bytes(1024);
+        assert_same!(&kib + &kib, Storage::new(2, 0, StorageUnit::KiB));
+        assert_same!(
+            kib.clone() - Storage::from_bytes(512),
+            Storage::new(512, 0, StorageUnit::B)
+        );
+        assert_eq!(Storage::from_bytes(1) - &kib, Storage::from_bytes(0));
+        assert_eq!(kib.checked_sub(&Storage::from_bytes(1025)), None);
+
+        let max = Storage::from_bytes(u64::MAX);
+        assert_eq!((&max + &kib).to_bytes(), u64::MAX);
+        assert_eq!(max.checked_add(&kib), None);
+        assert_eq!(max.checked_add(&Storage::from_bytes(0)), Some(max.clone()));
+
+        // Results keep the unit system of the operands.
+        let kb = Storage::from_bytes_in(1000, UnitSystem::Si);
+        assert_same!(&kb + &kb, Storage::new(2, 0, StorageUnit::KB));
+        assert_same!(
+            Storage::from_bytes(500) + &kb,
+            Storage::new(1, 500, StorageUnit::KB)
+        );
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let storage = Storage::from_bytes(3 * 1024);
+        assert_same!(&storage * 1024, Storage::new(3, 0, StorageUnit::MiB));
+        assert_same!(storage.clone() / 2, Storage::new(1, 512, StorageUnit::KiB));
+        assert_eq!(&storage / 4096, Storage::from_bytes(0));
+        assert_eq!((storage * u64::MAX).to_bytes(), u64::MAX);
+        assert_same!(
+            Storage::from_bytes_in(3000, UnitSystem::Si) / 3,
+            Storage::new(1, 0, StorageUnit::KB)
+        );
+    }
+
+    #[test]
+    fn test_ratio_and_sum() {
+        let used = Storage::from_bytes(1024);
+        let total = Storage::from_bytes(4096);
+        assert_eq!(used.ratio(&total), Some(0.25));
+        assert_eq!(used.ratio(&Storage::from_bytes(0)), None);
+
+        let parts = [used.clone(), total.clone(), Storage::from_bytes(1024)];
+        assert_same!(
+            parts.iter().sum::<Storage>(),
+            Storage::new(6, 0, StorageUnit::KiB)
+        );
+        assert_same!(
+            parts.into_iter().sum::<Storage>(),
+            Storage::new(6, 0, StorageUnit::KiB)
+        );
+        assert_eq!(
+            std::iter::empty::<Storage>().sum::<Storage>(),
+            Storage::from_bytes(0)
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut sizes = vec![
+            Storage::from_bytes(2048),
+            Storage::new(1, 0, StorageUnit::KB),
+            Storage::from_bytes(10),
+        ];
+        sizes.sort();
+        assert_same!(
+            sizes,
+            vec![
+                Storage::from_bytes(10),
+                Storage::new(1, 0, StorageUnit::KB),
+                Storage::from_bytes(2048),
+            ]
+        );
+        assert!(Storage::from_bytes(1) < Storage::new(1, 0, StorageUnit::KB));
+        assert!(Storage::from_bytes(u64::MAX) < Storage::new(u64::MAX, 0, StorageUnit::KiB));
+    }
+
+    #[test]
+    fn test_equality_ignores_unit() {
+        let kib = Storage::from_bytes(1024);
+        let bytes = Storage::new(1024, 0, StorageUnit::B);
+        assert_eq!(kib, bytes);
+        assert_eq!(kib.cmp(&bytes), std::cmp::Ordering::Equal);
+        assert_ne!(kib, Storage::new(1, 0, StorageUnit::KB));
+
+        let set: std::collections::HashSet<_> =
+            [kib, bytes, Storage::from_bytes(1)].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_storage_rate() {
+        let from = Storage::from_bytes(10 * 1024 * 1024);
+        let to = Storage::from_bytes(34 * 1024 * 1024);
+        let rate = StorageRate::between(&from, &to, Duration::from_secs(2)).unwrap();
+        assert_eq!(rate.bytes_per_second(), 12.0 * 1024.0 * 1024.0);
+        assert_eq!(rate.to_string(), "12.00 MiB/s");
+        assert_eq!(rate.format(Some(UnitSystem::Si)), "12.58 MB/s");
+
+        let rate = StorageRate::between(&to, &from, Duration::from_millis(500)).unwrap();
+        assert!(rate.is_negative());
+        assert_eq!(rate.bytes_per_minute(), -48.0 * 1024.0 * 1024.0 * 60.0);
+        assert_eq!(rate.to_string(), "-48.00 MiB/s");
+        assert_eq!(StorageRate::new(-0.2).to_string(), "0.00 B/s");
+
+        assert_eq!(StorageRate::between(&from, &to, Duration::ZERO), None);
+        assert_eq!(
+            StorageRate::over(&Storage::from_bytes(3000), Duration::from_secs(3)),
+            Some(StorageRate::new(1000.0))
+        );
+    }
 }
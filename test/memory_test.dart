@@ -1,5 +1,6 @@
 import 'package:flutter_test/flutter_test.dart';
 import 'package:flutter_demo/src/rust/api/memory.dart';
+import 'package:flutter_demo/src/rust/error.dart';
 import 'package:flutter_demo/src/rust/frb_generated.dart';
 import 'package:flutter_demo/src/rust/memory.dart';
 
@@ -38,4 +39,18 @@ void main() {
     expect(memory.totalSwap, isNotNull);
     expect(memory.usedSwap, isNotNull);
   });
+
+  test('errors arrive as MemoryError', () async {
+    expect(
+      () => parseStorage(input: 'lots'),
+      throwsA(isA<MemoryError_InvalidInput>()),
+    );
+
+    await expectLater(
+      killProcessTree(pid: 999999999, graceMs: BigInt.zero),
+      throwsA(
+        isA<MemoryError_ProcessGone>().having((e) => e.pid, 'pid', 999999999),
+      ),
+    );
+  });
 }